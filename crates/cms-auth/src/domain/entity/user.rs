@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
     pub id: Uuid,
+    pub tenant_id: i64,
     pub editor_type: String,
     pub editor_id: Option<Uuid>,
     pub no: String,
@@ -1,11 +1,13 @@
 use salvo::prelude::*;
 use salvo::{http::header::USER_AGENT, oapi::extract::*};
+use tracing::debug;
 use validator::Validate;
 
 use cms_core::{
     config::AppState,
     domain::{AppResult, dto::JwtClaimsDTO, response::BaseBooleanResponse, result_ok},
     enums::PlatformEnum,
+    service::MetricsService,
 };
 
 use crate::domain::dto::LoginStoreDTO;
@@ -49,9 +51,17 @@ pub async fn manager_create(
         .to_string();
 
     // 获取客户端 IP（支持代理场景）
-    dto.client_ip = req.remote_addr().as_ipv4().unwrap().ip().to_string();
-
-    let token = LoginService::store(&PlatformEnum::Manager, &dto, state).await?;
+    dto.client_ip = req
+        .remote_addr()
+        .clone()
+        .into_std()
+        .map(|addr| addr.ip().to_string())
+        .unwrap_or_default();
+
+    let platform = PlatformEnum::Manager;
+    let result = LoginService::store(&platform, &dto, state).await;
+    MetricsService::record_login(platform.as_value(), result.is_ok());
+    let token = result?;
     result_ok(token)
 }
 
@@ -77,7 +87,7 @@ pub async fn manager_update(depot: &mut Depot) -> AppResult<TokenUpdateVO> {
         }
         _ => None,
     };
-    println!("claims: {:?}", claims); // Add this line to print claims
+    debug!("Update token with claims: {:?}", claims);
 
     let vo = LoginService::update(claims, state).await?;
     result_ok(vo)
@@ -139,9 +149,17 @@ pub async fn open_create(
         .to_string();
 
     // 获取客户端 IP（支持代理场景）
-    dto.client_ip = req.remote_addr().as_ipv4().unwrap().ip().to_string();
-
-    let token = LoginService::store(&PlatformEnum::Open, &dto, state).await?;
+    dto.client_ip = req
+        .remote_addr()
+        .clone()
+        .into_std()
+        .map(|addr| addr.ip().to_string())
+        .unwrap_or_default();
+
+    let platform = PlatformEnum::Open;
+    let result = LoginService::store(&platform, &dto, state).await;
+    MetricsService::record_login(platform.as_value(), result.is_ok());
+    let token = result?;
     result_ok(token)
 }
 
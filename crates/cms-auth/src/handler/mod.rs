@@ -1,15 +1,14 @@
 use salvo::prelude::*;
 
-mod checker_handler;
 mod login_handler;
 mod route_handler;
 
+use cms_core::handler::checker_router;
 use cms_core::middleware::{jwt_verify_access, jwt_verify_refresh};
 
 pub fn init_router() -> Router {
     Router::new()
-        .push(Router::with_path("/checker/health").get(checker_handler::health))
-        .push(Router::with_path("/checker/database").get(checker_handler::database))
+        .push(checker_router())
         .push(Router::with_path("/manage/login/password").post(login_handler::manager_create))
         .push(Router::with_path("/manage/open/password").post(login_handler::open_create))
         .push(
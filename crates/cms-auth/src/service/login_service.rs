@@ -1,4 +1,6 @@
 use sea_orm::*;
+use tracing::debug;
+use uuid::Uuid;
 
 use cms_core::{
     config::AppState,
@@ -7,7 +9,7 @@ use cms_core::{
     },
     enums::PlatformEnum,
     error::AppError,
-    service::JwtService,
+    service::{JwtService, TenantService},
     utils::{encrypt_utils::encrypt_password, time_utils},
 };
 
@@ -41,7 +43,9 @@ impl LoginService {
             .add(UserColumn::Phone.eq(&username))
             .add(UserColumn::Email.eq(&username));
 
-        let user = match UserEntity::find().filter(condition).one(&state.db).await? {
+        // 登录名在租户内唯一
+        let query = TenantService::scope_query(UserEntity::find(), Some(UserColumn::TenantId));
+        let user = match query.filter(condition).one(&state.db).await? {
             Some(user) => user,
             None => return Err(AppError::BadRequest(String::from("用户不存在"))),
         };
@@ -51,8 +55,8 @@ impl LoginService {
         }
 
         let md5_password = encrypt_password(user.salt.as_str(), password);
-        println!("md5_password: {}", md5_password);
         if md5_password.ne(&user.password) {
+            debug!("Password mismatch for user {}", user.id);
             return Err(AppError::BadRequest(String::from("密码错误")));
         }
 
@@ -61,9 +65,10 @@ impl LoginService {
             _ => "member",
         };
 
-        let cert: CertificateModel = JwtService::create(user.id, login_type, state)
-            .await
-            .unwrap();
+        let cert: CertificateModel =
+            JwtService::create(&user.id, login_type, user.tenant_id, state)
+                .await
+                .unwrap();
 
         let avatar = user.avatar_url();
         let roles: Vec<String> = vec![login_type.to_string()];
@@ -82,9 +87,11 @@ impl LoginService {
             refresh_expired: time_utils::to_db_time(&cert.refresh_expired_at),
         };
 
-        // 记录登录日志
+        // 记录登录日志，主键在应用中生成，MySQL 插入后无法返回数据库生成的 UUID
         let now = time_utils::current_time();
         let login = LoginActiveModel {
+            id: Set(Uuid::new_v4()),
+            tenant_id: Set(user.tenant_id),
             user_id: Set(user.id),
             login_type: Set(login_type.to_owned()),
             client_ip: Set(dto.client_ip.to_owned()),
@@ -108,7 +115,7 @@ impl LoginService {
         claims: Option<JwtClaimsDTO>,
         state: &AppState,
     ) -> HandleResult<TokenUpdateVO> {
-        let cert = JwtService::update_by_claims(claims, state).await?;
+        let cert = JwtService::update_by_claims(claims.as_ref(), state).await?;
 
         let vo = TokenUpdateVO {
             access_token: cert.access_token.to_owned(),
@@ -120,7 +127,7 @@ impl LoginService {
     }
 
     pub async fn delete(claims: Option<JwtClaimsDTO>, state: &AppState) -> HandleResult<()> {
-        JwtService::delete_by_claims(claims, state).await?;
+        JwtService::delete_by_claims(claims.as_ref(), state).await?;
 
         handle_ok(())
     }
@@ -1,34 +1,122 @@
-use deadpool_lapin::Pool as LapinPool;
-use redis::Client as RedisClient;
 use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tracing::{error, info};
 
-use super::DatabaseConfig;
-use super::RabbitMQConfig;
-use super::RedisConfig;
+use super::{CmsConfig, SharedConfig};
+use crate::service::{CacheStore, MessagePublisher, RabbitMQPublisher, RedisCacheStore};
 
 #[derive(Debug, Clone)]
 pub struct AppState {
+    /// 运行中的配置，可热加载的配置项修改后替换
+    pub config: SharedConfig,
+    /// 主库
     pub db: DatabaseConnection,
-    pub redis: RedisClient,
-    pub rabbitmq: LapinPool,
+    /// 只读副本，为空时读取也使用主库
+    pub db_replicas: Arc<Vec<DatabaseConnection>>,
+    /// 读取使用主库，写入后立即读取时避免副本延迟
+    pub read_primary: bool,
+    /// 轮询副本的计数
+    pub replica_cursor: Arc<AtomicUsize>,
+    /// 键值缓存
+    pub cache: Arc<dyn CacheStore>,
+    /// 消息发布
+    pub publisher: Arc<dyn MessagePublisher>,
 }
 
 impl AppState {
-    pub async fn init() -> Self {
-        let db_config = DatabaseConfig::from_env().expect("Failed to load db config");
-        let redis_config = RedisConfig::from_env().expect("Failed to load redis config");
-        let rabbitmq_config = RabbitMQConfig::from_env().expect("Failed to load queue config");
+    pub async fn init(config: CmsConfig) -> Self {
+        let db = config.db.build_connection().await.unwrap();
+        let db_replicas = config.db.build_replica_connections().await.unwrap();
+        let redis = config.redis.build_client().await.unwrap();
+        let rabbitmq = config.rabbitmq.build_pool().await.unwrap();
 
-        let res = db_config.build_connection().await;
-        println!("condition database: {:#?}", res);
-        let db = db_config.build_connection().await.unwrap();
-        let redis = redis_config.build_client().await.unwrap();
-        let rabbitmq = rabbitmq_config.build_pool().await.unwrap();
+        Self::new(
+            config,
+            db,
+            db_replicas,
+            Arc::new(RedisCacheStore::new(redis)),
+            Arc::new(RabbitMQPublisher::new(rabbitmq)),
+        )
+    }
 
+    pub fn new(
+        config: CmsConfig,
+        db: DatabaseConnection,
+        db_replicas: Vec<DatabaseConnection>,
+        cache: Arc<dyn CacheStore>,
+        publisher: Arc<dyn MessagePublisher>,
+    ) -> Self {
         Self {
+            config: SharedConfig::new(config),
             db,
-            redis,
-            rabbitmq,
+            db_replicas: Arc::new(db_replicas),
+            read_primary: false,
+            replica_cursor: Arc::new(AtomicUsize::new(0)),
+            cache,
+            publisher,
+        }
+    }
+
+    /// 写入连接：主库
+    pub fn db_write(&self) -> &DatabaseConnection {
+        &self.db
+    }
+
+    /// 读取连接：轮询只读副本，没有副本或要求读取主库时使用主库
+    pub fn db_read(&self) -> &DatabaseConnection {
+        if self.read_primary || self.db_replicas.is_empty() {
+            return &self.db;
+        }
+        let index = self.replica_cursor.fetch_add(1, Ordering::Relaxed) % self.db_replicas.len();
+        &self.db_replicas[index]
+    }
+
+    /// 关闭数据库连接池和消息服务连接池
+    ///
+    /// Redis 每次操作单独建立连接，没有需要关闭的连接池。
+    pub async fn close(&self) {
+        for db in std::iter::once(&self.db).chain(self.db_replicas.iter()) {
+            if let Err(err) = db.clone().close().await {
+                error!("Failed to close database connection: {}", err);
+            }
+        }
+        self.publisher.close().await;
+        info!("Connection pools closed");
+    }
+
+    /// 读取也使用主库的副本，用于写入后立即读取的场景
+    pub fn read_your_writes(&self) -> Self {
+        Self {
+            read_primary: true,
+            ..self.clone()
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::fixture::config::FakerAppState;
+
+    #[tokio::test]
+    async fn test_db_read_without_replicas() {
+        let state = FakerAppState::init().await;
+        assert!(state.db_replicas.is_empty());
+        assert!(std::ptr::eq(state.db_read(), state.db_write()));
+    }
+
+    #[tokio::test]
+    async fn test_db_read_with_replicas() {
+        let primary = FakerAppState::init().await;
+        let replica = FakerAppState::init().await;
+        let mut state = primary.clone();
+        state.db_replicas = vec![replica.db.clone()].into();
+
+        assert!(std::ptr::eq(state.db_read(), &state.db_replicas[0]));
+        assert!(std::ptr::eq(state.db_write(), &state.db));
+
+        let state = state.read_your_writes();
+        assert!(state.read_primary);
+        assert!(std::ptr::eq(state.db_read(), &state.db));
+    }
+}
@@ -1,20 +1,30 @@
 use sea_orm::{ConnectOptions, Database, DatabaseConnection};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
+use super::cms_config::{REDACTED, redact_url};
 use crate::{
     domain::{HandleResult, handle_ok},
     error::AppError,
+    service::TelemetryService,
 };
 
+/// SQLite 内存数据库的名称
+pub const SQLITE_MEMORY_NAME: &str = ":memory:";
+
 /// 数据库配置结构体
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
 pub struct DatabaseConfig {
     pub protocol: Option<String>,
     pub host: Option<String>,
     pub port: Option<u16>,
+    /// SQLite 不需要用户名和密码
+    #[serde(default)]
     pub user: String,
+    #[serde(default)]
     pub password: String,
+    /// SQLite 为数据库文件路径，`:memory:` 为内存数据库
+    #[serde(default)]
     pub name: String,
     pub schema: Option<String>,
     pub max_connections: Option<u32>,
@@ -24,6 +34,9 @@ pub struct DatabaseConfig {
     pub idle_timeout: Option<u64>,
     pub max_lifetime: Option<u64>,
     pub sqlx_logging: Option<bool>,
+    /// 只读副本的连接URL，多个使用逗号分隔
+    #[serde(default)]
+    pub replica_urls: Vec<String>,
 }
 
 impl DatabaseConfig {
@@ -35,15 +48,65 @@ impl DatabaseConfig {
             .map_err(|e| format!("Failed to parse environment variables: {}", e))
     }
 
+    /// 校验配置，返回全部错误
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        if self.name.is_empty() {
+            errors.push("db.name is required".to_string());
+        } else if let Err(err) = self.url() {
+            errors.push(format!("db: {}", err));
+        } else if !self.is_protocol_enabled() {
+            let protocol = self.protocol();
+            errors.push(format!(
+                "db.protocol {} is not enabled, rebuild with cargo feature \"{}\"",
+                protocol, protocol
+            ));
+        }
+        for url in self.replica_urls.iter().map(|url| url.trim()) {
+            if !url.is_empty() && !url.contains("://") {
+                errors.push(format!("db.replica_urls '{}' is not a valid URL", url));
+            }
+        }
+        errors
+    }
+
+    /// 隐藏密码，包括只读副本连接URL中的密码
+    pub fn redacted(&self) -> Self {
+        let password = if self.password.is_empty() {
+            String::new()
+        } else {
+            REDACTED.to_string()
+        };
+        Self {
+            password,
+            replica_urls: self
+                .replica_urls
+                .iter()
+                .map(|url| redact_url(url))
+                .collect(),
+            ..self.clone()
+        }
+    }
+
     /// 构建数据库连接URL
     pub fn url(&self) -> Result<String, String> {
-        // 获取数据库协议，如果未指定则默认为mysql
-        let protocol = self.protocol.as_deref().unwrap_or("mysql");
+        let protocol = self.protocol();
         // 检查数据库协议是否支持，如果不支持则返回错误信息
-        if !["mysql", "postgres"].contains(&protocol) {
+        if !["mysql", "postgres", "sqlite"].contains(&protocol) {
             return Err(format!("Unsupported database protocol: {}", protocol));
         }
 
+        // SQLite 使用数据库文件，不存在时自动创建
+        if protocol == "sqlite" {
+            if self.name.is_empty() {
+                return Err("Name cannot be empty".to_string());
+            }
+            if self.is_sqlite_memory() {
+                return Ok("sqlite::memory:".to_string());
+            }
+            return Ok(format!("sqlite://{}?mode=rwc", self.name));
+        }
+
         // 获取数据库主机，如果未指定则默认为localhost
         let host = self.host.as_deref().unwrap_or("localhost");
         // 检查数据库主机是否为空，如果为空则返回错误信息
@@ -76,12 +139,54 @@ impl DatabaseConfig {
         }
     }
 
+    /// 数据库协议，如果未指定则默认为postgres
+    fn protocol(&self) -> &str {
+        self.protocol.as_deref().unwrap_or("postgres")
+    }
+
+    /// 数据库协议是否已编译，MySQL 和 SQLite 需要启用同名的 cargo feature
+    pub fn is_protocol_enabled(&self) -> bool {
+        match self.protocol() {
+            "postgres" => true,
+            "mysql" => cfg!(feature = "mysql"),
+            "sqlite" => cfg!(any(test, feature = "sqlite")),
+            _ => false,
+        }
+    }
+
+    /// 是否为 SQLite 内存数据库
+    pub fn is_sqlite_memory(&self) -> bool {
+        self.protocol.as_deref() == Some("sqlite") && self.name == SQLITE_MEMORY_NAME
+    }
+
     /// 建立数据库连接
     pub async fn build_connection(&self) -> HandleResult<DatabaseConnection> {
         // 生成数据库连接URL，如果失败则返回错误信息
         let url = self
             .url()
             .map_err(|e| AppError::Database(format!("Failed to generate database URL: {}", e)))?;
+        if !self.is_protocol_enabled() {
+            let protocol = self.protocol();
+            return Err(AppError::Database(format!(
+                "Database protocol {} is not enabled, rebuild with cargo feature \"{}\"",
+                protocol, protocol
+            )));
+        }
+        self.connect(url).await
+    }
+
+    /// 建立只读副本的连接，未配置时返回空列表
+    pub async fn build_replica_connections(&self) -> HandleResult<Vec<DatabaseConnection>> {
+        let mut connections = Vec::with_capacity(self.replica_urls.len());
+        for url in self.replica_urls.iter().map(|url| url.trim()) {
+            if !url.is_empty() {
+                connections.push(self.connect(url.to_string()).await?);
+            }
+        }
+        handle_ok(connections)
+    }
+
+    async fn connect(&self, url: String) -> HandleResult<DatabaseConnection> {
         let mut opt = ConnectOptions::new(url);
 
         // 配置数据库连接选项
@@ -89,14 +194,22 @@ impl DatabaseConfig {
             .min_connections(self.min_connections.unwrap_or(5))
             .connect_timeout(Duration::from_secs(self.connect_timeout.unwrap_or(10)))
             .acquire_timeout(Duration::from_secs(self.acquire_timeout.unwrap_or(10)))
-            .idle_timeout(Duration::from_secs(self.idle_timeout.unwrap_or(10)))
-            .max_lifetime(Duration::from_secs(self.max_lifetime.unwrap_or(10)))
             .sqlx_logging(self.sqlx_logging.unwrap_or(true));
 
+        // 内存数据库只存在于单个连接中，连接池只保留一个不会被回收的连接
+        if self.is_sqlite_memory() {
+            opt.max_connections(1).min_connections(1);
+        } else {
+            opt.idle_timeout(Duration::from_secs(self.idle_timeout.unwrap_or(10)))
+                .max_lifetime(Duration::from_secs(self.max_lifetime.unwrap_or(10)));
+        }
+
         // 建立数据库连接，如果失败则返回错误信息
-        let connection = Database::connect(opt)
+        let mut connection = Database::connect(opt)
             .await
             .map_err(|e| AppError::Database(format!("Failed to connect to the database: {}", e)))?;
+        // 导出链路追踪时记录每个查询的 span
+        connection.set_metric_callback(TelemetryService::record_query);
 
         handle_ok(connection)
     }
@@ -334,4 +447,78 @@ mod tests {
         assert_eq!(cfg_url, db_url);
         config.port = Some(port);
     }
+
+    #[test]
+    fn test_sqlite_database_config_url() {
+        let mut config = DatabaseConfig {
+            protocol: Some("sqlite".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            config.url().unwrap_err(),
+            "Name cannot be empty".to_string()
+        );
+
+        config.name = "storage/salvo_cms.db".to_string();
+        assert_eq!(
+            config.url().unwrap(),
+            "sqlite://storage/salvo_cms.db?mode=rwc".to_string()
+        );
+        assert!(!config.is_sqlite_memory());
+
+        config.name = SQLITE_MEMORY_NAME.to_string();
+        assert_eq!(config.url().unwrap(), "sqlite::memory:".to_string());
+        assert!(config.is_sqlite_memory());
+    }
+
+    #[test]
+    fn test_database_config_replica_urls() {
+        let vars = vec![
+            ("CMS_DB_NAME".to_string(), "salvo_cms".to_string()),
+            (
+                "CMS_DB_REPLICA_URLS".to_string(),
+                "postgres://replica1/salvo_cms,postgres://replica2/salvo_cms".to_string(),
+            ),
+        ];
+        let config = envy::prefixed("CMS_DB_")
+            .from_iter::<_, DatabaseConfig>(vars)
+            .unwrap();
+        assert_eq!(
+            config.replica_urls,
+            vec![
+                "postgres://replica1/salvo_cms".to_string(),
+                "postgres://replica2/salvo_cms".to_string()
+            ]
+        );
+
+        let config = DatabaseConfig::default();
+        assert!(config.replica_urls.is_empty());
+    }
+
+    #[test]
+    fn test_database_protocol_enabled() {
+        let mut config = DatabaseConfig::default();
+        assert!(config.is_protocol_enabled());
+
+        config.protocol = Some("mysql".to_string());
+        assert_eq!(config.is_protocol_enabled(), cfg!(feature = "mysql"));
+
+        config.protocol = Some("sqlite".to_string());
+        assert!(config.is_protocol_enabled());
+
+        config.protocol = Some("oracle".to_string());
+        assert!(!config.is_protocol_enabled());
+    }
+
+    #[cfg(not(feature = "mysql"))]
+    #[tokio::test]
+    async fn test_build_connection_protocol_not_enabled() {
+        let config = DatabaseConfig {
+            protocol: Some("mysql".to_string()),
+            name: "salvo_cms".to_string(),
+            ..Default::default()
+        };
+        let message = config.build_connection().await.unwrap_err().to_string();
+        assert!(message.contains("rebuild with cargo feature"));
+    }
 }
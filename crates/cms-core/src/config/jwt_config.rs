@@ -1,7 +1,9 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use tracing::warn;
 
-#[derive(Deserialize, Debug, Clone)]
+use super::cms_config::redact;
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
 pub struct JwtConfig {
     /// JWT 签名密钥，默认值为 "Cms Jwt Secret Key"，建议用户自定义
     secret_key: Option<String>,
@@ -17,6 +19,44 @@ impl JwtConfig {
         envy::prefixed("CMS_JWT_").from_env::<JwtConfig>()
     }
 
+    /// 校验配置，返回全部错误
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        if let Some(days) = self
+            .access_expire_days
+            .filter(|days| !(1..=30).contains(days))
+        {
+            errors.push(format!(
+                "jwt.access_expire_days {} must be between 1 and 30",
+                days
+            ));
+        }
+        if let Some(days) = self
+            .refresh_expire_days
+            .filter(|days| !(30..=365).contains(days))
+        {
+            errors.push(format!(
+                "jwt.refresh_expire_days {} must be between 30 and 365",
+                days
+            ));
+        }
+        errors
+    }
+
+    /// 更新可热加载的配置：Token 过期时间，签名密钥需要重启
+    pub fn reload_from(&mut self, other: &Self) {
+        self.access_expire_days = other.access_expire_days;
+        self.refresh_expire_days = other.refresh_expire_days;
+    }
+
+    /// 隐藏签名密钥
+    pub fn redacted(&self) -> Self {
+        Self {
+            secret_key: redact(&self.secret_key),
+            ..self.clone()
+        }
+    }
+
     /// 获取签名密钥的字节数组
     pub fn secret_bytes(&self) -> Vec<u8> {
         let mut key = self.secret_key.clone().unwrap_or_default();
@@ -1,13 +1,17 @@
 mod app_state;
+mod cms_config;
 mod database_config;
 mod jwt_config;
 mod rabbitmq_config;
 mod redis_config;
+mod shared_config;
 mod web_config;
 
 pub use app_state::AppState;
-pub use database_config::DatabaseConfig;
+pub use cms_config::{CONFIG_FILE_ENV, CmsConfig, DEFAULT_CONFIG_FILE, REDACTED};
+pub use database_config::{DatabaseConfig, SQLITE_MEMORY_NAME};
 pub use jwt_config::JwtConfig;
 pub use rabbitmq_config::RabbitMQConfig;
 pub use redis_config::RedisConfig;
+pub use shared_config::SharedConfig;
 pub use web_config::WebConfig;
@@ -1,12 +1,13 @@
 use deadpool_lapin::{Config, Pool, Runtime};
 use tracing::{error, info};
 
+use super::cms_config::redact;
 use crate::{
     domain::{HandleResult, handle_ok},
     error::AppError,
 };
 
-#[derive(Debug, serde::Deserialize)]
+#[derive(Debug, Default, Clone, serde::Deserialize, serde::Serialize)]
 pub struct RabbitMQConfig {
     pub host: Option<String>,
     pub port: Option<u16>,
@@ -27,6 +28,14 @@ impl RabbitMQConfig {
         }
     }
 
+    /// 隐藏密码
+    pub fn redacted(&self) -> Self {
+        Self {
+            password: redact(&self.password),
+            ..self.clone()
+        }
+    }
+
     /// 构建数据库连接URL
     pub fn url(&self) -> String {
         let username = self.username.as_deref().unwrap_or_default();
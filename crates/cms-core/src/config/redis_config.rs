@@ -1,10 +1,11 @@
 use redis::{Client, aio::ConnectionManager};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use tracing::error;
 
+use super::cms_config::redact;
 use crate::domain::{HandleResult, handle_ok};
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
 pub struct RedisConfig {
     host: Option<String>,
     port: Option<u16>,
@@ -25,6 +26,14 @@ impl RedisConfig {
         }
     }
 
+    /// 隐藏密码
+    pub fn redacted(&self) -> Self {
+        Self {
+            password: redact(&self.password),
+            ..self.clone()
+        }
+    }
+
     /// 构建 Redis 连接 URL
     pub fn url(&self) -> String {
         let username = self.username.as_deref().unwrap_or_default();
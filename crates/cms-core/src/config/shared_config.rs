@@ -0,0 +1,50 @@
+use std::sync::{Arc, PoisonError, RwLock};
+
+use super::CmsConfig;
+
+/// 运行中的配置，热加载时整体替换
+#[derive(Debug, Clone, Default)]
+pub struct SharedConfig {
+    inner: Arc<RwLock<Arc<CmsConfig>>>,
+}
+
+impl SharedConfig {
+    pub fn new(config: CmsConfig) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(Arc::new(config))),
+        }
+    }
+
+    /// 当前配置，热加载不影响已取得的配置
+    pub fn current(&self) -> Arc<CmsConfig> {
+        self.inner
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// 替换为新的配置
+    pub fn replace(&self, config: CmsConfig) {
+        *self.inner.write().unwrap_or_else(PoisonError::into_inner) = Arc::new(config);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replace() {
+        let shared = SharedConfig::default();
+        let before = shared.current();
+        let cloned = shared.clone();
+
+        let mut config = CmsConfig::default();
+        config.db.name = "salvo_cms".to_string();
+        shared.replace(config);
+
+        assert_eq!(before.db.name, "");
+        assert_eq!(shared.current().db.name, "salvo_cms");
+        assert_eq!(cloned.current().db.name, "salvo_cms");
+    }
+}
@@ -1,14 +1,27 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::Duration;
 use tracing::{Level, warn};
 
-#[derive(Deserialize, Debug, Default)]
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
 pub struct WebConfig {
     name: Option<String>,
     host: Option<String>,
     port: Option<u16>,
     version: Option<String>,
     log_level: Option<String>,
+    /// 日志格式：text 或 json
+    log_format: Option<String>,
     description: Option<String>,
+    /// 允许跨域的来源，为空时允许所有来源
+    #[serde(default)]
+    cors_origins: Vec<String>,
+    /// 关闭时等待处理中请求的最长时间（秒）
+    shutdown_timeout: Option<u64>,
+    /// OTLP/HTTP 采集器地址，如 `http://localhost:4318`，为空时不导出链路追踪
+    otlp_endpoint: Option<String>,
+    /// 后台导出任务的文件目录，多个服务共用时需要使用共享存储
+    export_dir: Option<String>,
 }
 
 impl WebConfig {
@@ -26,6 +39,72 @@ impl WebConfig {
         Ok(config)
     }
 
+    /// 校验配置，返回全部错误
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        if self.host.as_deref().unwrap_or_default().is_empty() {
+            errors.push("web.host is required".to_string());
+        }
+        if self.port.unwrap_or_default() < 1000 {
+            errors.push("web.port must be between 1000 and 65535".to_string());
+        }
+        let log_level = self.log_level.as_deref().unwrap_or_default();
+        if !["", "debug", "info", "warn", "error"].contains(&log_level.to_lowercase().as_str()) {
+            errors.push(format!(
+                "web.log_level '{}' must be one of debug, info, warn, error",
+                log_level
+            ));
+        }
+        if !(1..=300).contains(&self.shutdown_timeout.unwrap_or(30)) {
+            errors.push("web.shutdown_timeout must be between 1 and 300 seconds".to_string());
+        }
+        let log_format = self.log_format.as_deref().unwrap_or_default();
+        if !["", "text", "json"].contains(&log_format.to_lowercase().as_str()) {
+            errors.push(format!(
+                "web.log_format '{}' must be one of text, json",
+                log_format
+            ));
+        }
+        if let Some(endpoint) = self.otlp_endpoint()
+            && !endpoint.starts_with("http://")
+            && !endpoint.starts_with("https://")
+        {
+            errors.push(format!(
+                "web.otlp_endpoint '{}' must start with http:// or https://",
+                endpoint
+            ));
+        }
+        for origin in self.cors_origins() {
+            if origin != "*" && !origin.starts_with("http://") && !origin.starts_with("https://") {
+                errors.push(format!(
+                    "web.cors_origins '{}' must start with http:// or https://",
+                    origin
+                ));
+            }
+        }
+        errors
+    }
+
+    /// 更新可热加载的配置：日志级别和跨域来源
+    pub fn reload_from(&mut self, other: &Self) {
+        self.log_level = other.log_level.clone();
+        self.cors_origins = other.cors_origins.clone();
+    }
+
+    /// 允许跨域的来源
+    pub fn cors_origins(&self) -> impl Iterator<Item = &str> {
+        self.cors_origins
+            .iter()
+            .map(|origin| origin.trim())
+            .filter(|origin| !origin.is_empty())
+    }
+
+    /// 来源是否允许跨域
+    pub fn is_origin_allowed(&self, origin: &str) -> bool {
+        let mut origins = self.cors_origins().peekable();
+        origins.peek().is_none() || origins.any(|item| item == "*" || item == origin)
+    }
+
     fn get_default(value: &Option<String>, default: &str) -> String {
         let val = value.as_deref().unwrap_or_default();
         if val.is_empty() {
@@ -55,6 +134,43 @@ impl WebConfig {
         Self::get_default(&self.description, "A simple CMS")
     }
 
+    /// 关闭时等待处理中请求的最长时间，默认 30 秒
+    pub fn shutdown_timeout(&self) -> Duration {
+        Duration::from_secs(self.shutdown_timeout.unwrap_or(30))
+    }
+
+    /// 后台导出任务的文件目录，默认 `storage/exports`
+    pub fn export_dir(&self) -> PathBuf {
+        PathBuf::from(Self::get_default(&self.export_dir, "storage/exports"))
+    }
+
+    /// 是否输出 JSON 格式的日志
+    pub fn is_json_log(&self) -> bool {
+        self.log_format
+            .as_deref()
+            .is_some_and(|format| format.eq_ignore_ascii_case("json"))
+    }
+
+    /// OTLP/HTTP 采集器地址，未配置时返回 None
+    pub fn otlp_endpoint(&self) -> Option<&str> {
+        self.otlp_endpoint
+            .as_deref()
+            .map(|endpoint| endpoint.trim())
+            .filter(|endpoint| !endpoint.is_empty())
+    }
+
+    /// 链路追踪的导出地址，采集器地址后追加 `/v1/traces`
+    pub fn otlp_traces_endpoint(&self) -> Option<String> {
+        self.otlp_endpoint().map(|endpoint| {
+            let endpoint = endpoint.trim_end_matches('/');
+            if endpoint.ends_with("/v1/traces") {
+                endpoint.to_string()
+            } else {
+                format!("{}/v1/traces", endpoint)
+            }
+        })
+    }
+
     pub fn tracing_level(&self) -> Level {
         let name = self.log_level.as_deref().unwrap_or("info");
 
@@ -205,4 +321,107 @@ mod tests {
         config.log_level = Some("debug".to_string());
         assert_eq!(config.tracing_level(), Level::DEBUG);
     }
+
+    #[test]
+    fn test_web_config_log_format() {
+        let mut config = WebConfig::default();
+        assert!(!config.is_json_log());
+
+        config.log_format = Some("JSON".to_string());
+        assert!(config.is_json_log());
+        assert!(config.validate().iter().all(|e| !e.contains("log_format")));
+
+        config.log_format = Some("xml".to_string());
+        assert!(!config.is_json_log());
+        assert!(config.validate().iter().any(|e| e.contains("log_format")));
+    }
+
+    #[test]
+    fn test_web_config_shutdown_timeout() {
+        let mut config = WebConfig::default();
+        assert_eq!(config.shutdown_timeout(), Duration::from_secs(30));
+
+        config.shutdown_timeout = Some(5);
+        assert_eq!(config.shutdown_timeout(), Duration::from_secs(5));
+        assert!(config.validate().iter().all(|e| !e.contains("shutdown")));
+
+        config.shutdown_timeout = Some(0);
+        assert!(
+            config
+                .validate()
+                .iter()
+                .any(|e| e.contains("shutdown_timeout"))
+        );
+    }
+
+    #[test]
+    fn test_web_config_export_dir() {
+        let mut config = WebConfig::default();
+        assert_eq!(config.export_dir(), PathBuf::from("storage/exports"));
+
+        config.export_dir = Some("/mnt/exports".to_string());
+        assert_eq!(config.export_dir(), PathBuf::from("/mnt/exports"));
+    }
+
+    #[test]
+    fn test_web_config_otlp_endpoint() {
+        let mut config = WebConfig::default();
+        assert_eq!(config.otlp_traces_endpoint(), None);
+
+        config.otlp_endpoint = Some(" ".to_string());
+        assert_eq!(config.otlp_endpoint(), None);
+
+        config.otlp_endpoint = Some("http://localhost:4318/".to_string());
+        assert_eq!(
+            config.otlp_traces_endpoint().unwrap(),
+            "http://localhost:4318/v1/traces"
+        );
+        assert!(config.validate().iter().all(|e| !e.contains("otlp")));
+
+        config.otlp_endpoint = Some("https://otel.example.com/v1/traces".to_string());
+        assert_eq!(
+            config.otlp_traces_endpoint().unwrap(),
+            "https://otel.example.com/v1/traces"
+        );
+
+        config.otlp_endpoint = Some("localhost:4318".to_string());
+        assert!(
+            config
+                .validate()
+                .iter()
+                .any(|e| e.contains("otlp_endpoint"))
+        );
+    }
+
+    #[test]
+    fn test_web_config_is_origin_allowed() {
+        let mut config = WebConfig::default();
+        assert!(config.is_origin_allowed("https://a.example.com"));
+
+        config.cors_origins = vec!["https://a.example.com".to_string(), " ".to_string()];
+        assert!(config.is_origin_allowed("https://a.example.com"));
+        assert!(!config.is_origin_allowed("https://b.example.com"));
+        assert!(config.validate().iter().all(|e| !e.contains("cors")));
+
+        config.cors_origins.push("*".to_string());
+        assert!(config.is_origin_allowed("https://b.example.com"));
+
+        config.cors_origins = vec!["a.example.com".to_string()];
+        assert!(config.validate().iter().any(|e| e.contains("cors_origins")));
+    }
+
+    #[test]
+    fn test_web_config_reload_from() {
+        let mut config = WebConfig::default();
+        let other = WebConfig {
+            port: Some(6000),
+            log_level: Some("debug".to_string()),
+            cors_origins: vec!["https://a.example.com".to_string()],
+            ..Default::default()
+        };
+        config.reload_from(&other);
+        assert_eq!(config.tracing_level(), Level::DEBUG);
+        assert_eq!(config.cors_origins, other.cors_origins);
+        assert_eq!(config.port, None);
+    }
 }
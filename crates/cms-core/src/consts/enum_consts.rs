@@ -11,6 +11,7 @@ pub const ENABLE_TRUE_TITLE: &str = "启用";
 pub const ENABLE_FALSE_TITLE: &str = "禁用";
 
 pub const ERROR_VERSION_INVALID_MESSAGE: &str = "版本号错误";
+pub const ERROR_VERSION_CONFLICT_MESSAGE: &str = "数据已被修改，请刷新后重试";
 pub const ERROR_NAME_EXISTS_MESSAGE: &str = "名称已存在";
 pub const ERROR_TITLE_EXISTS_MESSAGE: &str = "标题已存在";
 pub const ERROR_FIELD_INVALID_MESSAGE: &str = "无效的字段";
@@ -18,6 +19,18 @@ pub const ERROR_PARAM_ID_INVALID_MESSAGE: &str = "参数ID错误";
 pub const ERROR_UPDATE_FIELD_INVALID_MESSAGE: &str = "更新字段错误";
 pub const ERROR_RECORD_NOT_FOUND_MESSAGE: &str = "访问记录不存在";
 pub const ERROR_NO_PERMISSION_DELETE_MESSAGE: &str = "无权限删除";
+pub const ERROR_NO_PERMISSION_UPDATE_MESSAGE: &str = "无权限更新";
+pub const ERROR_CURSOR_INVALID_MESSAGE: &str = "无效的分页游标";
+pub const ERROR_SORT_INVALID_MESSAGE: &str = "无效的排序字段";
+pub const ERROR_CURSOR_WITH_SORT_MESSAGE: &str = "游标分页不支持指定排序";
+pub const ERROR_FILTER_INVALID_MESSAGE: &str = "无效的筛选条件";
+pub const ERROR_INCLUDE_INVALID_MESSAGE: &str = "无效的关联数据";
+pub const ERROR_EXPORT_FORMAT_INVALID_MESSAGE: &str = "无效的导出格式";
+pub const ERROR_EXPORT_NOT_READY_MESSAGE: &str = "导出文件尚未生成";
+pub const ERROR_IMPORT_FILE_INVALID_MESSAGE: &str = "无效的导入文件";
+pub const ERROR_IMPORT_DUPLICATE_MESSAGE: &str = "导入数据重复";
+pub const ERROR_IDEMPOTENCY_KEY_REUSED_MESSAGE: &str = "幂等键已用于其他请求";
+pub const ERROR_IDEMPOTENCY_PROCESSING_MESSAGE: &str = "相同的请求正在处理中";
 
 pub const VIEW_MODEL_OPEN_TITLE: &str = "用户端";
 pub const VIEW_MODEL_MANAGER_TITLE: &str = "管理端";
@@ -32,3 +45,45 @@ pub const TOKEN_TYPE_NONE_TITLE: &str = "Invalid Token";
 pub const TOKEN_TYPE_ACCESS_VALUE: &str = "access_token";
 pub const TOKEN_TYPE_REFRESH_VALUE: &str = "refresh_token";
 pub const TOKEN_TYPE_NONE_VALUE: &str = "none";
+
+pub const AUDIT_ACTION_CREATE_TITLE: &str = "创建";
+pub const AUDIT_ACTION_UPDATE_TITLE: &str = "更新";
+pub const AUDIT_ACTION_DELETE_TITLE: &str = "删除";
+pub const AUDIT_ACTION_RESTORE_TITLE: &str = "恢复";
+pub const AUDIT_ACTION_REVERT_TITLE: &str = "回滚";
+pub const AUDIT_ACTION_PURGE_TITLE: &str = "彻底删除";
+pub const AUDIT_ACTION_CREATE_VALUE: &str = "create";
+pub const AUDIT_ACTION_UPDATE_VALUE: &str = "update";
+pub const AUDIT_ACTION_DELETE_VALUE: &str = "delete";
+pub const AUDIT_ACTION_RESTORE_VALUE: &str = "restore";
+pub const AUDIT_ACTION_REVERT_VALUE: &str = "revert";
+pub const AUDIT_ACTION_PURGE_VALUE: &str = "purge";
+
+pub const FILTER_OPERATOR_EQ_TITLE: &str = "等于";
+pub const FILTER_OPERATOR_NE_TITLE: &str = "不等于";
+pub const FILTER_OPERATOR_GT_TITLE: &str = "大于";
+pub const FILTER_OPERATOR_GTE_TITLE: &str = "大于等于";
+pub const FILTER_OPERATOR_LT_TITLE: &str = "小于";
+pub const FILTER_OPERATOR_LTE_TITLE: &str = "小于等于";
+pub const FILTER_OPERATOR_LIKE_TITLE: &str = "包含";
+pub const FILTER_OPERATOR_IN_TITLE: &str = "在列表中";
+pub const FILTER_OPERATOR_NULL_TITLE: &str = "是否为空";
+pub const FILTER_OPERATOR_EQ_VALUE: &str = "eq";
+pub const FILTER_OPERATOR_NE_VALUE: &str = "ne";
+pub const FILTER_OPERATOR_GT_VALUE: &str = "gt";
+pub const FILTER_OPERATOR_GTE_VALUE: &str = "gte";
+pub const FILTER_OPERATOR_LT_VALUE: &str = "lt";
+pub const FILTER_OPERATOR_LTE_VALUE: &str = "lte";
+pub const FILTER_OPERATOR_LIKE_VALUE: &str = "like";
+pub const FILTER_OPERATOR_IN_VALUE: &str = "in";
+pub const FILTER_OPERATOR_NULL_VALUE: &str = "null";
+
+pub const EXPORT_FORMAT_CSV_TITLE: &str = "CSV";
+pub const EXPORT_FORMAT_NDJSON_TITLE: &str = "JSON Lines";
+pub const EXPORT_FORMAT_CSV_VALUE: &str = "csv";
+pub const EXPORT_FORMAT_NDJSON_VALUE: &str = "ndjson";
+
+pub const EXPORT_STATUS_PENDING_VALUE: &str = "pending";
+pub const EXPORT_STATUS_RUNNING_VALUE: &str = "running";
+pub const EXPORT_STATUS_FINISHED_VALUE: &str = "finished";
+pub const EXPORT_STATUS_FAILED_VALUE: &str = "failed";
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::query::AuditPaginateQuery;
+
+/// Audit Query DTO
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+pub struct AuditQueryDTO {
+    /// 页码
+    pub page: u64,
+
+    /// 每页数量
+    pub page_size: u64,
+
+    /// 实体类型
+    pub entity_type: String,
+
+    /// 实体ID
+    pub entity_id: i64,
+}
+
+impl From<AuditPaginateQuery> for AuditQueryDTO {
+    fn from(model: AuditPaginateQuery) -> Self {
+        Self {
+            page: model.page,
+            page_size: model.page_size,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+use super::EditorCurrentDTO;
+use crate::enums::AuditActionEnum;
+
+/// Audit Store DTO
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct AuditStoreDTO {
+    /// 实体类型
+    pub entity_type: String,
+
+    /// 实体ID
+    pub entity_id: i64,
+
+    /// 操作类型
+    pub action: AuditActionEnum,
+
+    /// 编辑用户
+    pub editor: EditorCurrentDTO,
+
+    /// 版本号
+    pub version_no: i32,
+
+    /// 变更字段
+    pub changes: JsonValue,
+}
@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::dto::EditorCurrentDTO;
+use crate::domain::form::{BulkBoolUpdateForm, BulkIdsForm, BulkMoveForm, BulkSortForm};
+
+/// 批量操作 DTO
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+pub struct BulkUpdateDTO<T> {
+    /// 编辑用户
+    pub editor: EditorCurrentDTO,
+
+    /// 字段名
+    pub field_name: String,
+
+    /// 记录ID及对应的字段值，ID不重复
+    pub items: Vec<(i64, T)>,
+}
+
+impl<T> BulkUpdateDTO<T> {
+    fn from_items(field_name: &str, items: impl IntoIterator<Item = (i64, T)>) -> Self {
+        let mut list: Vec<(i64, T)> = Vec::new();
+        for (id, value) in items {
+            if !list.iter().any(|(item_id, _)| *item_id == id) {
+                list.push((id, value));
+            }
+        }
+        Self {
+            editor: EditorCurrentDTO::default(),
+            field_name: field_name.to_owned(),
+            items: list,
+        }
+    }
+
+    /// 记录ID
+    pub fn ids(&self) -> Vec<i64> {
+        self.items.iter().map(|(id, _)| *id).collect()
+    }
+}
+
+impl From<BulkIdsForm> for BulkUpdateDTO<()> {
+    fn from(form: BulkIdsForm) -> Self {
+        Self::from_items("", form.ids.into_iter().map(|id| (id, ())))
+    }
+}
+
+impl From<BulkBoolUpdateForm> for BulkUpdateDTO<bool> {
+    fn from(form: BulkBoolUpdateForm) -> Self {
+        let value = form.field_value.unwrap_or_default();
+        Self::from_items(&form.field_name, form.ids.into_iter().map(|id| (id, value)))
+    }
+}
+
+impl From<BulkMoveForm> for BulkUpdateDTO<i64> {
+    fn from(form: BulkMoveForm) -> Self {
+        let value = form.field_value;
+        Self::from_items(&form.field_name, form.ids.into_iter().map(|id| (id, value)))
+    }
+}
+
+impl From<BulkSortForm> for BulkUpdateDTO<i64> {
+    fn from(form: BulkSortForm) -> Self {
+        Self::from_items(
+            "sort",
+            form.items.into_iter().map(|item| (item.id, item.sort)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::form::BulkSortItemForm;
+
+    #[test]
+    fn test_from_ids_form_dedup() {
+        let form = BulkIdsForm {
+            ids: vec![3, 1, 3, 2],
+        };
+        let dto: BulkUpdateDTO<()> = form.into();
+        assert_eq!(dto.ids(), vec![3, 1, 2]);
+        assert_eq!(dto.field_name, "");
+    }
+
+    #[test]
+    fn test_from_bool_form() {
+        let form = BulkBoolUpdateForm {
+            ids: vec![1, 2],
+            field_name: "is_enabled".to_string(),
+            field_value: Some(true),
+        };
+        let dto: BulkUpdateDTO<bool> = form.into();
+        assert_eq!(dto.field_name, "is_enabled");
+        assert_eq!(dto.items, vec![(1, true), (2, true)]);
+    }
+
+    #[test]
+    fn test_from_move_form() {
+        let form = BulkMoveForm {
+            ids: vec![1, 2],
+            field_name: "kind_id".to_string(),
+            field_value: 5,
+        };
+        let dto: BulkUpdateDTO<i64> = form.into();
+        assert_eq!(dto.field_name, "kind_id");
+        assert_eq!(dto.items, vec![(1, 5), (2, 5)]);
+    }
+
+    #[test]
+    fn test_from_sort_form() {
+        let form = BulkSortForm {
+            items: vec![
+                BulkSortItemForm { id: 1, sort: 20 },
+                BulkSortItemForm { id: 2, sort: 10 },
+                BulkSortItemForm { id: 1, sort: 5 },
+            ],
+        };
+        let dto: BulkUpdateDTO<i64> = form.into();
+        assert_eq!(dto.field_name, "sort");
+        assert_eq!(dto.items, vec![(1, 20), (2, 10)]);
+    }
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+/// Config Change DTO
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+pub struct ConfigChangeDTO {
+    /// 已热加载的配置项，格式为 `web.log_level: "info" -> "debug"`
+    pub reloaded: Vec<String>,
+    /// 已修改但需要重启才能生效的配置项
+    pub restart_required: Vec<String>,
+}
+
+impl ConfigChangeDTO {
+    /// 配置是否有变化
+    pub fn is_empty(&self) -> bool {
+        self.reloaded.is_empty() && self.restart_required.is_empty()
+    }
+}
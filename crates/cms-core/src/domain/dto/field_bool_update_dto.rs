@@ -16,6 +16,9 @@ pub struct FieldBoolUpdateDTO {
 
     /// 字段值
     pub field_value: Option<bool>,
+
+    /// 客户端提交的版本号
+    pub version_no: Option<i32>,
 }
 
 impl FieldBoolUpdateDTO {
@@ -0,0 +1,141 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+/// 返回字段集合
+///
+/// 从 `fields=id,title,kind.title` 中解析，`.` 分隔关联数据的字段，为空时返回全部字段。
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+pub struct FieldSetDTO {
+    /// 字段名
+    pub fields: Vec<String>,
+}
+
+impl FieldSetDTO {
+    /// 解析字段参数，忽略空字段和重复字段
+    pub fn parse(fields: &str) -> Self {
+        let mut list: Vec<String> = Vec::new();
+        for field in fields.split(',') {
+            let field = field.trim().to_lowercase();
+            if !field.is_empty() && !list.contains(&field) {
+                list.push(field);
+            }
+        }
+        Self { fields: list }
+    }
+
+    /// 是否未指定字段
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// 当前层级的字段，如 `id`、`title`
+    pub fn roots(&self) -> Vec<&str> {
+        self.fields
+            .iter()
+            .filter(|field| !field.contains('.'))
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// 引用的关联数据，如 `kind.title` 中的 `kind`
+    pub fn relations(&self) -> Vec<&str> {
+        let mut list: Vec<&str> = Vec::new();
+        for field in &self.fields {
+            if let Some((relation, _)) = field.split_once('.')
+                && !list.contains(&relation)
+            {
+                list.push(relation);
+            }
+        }
+        list
+    }
+
+    /// 关联数据的字段集合
+    pub fn nested(&self, relation: &str) -> Self {
+        let prefix = format!("{}.", relation);
+        let fields = self
+            .fields
+            .iter()
+            .filter_map(|field| field.strip_prefix(&prefix))
+            .map(String::from)
+            .collect();
+        Self { fields }
+    }
+
+    /// 裁剪 JSON 对象，只保留指定字段，数组中的对象逐个裁剪
+    pub fn apply(&self, json: &mut JsonValue) {
+        if self.is_empty() {
+            return;
+        }
+
+        match json {
+            JsonValue::Object(map) => map.retain(|key, value| {
+                if self.fields.iter().any(|field| field == key) {
+                    return true;
+                }
+                let nested = self.nested(key);
+                if nested.is_empty() {
+                    return false;
+                }
+                nested.apply(value);
+                true
+            }),
+            JsonValue::Array(list) => {
+                for item in list.iter_mut() {
+                    self.apply(item);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse() {
+        let dto = FieldSetDTO::parse(" id, Title,,kind.title,id");
+        assert_eq!(dto.fields, vec!["id", "title", "kind.title"]);
+        assert!(FieldSetDTO::parse("").is_empty());
+    }
+
+    #[test]
+    fn test_roots_and_relations() {
+        let dto = FieldSetDTO::parse("id,kind.title,kind.name,app.title");
+        assert_eq!(dto.roots(), vec!["id"]);
+        assert_eq!(dto.relations(), vec!["kind", "app"]);
+        assert_eq!(dto.nested("kind").fields, vec!["title", "name"]);
+    }
+
+    #[test]
+    fn test_apply() {
+        let dto = FieldSetDTO::parse("id,title,kind.title");
+        let mut json = json!({
+            "id": 1,
+            "title": "item",
+            "name": "item",
+            "kind": {"id": 2, "title": "kind"},
+            "app": {"id": 3},
+        });
+        dto.apply(&mut json);
+        assert_eq!(
+            json,
+            json!({"id": 1, "title": "item", "kind": {"title": "kind"}})
+        );
+    }
+
+    #[test]
+    fn test_apply_array_and_empty() {
+        let dto = FieldSetDTO::parse("id");
+        let mut json = json!([{"id": 1, "title": "a"}, {"id": 2, "title": "b"}]);
+        dto.apply(&mut json);
+        assert_eq!(json, json!([{"id": 1}, {"id": 2}]));
+
+        let mut json = json!({"id": 1, "title": "a"});
+        FieldSetDTO::default().apply(&mut json);
+        assert_eq!(json, json!({"id": 1, "title": "a"}));
+    }
+}
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+/// 幂等请求记录
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+pub struct IdempotencyRecordDTO {
+    /// 请求指纹：方法、路径和请求体的摘要
+    pub fingerprint: String,
+
+    /// 响应状态码，为空表示请求正在处理
+    pub status: Option<u16>,
+
+    /// 响应内容
+    pub body: Option<String>,
+}
+
+impl IdempotencyRecordDTO {
+    /// 处理中的记录
+    pub fn processing(fingerprint: &str) -> Self {
+        Self {
+            fingerprint: fingerprint.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    /// 是否正在处理
+    pub fn is_processing(&self) -> bool {
+        self.status.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_processing() {
+        let record = IdempotencyRecordDTO::processing("abc");
+        assert!(record.is_processing());
+
+        let record = IdempotencyRecordDTO {
+            status: Some(200),
+            body: Some("{}".to_string()),
+            ..record
+        };
+        assert!(!record.is_processing());
+        assert_eq!(record.fingerprint, "abc");
+    }
+}
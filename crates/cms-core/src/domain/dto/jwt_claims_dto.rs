@@ -8,6 +8,9 @@ pub struct JwtClaimsDTO {
     pub user_type: String,
     pub token_type: String,
     pub exp: i64,
+    /// 租户ID，启用多租户前签发的 Token 没有该字段
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_id: Option<i64>,
 }
 
 #[cfg(test)]
@@ -32,6 +35,7 @@ mod tests {
             user_type: "admin".to_string(),
             token_type: "bearer".to_string(),
             exp: 1717029203,
+            tenant_id: None,
         };
         let cloned = claims.clone();
         assert_eq!(claims, cloned);
@@ -45,6 +49,7 @@ mod tests {
             user_type: "admin".to_string(),
             token_type: "bearer".to_string(),
             exp: 1717029203,
+            tenant_id: None,
         };
         let json_str = serde_json::to_string(&claims).unwrap();
         let expected_json = r#"{"uuid":"uuid123","user_id":"user123","user_type":"admin","token_type":"bearer","exp":1717029203}"#;
@@ -70,6 +75,7 @@ mod tests {
             user_type: "admin".to_string(),
             token_type: "bearer".to_string(),
             exp: 1717029203,
+            tenant_id: None,
         };
 
         let claims2 = claims1.clone();
@@ -81,4 +87,18 @@ mod tests {
         assert!(claims1 == claims2); // Same values
         assert!(claims1 != claims3); // Different uuid
     }
+
+    #[test]
+    fn test_tenant_id() {
+        let json_str = r#"{"uuid":"uuid123","user_id":"user123","user_type":"admin","token_type":"bearer","exp":1717029203}"#;
+        let claims: JwtClaimsDTO = serde_json::from_str(json_str).unwrap();
+        assert_eq!(claims.tenant_id, None);
+
+        let claims = JwtClaimsDTO {
+            tenant_id: Some(2),
+            ..claims
+        };
+        let json_str = serde_json::to_string(&claims).unwrap();
+        assert!(json_str.ends_with(r#""exp":1717029203,"tenant_id":2}"#));
+    }
 }
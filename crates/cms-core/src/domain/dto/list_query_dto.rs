@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+
+use crate::enums::FilterOperatorEnum;
+
+/// 列表排序项
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+pub struct ListSortDTO {
+    /// 字段名
+    pub field: String,
+
+    /// 是否倒序
+    pub desc: bool,
+}
+
+impl ListSortDTO {
+    /// 解析排序参数，如 `-created_at,title`，`-` 前缀表示倒序
+    pub fn parse_list(sort: &str) -> Vec<Self> {
+        sort.split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(|item| match item.strip_prefix('-') {
+                Some(field) => Self {
+                    field: field.trim().to_lowercase(),
+                    desc: true,
+                },
+                None => Self {
+                    field: item.trim_start_matches('+').to_lowercase(),
+                    desc: false,
+                },
+            })
+            .collect()
+    }
+}
+
+/// 列表筛选项
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct ListFilterDTO {
+    /// 字段名
+    pub field: String,
+
+    /// 操作符，为空表示操作符无效
+    pub operator: Option<FilterOperatorEnum>,
+
+    /// 筛选值
+    pub value: String,
+}
+
+impl ListFilterDTO {
+    /// 解析筛选参数，如 `filter[is_enabled]=true`、`filter[created_at][gte]=2025-01-01`，
+    /// 未指定操作符时为 `eq`，非 `filter[` 开头的参数返回空
+    pub fn parse(key: &str, value: &str) -> Option<Self> {
+        let rest = key.strip_prefix("filter[")?;
+        let (field, rest) = rest.split_once(']')?;
+        let operator = match rest {
+            "" => Some(FilterOperatorEnum::Eq),
+            _ => rest
+                .strip_prefix('[')
+                .and_then(|rest| rest.strip_suffix(']'))
+                .and_then(FilterOperatorEnum::from_string),
+        };
+
+        Some(Self {
+            field: field.trim().to_lowercase(),
+            operator,
+            value: value.trim().to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sort_parse_list() {
+        let list = ListSortDTO::parse_list("-created_at, title,,+sort");
+        assert_eq!(
+            list,
+            vec![
+                ListSortDTO {
+                    field: "created_at".to_string(),
+                    desc: true,
+                },
+                ListSortDTO {
+                    field: "title".to_string(),
+                    desc: false,
+                },
+                ListSortDTO {
+                    field: "sort".to_string(),
+                    desc: false,
+                },
+            ]
+        );
+        assert!(ListSortDTO::parse_list("").is_empty());
+    }
+
+    #[test]
+    fn test_filter_parse_default_operator() {
+        let filter = ListFilterDTO::parse("filter[is_enabled]", "true").unwrap();
+        assert_eq!(filter.field, "is_enabled");
+        assert_eq!(filter.operator, Some(FilterOperatorEnum::Eq));
+        assert_eq!(filter.value, "true");
+    }
+
+    #[test]
+    fn test_filter_parse_with_operator() {
+        let filter = ListFilterDTO::parse("filter[created_at][gte]", "2025-01-01").unwrap();
+        assert_eq!(filter.field, "created_at");
+        assert_eq!(filter.operator, Some(FilterOperatorEnum::Gte));
+        assert_eq!(filter.value, "2025-01-01");
+    }
+
+    #[test]
+    fn test_filter_parse_invalid() {
+        assert!(ListFilterDTO::parse("page", "1").is_none());
+        assert!(ListFilterDTO::parse("filter[title", "a").is_none());
+
+        let filter = ListFilterDTO::parse("filter[title][between]", "a").unwrap();
+        assert_eq!(filter.operator, None);
+
+        let filter = ListFilterDTO::parse("filter[title]gte", "a").unwrap();
+        assert_eq!(filter.operator, None);
+    }
+}
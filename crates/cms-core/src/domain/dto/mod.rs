@@ -1,17 +1,35 @@
+mod audit_query_dto;
+mod audit_store_dto;
+mod bulk_update_dto;
+mod config_change_dto;
 mod editor_current_dto;
 mod field_bool_update_dto;
+mod field_set_dto;
 mod field_value_unique_dto;
+mod idempotency_record_dto;
 mod jwt_claims_dto;
 mod jwt_token_dto;
+mod list_query_dto;
 mod model_logic_delete_dto;
 mod model_relation_count_dto;
 mod model_view_dto;
+mod resource_dto;
+mod tenant_current_dto;
 
+pub use audit_query_dto::AuditQueryDTO;
+pub use audit_store_dto::AuditStoreDTO;
+pub use bulk_update_dto::BulkUpdateDTO;
+pub use config_change_dto::ConfigChangeDTO;
 pub use editor_current_dto::EditorCurrentDTO;
 pub use field_bool_update_dto::FieldBoolUpdateDTO;
+pub use field_set_dto::FieldSetDTO;
 pub use field_value_unique_dto::FieldValueUniqueDTO;
+pub use idempotency_record_dto::IdempotencyRecordDTO;
 pub use jwt_claims_dto::JwtClaimsDTO;
 pub use jwt_token_dto::JwtTokenDTO;
+pub use list_query_dto::{ListFilterDTO, ListSortDTO};
 pub use model_logic_delete_dto::ModelLogicDeleteDTO;
 pub use model_relation_count_dto::ModelRelationCountDTO;
 pub use model_view_dto::ModelViewDTO;
+pub use resource_dto::{ResourceQueryDTO, ResourceStoreDTO};
+pub use tenant_current_dto::{DEFAULT_TENANT_ID, TenantCurrentDTO};
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use super::EditorCurrentDTO;
+use super::{EditorCurrentDTO, FieldSetDTO};
 use crate::enums::PrimaryIdEnum;
 
 /// Model View DTO
@@ -17,6 +17,9 @@ pub struct ModelViewDTO<T> {
 
     /// 加载关联数据
     pub load_models: Option<Vec<T>>,
+
+    /// 返回字段，不为空时只查询相关的列
+    pub fields: Option<FieldSetDTO>,
 }
 
 #[cfg(test)]
@@ -41,6 +44,7 @@ mod tests {
             editor: EditorCurrentDTO::default(),
             enabled: Some(true),
             load_models: Some(vec![1, 2, 3]),
+            fields: Some(FieldSetDTO::parse("id,title")),
         }
     }
 
@@ -76,6 +80,7 @@ mod tests {
         assert_eq!(dto.editor, EditorCurrentDTO::default());
         assert_eq!(dto.enabled, None);
         assert_eq!(dto.load_models, None);
+        assert_eq!(dto.fields, None);
     }
 
     #[test]
@@ -85,6 +90,7 @@ mod tests {
             editor: EditorCurrentDTO::default(),
             enabled: Some(false),
             load_models: Some(vec![DummyStruct { value: 10 }, DummyStruct { value: 20 }]),
+            fields: None,
         };
 
         let serialized = serde_json::to_string(&dto).unwrap();
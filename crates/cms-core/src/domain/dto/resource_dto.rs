@@ -0,0 +1,58 @@
+use super::{EditorCurrentDTO, FieldSetDTO, ListFilterDTO, ListSortDTO};
+
+/// 资源创建/更新 DTO
+pub trait ResourceStoreDTO {
+    /// 主键，小于1表示新建
+    fn id(&self) -> i64;
+
+    /// 客户端提交的版本号
+    fn version_no(&self) -> Option<i32> {
+        None
+    }
+
+    /// 编辑用户
+    fn editor(&self) -> &EditorCurrentDTO;
+
+    /// 设置编辑用户
+    fn set_editor(&mut self, editor: EditorCurrentDTO);
+}
+
+/// 资源分页查询 DTO
+pub trait ResourceQueryDTO<T> {
+    /// 页码
+    fn page(&self) -> u64;
+
+    /// 每页数量
+    fn page_size(&self) -> u64;
+
+    /// 分页游标，不为空时使用游标分页，空字符串表示第一页，不能与排序项一起使用
+    fn cursor(&self) -> Option<&str> {
+        None
+    }
+
+    /// 是否统计总条数
+    fn with_total(&self) -> bool {
+        true
+    }
+
+    /// 排序项
+    fn sorts(&self) -> &[ListSortDTO] {
+        &[]
+    }
+
+    /// 筛选项
+    fn filters(&self) -> &[ListFilterDTO] {
+        &[]
+    }
+
+    /// 返回字段，不为空时只查询相关的列
+    fn fields(&self) -> Option<&FieldSetDTO> {
+        None
+    }
+
+    /// 编辑用户
+    fn editor(&self) -> &EditorCurrentDTO;
+
+    /// 加载关联数据
+    fn load_models(&self) -> Option<&Vec<T>>;
+}
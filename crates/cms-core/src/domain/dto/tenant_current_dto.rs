@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+/// 默认租户ID，未匹配到租户的请求及启用多租户前的数据属于默认租户
+pub const DEFAULT_TENANT_ID: i64 = 0;
+
+/// Current Tenant DTO
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Default)]
+pub struct TenantCurrentDTO {
+    /// 租户ID，新建的记录属于该租户
+    pub tenant_id: i64,
+    /// 跨租户查看，查询时不限制租户，仅超级管理员可用
+    pub is_cross_tenant: bool,
+}
+
+impl TenantCurrentDTO {
+    pub fn new(tenant_id: i64) -> Self {
+        Self {
+            tenant_id,
+            is_cross_tenant: false,
+        }
+    }
+
+    /// 跨租户查看，新建的记录仍属于 `tenant_id`
+    pub fn cross_tenant(tenant_id: i64) -> Self {
+        Self {
+            tenant_id,
+            is_cross_tenant: true,
+        }
+    }
+
+    /// 查询时限制的租户，跨租户查看时为空
+    pub fn scope_id(&self) -> Option<i64> {
+        if self.is_cross_tenant {
+            None
+        } else {
+            Some(self.tenant_id)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        let dto = TenantCurrentDTO::default();
+        assert_eq!(dto.tenant_id, DEFAULT_TENANT_ID);
+        assert_eq!(dto.scope_id(), Some(DEFAULT_TENANT_ID));
+    }
+
+    #[test]
+    fn test_scope_id() {
+        assert_eq!(TenantCurrentDTO::new(3).scope_id(), Some(3));
+
+        let dto = TenantCurrentDTO::cross_tenant(0);
+        assert!(dto.is_cross_tenant);
+        assert_eq!(dto.scope_id(), None);
+    }
+}
@@ -2,5 +2,7 @@
 
 pub mod prelude;
 
+pub mod audit;
 pub mod certificate;
 pub mod editor;
+pub mod tenant;
@@ -1,4 +1,6 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.4
 
+pub use super::audit::Entity as Audit;
 pub use super::certificate::Entity as Certificate;
 pub use super::editor::Entity as Editor;
+pub use super::tenant::Entity as Tenant;
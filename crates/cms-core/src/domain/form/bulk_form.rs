@@ -0,0 +1,145 @@
+use salvo::oapi::ToSchema;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// 批量删除/恢复
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default, Validate, ToSchema)]
+#[salvo(schema(name = "Core::Base::BulkIdsForm"))]
+pub struct BulkIdsForm {
+    /// 记录ID
+    #[validate(length(min = 1, max = 100, message = "记录数量必须在1-100之间"))]
+    #[salvo(schema(required = true, nullable = false, value_type = Vec<i64>, example = json!([1, 2])))]
+    pub ids: Vec<i64>,
+}
+
+/// 批量修改布尔值字段
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default, Validate, ToSchema)]
+#[salvo(schema(name = "Core::Base::BulkBoolUpdateForm"))]
+pub struct BulkBoolUpdateForm {
+    /// 记录ID
+    #[validate(length(min = 1, max = 100, message = "记录数量必须在1-100之间"))]
+    #[salvo(schema(required = true, nullable = false, value_type = Vec<i64>, example = json!([1, 2])))]
+    pub ids: Vec<i64>,
+
+    /// 字段名
+    #[validate(length(min = 2, max = 50, message = "字段名不能为空"))]
+    #[salvo(schema(required = true, nullable = false, example = "is_enabled"))]
+    pub field_name: String,
+
+    /// 字段值
+    #[validate(required(message = "字段值不能为空"))]
+    #[salvo(schema(required = true, nullable = false, value_type = bool, example = true))]
+    pub field_value: Option<bool>,
+}
+
+/// 批量移动，如修改所属类型或父级
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default, Validate, ToSchema)]
+#[salvo(schema(name = "Core::Base::BulkMoveForm"))]
+pub struct BulkMoveForm {
+    /// 记录ID
+    #[validate(length(min = 1, max = 100, message = "记录数量必须在1-100之间"))]
+    #[salvo(schema(required = true, nullable = false, value_type = Vec<i64>, example = json!([1, 2])))]
+    pub ids: Vec<i64>,
+
+    /// 字段名
+    #[validate(length(min = 2, max = 50, message = "字段名不能为空"))]
+    #[salvo(schema(required = true, nullable = false, example = "parent_id"))]
+    pub field_name: String,
+
+    /// 目标ID
+    #[validate(range(min = 0, message = "目标ID不能小于0"))]
+    #[salvo(schema(required = true, nullable = false, value_type = i64, minimum = 0, example = 1))]
+    pub field_value: i64,
+}
+
+/// 批量排序
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default, Validate, ToSchema)]
+#[salvo(schema(name = "Core::Base::BulkSortForm"))]
+pub struct BulkSortForm {
+    /// 排序项
+    #[validate(length(min = 1, max = 100, message = "记录数量必须在1-100之间"))]
+    #[salvo(schema(required = true, nullable = false))]
+    pub items: Vec<BulkSortItemForm>,
+}
+
+/// 批量排序项
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default, ToSchema)]
+#[salvo(schema(name = "Core::Base::BulkSortItemForm"))]
+pub struct BulkSortItemForm {
+    /// 记录ID
+    #[salvo(schema(required = true, nullable = false, value_type = i64, minimum = 1, example = 1))]
+    pub id: i64,
+
+    /// 排序
+    #[salvo(schema(required = true, nullable = false, value_type = i64, example = 10))]
+    pub sort: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::validate_utils::validate_error_hash;
+
+    #[test]
+    fn test_bulk_ids_form_validate() {
+        let form = BulkIdsForm { ids: vec![1, 2] };
+        assert!(form.validate().is_ok());
+
+        let form = BulkIdsForm { ids: vec![] };
+        let err = form.validate().unwrap_err();
+        let map = validate_error_hash(&err);
+        assert_eq!(map.get("ids"), Some(&"记录数量必须在1-100之间".to_string()));
+
+        let form = BulkIdsForm {
+            ids: (1..=101).collect(),
+        };
+        assert!(form.validate().is_err());
+    }
+
+    #[test]
+    fn test_bulk_bool_update_form_validate() {
+        let form = BulkBoolUpdateForm {
+            ids: vec![1],
+            field_name: "is_enabled".to_string(),
+            field_value: Some(false),
+        };
+        assert!(form.validate().is_ok());
+
+        let form = BulkBoolUpdateForm {
+            ids: vec![1],
+            field_name: "".to_string(),
+            field_value: None,
+        };
+        let err = form.validate().unwrap_err();
+        let map = validate_error_hash(&err);
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get("field_value"), Some(&"字段值不能为空".to_string()));
+    }
+
+    #[test]
+    fn test_bulk_move_form_validate() {
+        let form = BulkMoveForm {
+            ids: vec![1],
+            field_name: "parent_id".to_string(),
+            field_value: 0,
+        };
+        assert!(form.validate().is_ok());
+
+        let form = BulkMoveForm {
+            field_value: -1,
+            ..form
+        };
+        let err = form.validate().unwrap_err();
+        let map = validate_error_hash(&err);
+        assert_eq!(map.get("field_value"), Some(&"目标ID不能小于0".to_string()));
+    }
+
+    #[test]
+    fn test_bulk_sort_form_validate() {
+        let form = BulkSortForm {
+            items: vec![BulkSortItemForm { id: 1, sort: 2 }],
+        };
+        assert!(form.validate().is_ok());
+        assert!(BulkSortForm::default().validate().is_err());
+    }
+}
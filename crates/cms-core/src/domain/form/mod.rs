@@ -1,5 +1,9 @@
+mod bulk_form;
 mod field_bool_update_form;
 mod field_value_unique_form;
 
+pub use bulk_form::{
+    BulkBoolUpdateForm, BulkIdsForm, BulkMoveForm, BulkSortForm, BulkSortItemForm,
+};
 pub use field_bool_update_form::FieldBoolUpdateForm;
 pub use field_value_unique_form::FieldValueUniqueForm;
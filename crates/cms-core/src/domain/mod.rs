@@ -7,6 +7,7 @@ pub mod dto;
 pub mod entity;
 pub mod form;
 pub mod model;
+pub mod query;
 pub mod response;
 pub mod vo;
 
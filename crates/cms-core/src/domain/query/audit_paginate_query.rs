@@ -0,0 +1,32 @@
+use salvo::oapi::{ToParameters, ToSchema};
+use serde::{Deserialize, Serialize};
+
+use crate::utils::{deserializer_utils, parameter_utils};
+
+/// 变更历史查询条件
+#[derive(Deserialize, Serialize, Debug, Clone, Default, ToParameters, ToSchema)]
+#[salvo(parameters(default_parameter_in = Query))]
+#[salvo(schema(name = "Core::Base::AuditPaginateQuery"))]
+pub struct AuditPaginateQuery {
+    /// 页码
+    #[serde(
+        default = "parameter_utils::page_no_default",
+        deserialize_with = "deserializer_utils::string_to_param_page_no"
+    )]
+    #[salvo(parameter(required = false, nullable = false, default = 1, minimum = 1))]
+    pub page: u64,
+
+    /// 每页数量
+    #[serde(
+        default = "parameter_utils::page_size_default",
+        deserialize_with = "deserializer_utils::string_to_param_page_size"
+    )]
+    #[salvo(parameter(
+        required = false,
+        nullable = false,
+        default = 10,
+        minimum = 1,
+        maximum = 100
+    ))]
+    pub page_size: u64,
+}
@@ -0,0 +1,58 @@
+use salvo::oapi::{ToParameters, ToSchema};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{HandleResult, handle_ok};
+use crate::enums::{ErrorEnum, ExportFormatEnum};
+use crate::utils::deserializer_utils;
+
+/// 导出参数
+#[derive(Deserialize, Serialize, Debug, Clone, Default, ToParameters, ToSchema)]
+#[salvo(parameters(default_parameter_in = Query))]
+#[salvo(schema(name = "Core::Base::ExportQuery"))]
+pub struct ExportQuery {
+    /// 导出格式，可选 csv、ndjson，默认为 csv
+    #[salvo(parameter(required = false, nullable = false, value_type = String, example = "csv"))]
+    pub format: Option<String>,
+
+    /// 是否后台生成，记录数超过直接下载的上限时总是后台生成
+    #[serde(
+        default,
+        deserialize_with = "deserializer_utils::string_to_option_bool"
+    )]
+    #[salvo(parameter(required = false, nullable = false, value_type = bool, example = false))]
+    pub background: Option<bool>,
+}
+
+impl ExportQuery {
+    /// 导出格式，格式无效时返回错误
+    pub fn format_enum(&self) -> HandleResult<ExportFormatEnum> {
+        match self.format.as_deref().map(str::trim) {
+            None | Some("") => handle_ok(ExportFormatEnum::default()),
+            Some(format) => ExportFormatEnum::from_string(format)
+                .ok_or_else(|| ErrorEnum::ExportFormatInvalid.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_enum() {
+        let query = ExportQuery::default();
+        assert_eq!(query.format_enum().unwrap(), ExportFormatEnum::Csv);
+
+        let query = ExportQuery {
+            format: Some("NDJSON".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(query.format_enum().unwrap(), ExportFormatEnum::Ndjson);
+
+        let query = ExportQuery {
+            format: Some("xlsx".to_string()),
+            ..Default::default()
+        };
+        assert!(query.format_enum().is_err());
+    }
+}
@@ -0,0 +1,86 @@
+use salvo::Request;
+use salvo::extract::{Extractible, Metadata};
+use salvo::http::ParseError;
+use salvo::oapi::{Components, EndpointArgRegister, Operation, Parameter, ParameterIn, ToSchema};
+use std::marker::PhantomData;
+
+use crate::domain::dto::FieldSetDTO;
+use crate::service::ResourceService;
+
+/// 返回字段和关联数据参数
+///
+/// 从 `fields=id,title,kind.title` 和 `include=app,kind` 中解析，可加载的关联数据由资源服务的
+/// `include_options` 决定并写入 OpenAPI 文档。
+pub struct FieldsQuery<S> {
+    /// 返回字段
+    pub fields: FieldSetDTO,
+
+    /// 加载的关联数据
+    pub includes: Option<Vec<String>>,
+
+    _service: PhantomData<fn() -> S>,
+}
+
+impl<S> FieldsQuery<S> {
+    /// 从请求参数中解析
+    pub fn from_request(req: &Request) -> Self {
+        let fields = req
+            .queries()
+            .get("fields")
+            .map(|fields| FieldSetDTO::parse(fields))
+            .unwrap_or_default();
+        let includes = req.queries().get("include").map(|include| {
+            include
+                .split(',')
+                .map(|item| item.trim().to_lowercase())
+                .filter(|item| !item.is_empty())
+                .collect()
+        });
+
+        Self {
+            fields,
+            includes,
+            _service: PhantomData,
+        }
+    }
+}
+
+impl<'ex, S> Extractible<'ex> for FieldsQuery<S> {
+    fn metadata() -> &'ex Metadata {
+        static METADATA: Metadata = Metadata::new("");
+        &METADATA
+    }
+
+    #[allow(refining_impl_trait)]
+    async fn extract(req: &'ex mut Request) -> Result<Self, ParseError> {
+        Ok(Self::from_request(req))
+    }
+}
+
+impl<S: ResourceService> EndpointArgRegister for FieldsQuery<S> {
+    fn register(components: &mut Components, operation: &mut Operation, _arg: &str) {
+        let parameter = Parameter::new("fields")
+            .parameter_in(ParameterIn::Query)
+            .description("返回字段，多个字段用逗号分隔，关联数据字段如 `kind.title`")
+            .schema(String::to_schema(components))
+            .required(false);
+        operation.parameters.insert(parameter);
+
+        let names: Vec<&str> = S::include_options()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        if names.is_empty() {
+            return;
+        }
+        let parameter = Parameter::new("include")
+            .parameter_in(ParameterIn::Query)
+            .description(format!(
+                "加载的关联数据，多个用逗号分隔，可选：{}",
+                names.join(", ")
+            ))
+            .schema(String::to_schema(components))
+            .required(false);
+        operation.parameters.insert(parameter);
+    }
+}
@@ -0,0 +1,18 @@
+use salvo::oapi::{ToParameters, ToSchema};
+use serde::{Deserialize, Serialize};
+
+use crate::utils::deserializer_utils;
+
+/// 导入参数
+#[derive(Deserialize, Serialize, Debug, Clone, Default, ToParameters, ToSchema)]
+#[salvo(parameters(default_parameter_in = Query))]
+#[salvo(schema(name = "Core::Base::ImportQuery"))]
+pub struct ImportQuery {
+    /// 是否试运行，试运行时只校验并返回每行的结果，不保存数据
+    #[serde(
+        default,
+        deserialize_with = "deserializer_utils::string_to_option_bool"
+    )]
+    #[salvo(parameter(required = false, nullable = false, value_type = bool, example = true))]
+    pub dry_run: Option<bool>,
+}
@@ -0,0 +1,92 @@
+use salvo::Request;
+use salvo::extract::{Extractible, Metadata};
+use salvo::http::ParseError;
+use salvo::oapi::{Components, EndpointArgRegister, Operation, Parameter, ParameterIn, ToSchema};
+use sea_orm::IdenStatic;
+use std::marker::PhantomData;
+
+use crate::domain::dto::{ListFilterDTO, ListSortDTO};
+use crate::service::ResourceService;
+
+/// 列表排序和筛选参数
+///
+/// 从 `sort=-created_at,title` 和 `filter[is_enabled]=true`、`filter[created_at][gte]=2025-01-01`
+/// 中解析，允许的字段和操作符由资源服务的 `sort_columns`、`filter_columns` 决定并写入 OpenAPI 文档。
+pub struct ListQuery<S> {
+    /// 排序项
+    pub sorts: Vec<ListSortDTO>,
+
+    /// 筛选项
+    pub filters: Vec<ListFilterDTO>,
+
+    _service: PhantomData<fn() -> S>,
+}
+
+impl<S> ListQuery<S> {
+    /// 从请求参数中解析
+    pub fn from_request(req: &Request) -> Self {
+        let mut sorts = Vec::new();
+        let mut filters = Vec::new();
+        for (key, values) in req.queries().iter_all() {
+            if key == "sort" {
+                for value in values {
+                    sorts.extend(ListSortDTO::parse_list(value));
+                }
+                continue;
+            }
+            for value in values {
+                if let Some(filter) = ListFilterDTO::parse(key, value) {
+                    filters.push(filter);
+                }
+            }
+        }
+
+        Self {
+            sorts,
+            filters,
+            _service: PhantomData,
+        }
+    }
+}
+
+impl<'ex, S> Extractible<'ex> for ListQuery<S> {
+    fn metadata() -> &'ex Metadata {
+        static METADATA: Metadata = Metadata::new("");
+        &METADATA
+    }
+
+    #[allow(refining_impl_trait)]
+    async fn extract(req: &'ex mut Request) -> Result<Self, ParseError> {
+        Ok(Self::from_request(req))
+    }
+}
+
+impl<S: ResourceService> EndpointArgRegister for ListQuery<S> {
+    fn register(components: &mut Components, operation: &mut Operation, _arg: &str) {
+        let fields: Vec<String> = S::sort_columns()
+            .iter()
+            .map(|column| column.as_str().to_string())
+            .collect();
+        let parameter = Parameter::new("sort")
+            .parameter_in(ParameterIn::Query)
+            .description(format!(
+                "排序字段，多个字段用逗号分隔，`-` 前缀表示倒序，可选字段：{}",
+                fields.join(", ")
+            ))
+            .schema(String::to_schema(components))
+            .required(false);
+        operation.parameters.insert(parameter);
+
+        for (column, operators) in S::filter_columns() {
+            for operator in operators {
+                let name = format!("filter[{}][{}]", column.as_str(), operator.as_value());
+                let parameter = Parameter::new(name)
+                    .parameter_in(ParameterIn::Query)
+                    .description(format!("筛选 {}：{}", column.as_str(), operator.as_title()))
+                    .schema(String::to_schema(components))
+                    .required(false);
+                operation.parameters.insert(parameter);
+            }
+        }
+    }
+}
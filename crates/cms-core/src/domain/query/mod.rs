@@ -0,0 +1,11 @@
+mod audit_paginate_query;
+mod export_query;
+mod fields_query;
+mod import_query;
+mod list_query;
+
+pub use audit_paginate_query::AuditPaginateQuery;
+pub use export_query::ExportQuery;
+pub use fields_query::FieldsQuery;
+pub use import_query::ImportQuery;
+pub use list_query::ListQuery;
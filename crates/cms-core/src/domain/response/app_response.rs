@@ -1,10 +1,13 @@
 use std::fmt::Debug;
 
-use salvo::http::StatusCode;
+use salvo::http::header::{CACHE_CONTROL, ETAG};
+use salvo::http::{Method, StatusCode};
 use salvo::oapi::{self, EndpointOutRegister, ToSchema};
 use salvo::prelude::*;
 use serde::Serialize;
 
+use crate::utils::header_utils;
+
 // 统一响应结构
 #[derive(Debug, Serialize, ToSchema)]
 #[salvo(schema(name = "Core::Base::Response::AppResponse"))]
@@ -21,6 +24,11 @@ pub struct AppResponse<T: Serialize> {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[salvo(schema(required = false, value_type = T))]
     data: Option<T>,
+
+    /// 请求 ID，与响应头 X-Request-Id 相同
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[salvo(schema(required = false, value_type = String))]
+    request_id: Option<String>,
 }
 
 impl<T: Serialize> AppResponse<T> {
@@ -40,8 +48,35 @@ impl<T: Serialize> AppResponse<T> {
             code,
             message,
             data,
+            request_id: None,
         }
     }
+
+    // 状态码
+    pub fn code(&self) -> u32 {
+        self.code
+    }
+
+    // 错误信息
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    // 返回数据
+    pub fn data(&self) -> Option<&T> {
+        self.data.as_ref()
+    }
+
+    // 请求 ID
+    pub fn request_id(&self) -> Option<&str> {
+        self.request_id.as_deref()
+    }
+
+    // 设置请求 ID
+    pub fn with_request_id(mut self, request_id: Option<String>) -> Self {
+        self.request_id = request_id;
+        self
+    }
 }
 
 #[async_trait]
@@ -49,9 +84,34 @@ impl<T> Writer for AppResponse<T>
 where
     T: Serialize + salvo::prelude::ToSchema + Send + Sync + Debug + 'static,
 {
-    async fn write(mut self, _req: &mut Request, depot: &mut Depot, res: &mut Response) {
+    async fn write(mut self, req: &mut Request, depot: &mut Depot, res: &mut Response) {
         let json_string = serde_json::to_string(&self).unwrap_or_default();
+
+        // GET 成功响应带 ETag，与 If-None-Match 匹配时返回 304
+        if req.method() == Method::GET && self.code == 200 {
+            let etag = depot
+                .get::<String>(header_utils::DEPOT_ETAG_KEY)
+                .cloned()
+                .unwrap_or_else(|_| header_utils::content_etag(&json_string));
+            let cache_control = depot
+                .get::<String>(header_utils::DEPOT_CACHE_CONTROL_KEY)
+                .cloned()
+                .unwrap_or_else(|_| header_utils::DEFAULT_CACHE_CONTROL.to_string());
+            let _ = res.add_header(ETAG, &etag, true);
+            let _ = res.add_header(CACHE_CONTROL, cache_control, true);
+
+            let matched = req
+                .header::<String>(header_utils::HEADER_IF_NONE_MATCH)
+                .is_some_and(|value| header_utils::etag_matches(&value, &etag));
+            if matched {
+                res.status_code(StatusCode::NOT_MODIFIED);
+                return;
+            }
+        }
+
+        // ETag 和幂等重放使用的响应内容不含请求 ID
         depot.insert("res_v", json_string);
+        self.request_id = header_utils::request_id(depot);
         res.render(Json(&self));
     }
 }
@@ -0,0 +1,70 @@
+use salvo::oapi::ToSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use uuid::Uuid;
+
+use crate::domain::entity::audit::Model;
+use crate::utils::time_utils;
+
+/// Audit VO
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default, ToSchema)]
+#[salvo(schema(name = "Core::Base::AuditVO"))]
+pub struct AuditVO {
+    /// 主键
+    #[salvo(schema(required = true, nullable = false, value_type = i64, minimum = 1, example = 1))]
+    pub id: i64,
+
+    /// 实体类型
+    #[salvo(schema(required = true, nullable = false, value_type = String, example = "mate_kinds"))]
+    pub entity_type: String,
+
+    /// 实体ID
+    #[salvo(schema(required = true, nullable = false, value_type = i64, minimum = 1, example = 1))]
+    pub entity_id: i64,
+
+    /// 操作类型
+    #[salvo(schema(required = true, nullable = false, value_type = String, example = "update"))]
+    pub action: String,
+
+    /// 编辑类型
+    #[salvo(schema(required = true, nullable = false, value_type = String, example = "admin"))]
+    pub editor_type: String,
+
+    /// 编辑ID
+    #[salvo(schema(required = true, nullable = false, value_type = KnownFormat::Uuid, example = "00000000-0000-0000-0000-000000000000"))]
+    pub editor_id: Uuid,
+
+    /// 版本号
+    #[salvo(schema(required = true, nullable = false, value_type = i32, example = 1))]
+    pub version_no: i32,
+
+    /// 变更字段，格式为 {"字段": {"old": 旧值, "new": 新值}}
+    #[salvo(schema(required = true, nullable = false, value_type = Object))]
+    pub changes: JsonValue,
+
+    /// 创建时间
+    #[salvo(schema(required = true, nullable = false, value_type = String, example = "2023-08-10 10:00:00"))]
+    pub created_time: String,
+}
+
+impl From<&Model> for AuditVO {
+    fn from(model: &Model) -> Self {
+        Self {
+            id: model.id,
+            entity_type: model.entity_type.to_owned(),
+            entity_id: model.entity_id,
+            action: model.action.to_owned(),
+            editor_type: model.editor_type.to_owned(),
+            editor_id: model.editor_id,
+            version_no: model.version_no,
+            changes: model.changes.clone(),
+            created_time: time_utils::to_db_time(&model.created_at),
+        }
+    }
+}
+
+impl From<Model> for AuditVO {
+    fn from(model: Model) -> Self {
+        Self::from(&model)
+    }
+}
@@ -0,0 +1,102 @@
+use salvo::oapi::ToSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::domain::response::AppResponse;
+use crate::error::AppError;
+
+/// 批量操作结果
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default, ToSchema)]
+#[salvo(schema(name = "Core::Base::BulkResultVO"))]
+pub struct BulkResultVO {
+    /// 成功数量
+    #[salvo(schema(required = true, nullable = false, value_type = i64, minimum = 0, example = 2))]
+    pub success_count: usize,
+
+    /// 失败数量
+    #[salvo(schema(required = true, nullable = false, value_type = i64, minimum = 0, example = 1))]
+    pub failure_count: usize,
+
+    /// 每条记录的处理结果
+    #[salvo(schema(required = true, nullable = false))]
+    pub list: Vec<BulkItemResultVO>,
+}
+
+/// 批量操作中单条记录的结果
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default, ToSchema)]
+#[salvo(schema(name = "Core::Base::BulkItemResultVO"))]
+pub struct BulkItemResultVO {
+    /// 记录ID
+    #[salvo(schema(required = true, nullable = false, value_type = i64, example = 1))]
+    pub id: i64,
+
+    /// 是否成功
+    #[salvo(schema(required = true, nullable = false, value_type = bool, example = true))]
+    pub success: bool,
+
+    /// 失败时的状态码，与单条接口一致
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[salvo(schema(required = false, nullable = false, value_type = u32, example = 403))]
+    pub code: Option<u32>,
+
+    /// 失败原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[salvo(schema(required = false, nullable = false, value_type = String, example = "记录不存在"))]
+    pub message: Option<String>,
+}
+
+impl BulkResultVO {
+    /// 记录成功
+    pub fn push_success(&mut self, id: i64) {
+        self.success_count += 1;
+        self.list.push(BulkItemResultVO {
+            id,
+            success: true,
+            ..Default::default()
+        });
+    }
+
+    /// 记录失败
+    pub fn push_failure(&mut self, id: i64, err: AppError) {
+        let response: AppResponse<HashMap<String, String>> = err.into();
+        self.failure_count += 1;
+        self.list.push(BulkItemResultVO {
+            id,
+            success: false,
+            code: Some(response.code()),
+            message: response.message().map(String::from),
+        });
+    }
+
+    /// 按请求中的ID顺序排列结果
+    pub fn sort_by_ids(&mut self, ids: &[i64]) {
+        self.list
+            .sort_by_key(|item| ids.iter().position(|id| *id == item.id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::ErrorEnum;
+
+    #[test]
+    fn test_push_result() {
+        let mut vo = BulkResultVO::default();
+        vo.push_success(2);
+        vo.push_failure(1, ErrorEnum::RecordNotFound.into());
+        vo.push_failure(3, AppError::Forbidden);
+        vo.sort_by_ids(&[1, 2, 3]);
+
+        assert_eq!(vo.success_count, 1);
+        assert_eq!(vo.failure_count, 2);
+        assert_eq!(
+            vo.list.iter().map(|item| item.id).collect::<Vec<i64>>(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(vo.list[0].code, Some(404));
+        assert_eq!(vo.list[2].code, Some(403));
+        assert!(vo.list[1].success);
+        assert_eq!(vo.list[1].message, None);
+    }
+}
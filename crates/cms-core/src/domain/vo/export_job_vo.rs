@@ -0,0 +1,94 @@
+use salvo::oapi::ToSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::enums::{ExportFormatEnum, ExportStatusEnum};
+use crate::utils::time_utils;
+
+/// 后台导出任务
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, ToSchema)]
+#[salvo(schema(name = "Core::Base::ExportJobVO"))]
+pub struct ExportJobVO {
+    /// 任务ID
+    #[salvo(schema(required = true, nullable = false, value_type = KnownFormat::Uuid, example = "00000000-0000-0000-0000-000000000000"))]
+    pub id: Uuid,
+
+    /// 任务状态
+    #[salvo(schema(required = true, nullable = false, value_type = String, example = "pending"))]
+    pub status: String,
+
+    /// 导出格式
+    #[salvo(schema(required = true, nullable = false, value_type = String, example = "csv"))]
+    pub format: String,
+
+    /// 记录总数
+    #[salvo(schema(required = true, nullable = false, value_type = u64, example = 20000))]
+    pub total: u64,
+
+    /// 已导出数量
+    #[salvo(schema(required = true, nullable = false, value_type = u64, example = 0))]
+    pub exported: u64,
+
+    /// 下载文件名
+    #[salvo(schema(required = true, nullable = false, value_type = String, example = "items.csv"))]
+    pub file_name: String,
+
+    /// 失败原因
+    #[salvo(schema(required = false, nullable = true, value_type = String))]
+    pub message: Option<String>,
+
+    /// 创建用户ID
+    #[salvo(schema(required = true, nullable = false, value_type = KnownFormat::Uuid, example = "00000000-0000-0000-0000-000000000000"))]
+    pub editor_id: Uuid,
+
+    /// 创建时间
+    #[salvo(schema(required = true, nullable = false, value_type = String, example = "2023-08-10 10:00:00"))]
+    pub created_time: String,
+}
+
+impl ExportJobVO {
+    pub fn new(name: &str, format: &ExportFormatEnum, total: u64, editor_id: Uuid) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            status: ExportStatusEnum::Pending.as_value().to_owned(),
+            format: format.as_value().to_owned(),
+            total,
+            exported: 0,
+            file_name: format!("{}.{}", name, format.as_value()),
+            message: None,
+            editor_id,
+            created_time: time_utils::to_db_time(&time_utils::current_time()),
+        }
+    }
+
+    /// 导出格式
+    pub fn format_enum(&self) -> ExportFormatEnum {
+        ExportFormatEnum::from_string(&self.format).unwrap_or_default()
+    }
+
+    /// 是否已生成
+    pub fn is_finished(&self) -> bool {
+        self.status == ExportStatusEnum::Finished.as_value()
+    }
+
+    /// 修改状态
+    pub fn set_status(&mut self, status: ExportStatusEnum) {
+        self.status = status.as_value().to_owned();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let mut job = ExportJobVO::new("items", &ExportFormatEnum::Ndjson, 10, Uuid::nil());
+        assert_eq!(job.file_name, "items.ndjson");
+        assert_eq!(job.format_enum(), ExportFormatEnum::Ndjson);
+        assert!(!job.is_finished());
+
+        job.set_status(ExportStatusEnum::Finished);
+        assert!(job.is_finished());
+    }
+}
@@ -0,0 +1,111 @@
+use salvo::oapi::ToSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::domain::response::AppResponse;
+use crate::error::AppError;
+
+/// 导入结果
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default, ToSchema)]
+#[salvo(schema(name = "Core::Base::ImportResultVO"))]
+pub struct ImportResultVO {
+    /// 是否试运行，试运行时只校验不保存
+    #[salvo(schema(required = true, nullable = false, value_type = bool, example = true))]
+    pub dry_run: bool,
+
+    /// 成功数量
+    #[salvo(schema(required = true, nullable = false, value_type = i64, minimum = 0, example = 2))]
+    pub success_count: usize,
+
+    /// 失败数量
+    #[salvo(schema(required = true, nullable = false, value_type = i64, minimum = 0, example = 1))]
+    pub failure_count: usize,
+
+    /// 每行的处理结果
+    #[salvo(schema(required = true, nullable = false))]
+    pub list: Vec<ImportRowResultVO>,
+}
+
+/// 导入中单行的结果
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default, ToSchema)]
+#[salvo(schema(name = "Core::Base::ImportRowResultVO"))]
+pub struct ImportRowResultVO {
+    /// CSV 中的行号，表头为第1行
+    #[salvo(schema(required = true, nullable = false, value_type = u64, minimum = 2, example = 2))]
+    pub line: u64,
+
+    /// 是否成功
+    #[salvo(schema(required = true, nullable = false, value_type = bool, example = true))]
+    pub success: bool,
+
+    /// 创建的记录ID，试运行时为空
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[salvo(schema(required = false, nullable = false, value_type = i64, example = 1))]
+    pub id: Option<i64>,
+
+    /// 失败原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[salvo(schema(required = false, nullable = false, value_type = String, example = "Validation failed"))]
+    pub message: Option<String>,
+
+    /// 字段错误，格式为 {"字段": "错误信息"}
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[salvo(schema(required = false, nullable = false, value_type = Object))]
+    pub errors: Option<HashMap<String, String>>,
+}
+
+impl ImportResultVO {
+    /// 记录成功
+    pub fn push_success(&mut self, line: u64, id: Option<i64>) {
+        self.success_count += 1;
+        self.list.push(ImportRowResultVO {
+            line,
+            success: true,
+            id,
+            ..Default::default()
+        });
+    }
+
+    /// 记录失败
+    pub fn push_failure(&mut self, line: u64, err: AppError) {
+        let response: AppResponse<HashMap<String, String>> = err.into();
+        self.failure_count += 1;
+        self.list.push(ImportRowResultVO {
+            line,
+            success: false,
+            id: None,
+            message: response.message().map(String::from),
+            errors: response.data().cloned(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use validator::{ValidationError, ValidationErrors};
+
+    #[test]
+    fn test_push_result() {
+        let mut vo = ImportResultVO::default();
+        vo.push_success(2, Some(10));
+
+        let mut errors = ValidationErrors::new();
+        errors.add(
+            "name",
+            ValidationError::new("length").with_message("名称无效".into()),
+        );
+        vo.push_failure(3, AppError::Validation(errors));
+        vo.push_failure(4, AppError::BadRequest(String::from("类型不存在")));
+
+        assert_eq!(vo.success_count, 1);
+        assert_eq!(vo.failure_count, 2);
+        assert_eq!(vo.list[0].id, Some(10));
+        assert_eq!(
+            vo.list[1].errors.as_ref().and_then(|map| map.get("name")),
+            Some(&"名称无效".to_string())
+        );
+        assert_eq!(vo.list[2].message, Some("类型不存在".to_string()));
+        assert_eq!(vo.list[2].errors, None);
+    }
+}
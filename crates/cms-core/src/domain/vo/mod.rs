@@ -1,7 +1,21 @@
+mod audit_vo;
+mod bulk_result_vo;
 mod editor_load_vo;
+mod export_job_vo;
+mod import_result_vo;
 mod jwt_login_vo;
 mod paginate_result_vo;
+mod readiness_vo;
+mod replica_status_vo;
+mod sparse_vo;
 
+pub use audit_vo::AuditVO;
+pub use bulk_result_vo::{BulkItemResultVO, BulkResultVO};
 pub use editor_load_vo::EditorLoadVO;
+pub use export_job_vo::ExportJobVO;
+pub use import_result_vo::{ImportResultVO, ImportRowResultVO};
 pub use jwt_login_vo::JwtLoginVO;
 pub use paginate_result_vo::PaginateResultVO;
+pub use readiness_vo::{DependencyStatusVO, ReadinessVO};
+pub use replica_status_vo::ReplicaStatusVO;
+pub use sparse_vo::SparseVO;
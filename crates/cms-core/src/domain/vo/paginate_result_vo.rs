@@ -17,9 +17,15 @@ pub struct PaginateResultVO<T: Serialize> {
     #[salvo(schema(required = true, nullable = false, value_type = i64, minimum = 1, maximum = 100, example = 50))]
     pub page_size: u64,
 
-    /// 总条数
-    #[salvo(schema(required = true, nullable = false, value_type = i64, minimum = 0, example = 50))]
-    pub total: u64,
+    /// 总条数，`with_total=false` 或游标分页时不返回
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[salvo(schema(required = false, nullable = false, value_type = i64, minimum = 0, example = 50))]
+    pub total: Option<u64>,
+
+    /// 下一页游标，仅游标分页且存在下一页时返回
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[salvo(schema(required = false, nullable = false, value_type = String, example = "eyJrZXkiOjEsImlkIjoxfQ"))]
+    pub next_cursor: Option<String>,
 
     #[salvo(schema(required = true, nullable = false, value_type = Vec<Object>))]
     pub list: Vec<T>,
@@ -32,12 +38,26 @@ impl<T: Serialize> Default for PaginateResultVO<T> {
         Self {
             current_page,
             page_size,
-            total: 0,
+            total: Some(0),
+            next_cursor: None,
             list: Vec::new(),
         }
     }
 }
 
+impl<T: Serialize> PaginateResultVO<T> {
+    /// 转换列表数据，分页信息不变
+    pub fn map<U: Serialize>(self, f: impl FnMut(T) -> U) -> PaginateResultVO<U> {
+        PaginateResultVO {
+            current_page: self.current_page,
+            page_size: self.page_size,
+            total: self.total,
+            next_cursor: self.next_cursor,
+            list: self.list.into_iter().map(f).collect(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::json;
@@ -68,7 +88,8 @@ mod tests {
         let vo: PaginateResultVO<MockModel> = PaginateResultVO {
             current_page: 5,
             page_size: 50,
-            total: 50,
+            total: Some(50),
+            next_cursor: None,
             list: models.clone(),
         };
 
@@ -96,7 +117,7 @@ mod tests {
 
         assert_eq!(deserialized.current_page, 3);
         assert_eq!(deserialized.page_size, 20);
-        assert_eq!(deserialized.total, 100);
+        assert_eq!(deserialized.total, Some(100));
         assert_eq!(deserialized.list, vec!["a", "b", "c"]);
     }
 
@@ -106,7 +127,7 @@ mod tests {
 
         assert_eq!(default_vo.current_page, page_no_default());
         assert_eq!(default_vo.page_size, page_size_default());
-        assert_eq!(default_vo.total, 0);
+        assert_eq!(default_vo.total, Some(0));
         assert!(default_vo.list.is_empty());
     }
 
@@ -115,7 +136,8 @@ mod tests {
         let vo: PaginateResultVO<MockModel> = PaginateResultVO {
             current_page: 1,
             page_size: 10,
-            total: 0,
+            total: Some(0),
+            next_cursor: None,
             list: vec![],
         };
         let json = serde_json::to_value(&vo).unwrap();
@@ -127,7 +149,8 @@ mod tests {
         let vo: PaginateResultVO<MockModel> = PaginateResultVO {
             current_page: 1,
             page_size: 1,
-            total: 0,
+            total: Some(0),
+            next_cursor: None,
             list: vec![],
         };
         let json = serde_json::to_value(&vo).unwrap();
@@ -139,7 +162,8 @@ mod tests {
         let vo: PaginateResultVO<MockModel> = PaginateResultVO {
             current_page: 1,
             page_size: 100,
-            total: 0,
+            total: Some(0),
+            next_cursor: None,
             list: vec![],
         };
         let json = serde_json::to_value(&vo).unwrap();
@@ -151,19 +175,48 @@ mod tests {
         let vo: PaginateResultVO<MockModel> = PaginateResultVO {
             current_page: 1,
             page_size: 10,
-            total: 0,
+            total: Some(0),
+            next_cursor: None,
             list: vec![],
         };
         let json = serde_json::to_value(&vo).unwrap();
         assert_eq!(json["total"], 0);
     }
 
+    #[test]
+    fn test_paginate_result_vo_cursor() {
+        let vo: PaginateResultVO<i32> = PaginateResultVO {
+            current_page: 1,
+            page_size: 2,
+            total: None,
+            next_cursor: Some("abc".to_string()),
+            list: vec![100, 200],
+        };
+        let json = serde_json::to_value(&vo).unwrap();
+        assert!(json.get("total").is_none());
+        assert_eq!(json["next_cursor"], "abc");
+    }
+
+    #[test]
+    fn test_paginate_result_vo_map() {
+        let vo: PaginateResultVO<MockModel> = PaginateResultVO {
+            total: Some(10),
+            list: mock_model_list(),
+            ..Default::default()
+        };
+        let names = vo.map(|model| model.name);
+        assert_eq!(names.total, Some(10));
+        assert_eq!(names.list.len(), 10);
+        assert_eq!(names.list[0], "User 1");
+    }
+
     #[test]
     fn test_paginate_result_vo_generic_support() {
         let vo: PaginateResultVO<i32> = PaginateResultVO {
             current_page: 1,
             page_size: 2,
-            total: 3,
+            total: Some(3),
+            next_cursor: None,
             list: vec![100, 200],
         };
 
@@ -172,7 +225,7 @@ mod tests {
 
         assert_eq!(deserialized.current_page, 1);
         assert_eq!(deserialized.page_size, 2);
-        assert_eq!(deserialized.total, 3);
+        assert_eq!(deserialized.total, Some(3));
         assert_eq!(deserialized.list, vec![100, 200]);
     }
 }
@@ -0,0 +1,46 @@
+use salvo::oapi::ToSchema;
+use serde::{Deserialize, Serialize};
+
+/// 服务就绪状态
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default, ToSchema)]
+#[salvo(schema(name = "Core::Base::ReadinessVO"))]
+pub struct ReadinessVO {
+    /// 必需的依赖是否都可用
+    #[salvo(schema(required = true, nullable = false, value_type = bool, example = true))]
+    pub ready: bool,
+
+    /// 每个依赖的状态
+    #[salvo(schema(required = true, nullable = false))]
+    pub dependencies: Vec<DependencyStatusVO>,
+}
+
+/// 依赖服务状态
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default, ToSchema)]
+#[salvo(schema(name = "Core::Base::DependencyStatusVO"))]
+pub struct DependencyStatusVO {
+    /// 依赖名称：database、redis、rabbitmq
+    #[salvo(schema(required = true, nullable = false, value_type = String, example = "database"))]
+    pub name: String,
+
+    /// 是否必需，必需的依赖不可用时服务未就绪
+    #[salvo(schema(required = true, nullable = false, value_type = bool, example = true))]
+    pub required: bool,
+
+    /// 是否可用
+    #[salvo(schema(required = true, nullable = false, value_type = bool, example = true))]
+    pub healthy: bool,
+
+    /// 检查耗时（毫秒）
+    #[salvo(schema(required = true, nullable = false, value_type = i64, minimum = 0, example = 3))]
+    pub latency_ms: u64,
+
+    /// 不可用的原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[salvo(schema(required = false, nullable = false, value_type = String, example = "timed out after 3s"))]
+    pub error: Option<String>,
+
+    /// 待执行的数据库迁移数量，仅数据库有此项
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[salvo(schema(required = false, nullable = false, value_type = i64, minimum = 0, example = 0))]
+    pub pending_migrations: Option<usize>,
+}
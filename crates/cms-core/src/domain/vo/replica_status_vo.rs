@@ -0,0 +1,19 @@
+use salvo::oapi::ToSchema;
+use serde::{Deserialize, Serialize};
+
+/// 只读副本状态
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default, ToSchema)]
+#[salvo(schema(name = "Core::Base::ReplicaStatusVO"))]
+pub struct ReplicaStatusVO {
+    /// 副本序号
+    #[salvo(schema(required = true, nullable = false, value_type = i64, minimum = 0, example = 0))]
+    pub index: usize,
+
+    /// 是否可连接
+    #[salvo(schema(required = true, nullable = false, value_type = bool, example = true))]
+    pub healthy: bool,
+
+    /// 复制延迟（秒），无法获取时为空
+    #[salvo(schema(required = false, nullable = true, value_type = f64, example = 0.5))]
+    pub lag_seconds: Option<f64>,
+}
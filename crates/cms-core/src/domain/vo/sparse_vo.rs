@@ -0,0 +1,66 @@
+use salvo::oapi::{Components, RefOr, Schema, ToSchema};
+use serde::{Serialize, Serializer, ser::Error as _};
+
+use crate::domain::dto::FieldSetDTO;
+
+/// 按 `fields` 参数裁剪字段的 VO，OpenAPI 文档与原 VO 一致
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseVO<T> {
+    /// 原 VO
+    pub data: T,
+
+    /// 返回字段
+    pub fields: FieldSetDTO,
+}
+
+impl<T> SparseVO<T> {
+    pub fn new(data: T, fields: FieldSetDTO) -> Self {
+        Self { data, fields }
+    }
+}
+
+impl<T: Serialize> Serialize for SparseVO<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.fields.is_empty() {
+            return self.data.serialize(serializer);
+        }
+
+        let mut json = serde_json::to_value(&self.data).map_err(S::Error::custom)?;
+        self.fields.apply(&mut json);
+        json.serialize(serializer)
+    }
+}
+
+impl<T: ToSchema> ToSchema for SparseVO<T> {
+    fn to_schema(components: &mut Components) -> RefOr<Schema> {
+        T::to_schema(components)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[derive(Serialize)]
+    struct DummyVO {
+        id: i64,
+        title: String,
+    }
+
+    #[test]
+    fn test_serialize() {
+        let data = DummyVO {
+            id: 1,
+            title: String::from("title"),
+        };
+        let vo = SparseVO::new(data, FieldSetDTO::parse("id"));
+        assert_eq!(serde_json::to_value(&vo).unwrap(), json!({"id": 1}));
+
+        let vo = SparseVO::new(vo.data, FieldSetDTO::default());
+        assert_eq!(
+            serde_json::to_value(&vo).unwrap(),
+            json!({"id": 1, "title": "title"})
+        );
+    }
+}
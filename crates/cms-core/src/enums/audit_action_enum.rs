@@ -0,0 +1,112 @@
+use salvo::oapi::ToSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::consts::enum_consts::*;
+
+/// 审计操作类型
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, ToSchema)]
+#[salvo(schema(name = "Core::Enum::AuditActionEnum"))]
+pub enum AuditActionEnum {
+    Create,
+    Update,
+    Delete,
+    Restore,
+    Revert,
+    Purge,
+}
+
+impl AuditActionEnum {
+    pub fn as_value(&self) -> &'static str {
+        match self {
+            AuditActionEnum::Create => AUDIT_ACTION_CREATE_VALUE,
+            AuditActionEnum::Update => AUDIT_ACTION_UPDATE_VALUE,
+            AuditActionEnum::Delete => AUDIT_ACTION_DELETE_VALUE,
+            AuditActionEnum::Restore => AUDIT_ACTION_RESTORE_VALUE,
+            AuditActionEnum::Revert => AUDIT_ACTION_REVERT_VALUE,
+            AuditActionEnum::Purge => AUDIT_ACTION_PURGE_VALUE,
+        }
+    }
+
+    pub fn as_title(&self) -> &'static str {
+        match self {
+            AuditActionEnum::Create => AUDIT_ACTION_CREATE_TITLE,
+            AuditActionEnum::Update => AUDIT_ACTION_UPDATE_TITLE,
+            AuditActionEnum::Delete => AUDIT_ACTION_DELETE_TITLE,
+            AuditActionEnum::Restore => AUDIT_ACTION_RESTORE_TITLE,
+            AuditActionEnum::Revert => AUDIT_ACTION_REVERT_TITLE,
+            AuditActionEnum::Purge => AUDIT_ACTION_PURGE_TITLE,
+        }
+    }
+
+    pub fn from_string(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            AUDIT_ACTION_CREATE_VALUE => Some(AuditActionEnum::Create),
+            AUDIT_ACTION_UPDATE_VALUE => Some(AuditActionEnum::Update),
+            AUDIT_ACTION_DELETE_VALUE => Some(AuditActionEnum::Delete),
+            AUDIT_ACTION_RESTORE_VALUE => Some(AuditActionEnum::Restore),
+            AUDIT_ACTION_REVERT_VALUE => Some(AuditActionEnum::Revert),
+            AUDIT_ACTION_PURGE_VALUE => Some(AuditActionEnum::Purge),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AuditActionEnum;
+    use crate::consts::enum_consts::*;
+
+    #[test]
+    fn test_as_value() {
+        assert_eq!(
+            AuditActionEnum::Create.as_value(),
+            AUDIT_ACTION_CREATE_VALUE
+        );
+        assert_eq!(
+            AuditActionEnum::Update.as_value(),
+            AUDIT_ACTION_UPDATE_VALUE
+        );
+        assert_eq!(
+            AuditActionEnum::Delete.as_value(),
+            AUDIT_ACTION_DELETE_VALUE
+        );
+        assert_eq!(
+            AuditActionEnum::Restore.as_value(),
+            AUDIT_ACTION_RESTORE_VALUE
+        );
+        assert_eq!(
+            AuditActionEnum::Revert.as_value(),
+            AUDIT_ACTION_REVERT_VALUE
+        );
+        assert_eq!(AuditActionEnum::Purge.as_value(), AUDIT_ACTION_PURGE_VALUE);
+    }
+
+    #[test]
+    fn test_as_title() {
+        assert_eq!(
+            AuditActionEnum::Create.as_title(),
+            AUDIT_ACTION_CREATE_TITLE
+        );
+        assert_eq!(
+            AuditActionEnum::Revert.as_title(),
+            AUDIT_ACTION_REVERT_TITLE
+        );
+    }
+
+    #[test]
+    fn test_from_string() {
+        assert_eq!(
+            AuditActionEnum::from_string("UPDATE"),
+            Some(AuditActionEnum::Update)
+        );
+        assert_eq!(
+            AuditActionEnum::from_string("restore"),
+            Some(AuditActionEnum::Restore)
+        );
+        assert_eq!(
+            AuditActionEnum::from_string("purge"),
+            Some(AuditActionEnum::Purge)
+        );
+        assert_eq!(AuditActionEnum::from_string("invalid"), None);
+    }
+}
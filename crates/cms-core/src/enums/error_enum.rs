@@ -15,6 +15,16 @@ pub enum ErrorEnum {
     UpdateFieldInvalid,
     RecordNotFound,
     NoPermissionDelete,
+    NoPermissionUpdate,
+    CursorInvalid,
+    SortInvalid,
+    CursorWithSort,
+    FilterInvalid,
+    IncludeInvalid,
+    ExportFormatInvalid,
+    ExportNotReady,
+    ImportFileInvalid,
+    ImportDuplicate,
 }
 
 impl ErrorEnum {
@@ -28,6 +38,16 @@ impl ErrorEnum {
             ErrorEnum::UpdateFieldInvalid => ERROR_UPDATE_FIELD_INVALID_MESSAGE,
             ErrorEnum::RecordNotFound => ERROR_RECORD_NOT_FOUND_MESSAGE,
             ErrorEnum::NoPermissionDelete => ERROR_NO_PERMISSION_DELETE_MESSAGE,
+            ErrorEnum::NoPermissionUpdate => ERROR_NO_PERMISSION_UPDATE_MESSAGE,
+            ErrorEnum::CursorInvalid => ERROR_CURSOR_INVALID_MESSAGE,
+            ErrorEnum::SortInvalid => ERROR_SORT_INVALID_MESSAGE,
+            ErrorEnum::CursorWithSort => ERROR_CURSOR_WITH_SORT_MESSAGE,
+            ErrorEnum::FilterInvalid => ERROR_FILTER_INVALID_MESSAGE,
+            ErrorEnum::IncludeInvalid => ERROR_INCLUDE_INVALID_MESSAGE,
+            ErrorEnum::ExportFormatInvalid => ERROR_EXPORT_FORMAT_INVALID_MESSAGE,
+            ErrorEnum::ExportNotReady => ERROR_EXPORT_NOT_READY_MESSAGE,
+            ErrorEnum::ImportFileInvalid => ERROR_IMPORT_FILE_INVALID_MESSAGE,
+            ErrorEnum::ImportDuplicate => ERROR_IMPORT_DUPLICATE_MESSAGE,
         };
         str.to_string()
     }
@@ -38,7 +58,7 @@ impl ErrorEnum {
     {
         match self {
             ErrorEnum::RecordNotFound => AppError::NotFound(self.message()),
-            ErrorEnum::NoPermissionDelete => AppError::Forbidden,
+            ErrorEnum::NoPermissionDelete | ErrorEnum::NoPermissionUpdate => AppError::Forbidden,
             _ => AppError::BadRequest(self.message()),
         }
     }
@@ -86,6 +106,43 @@ mod tests {
             ErrorEnum::NoPermissionDelete.message(),
             ERROR_NO_PERMISSION_DELETE_MESSAGE
         );
+        assert_eq!(
+            ErrorEnum::NoPermissionUpdate.message(),
+            ERROR_NO_PERMISSION_UPDATE_MESSAGE
+        );
+        assert_eq!(
+            ErrorEnum::CursorInvalid.message(),
+            ERROR_CURSOR_INVALID_MESSAGE
+        );
+        assert_eq!(ErrorEnum::SortInvalid.message(), ERROR_SORT_INVALID_MESSAGE);
+        assert_eq!(
+            ErrorEnum::CursorWithSort.message(),
+            ERROR_CURSOR_WITH_SORT_MESSAGE
+        );
+        assert_eq!(
+            ErrorEnum::FilterInvalid.message(),
+            ERROR_FILTER_INVALID_MESSAGE
+        );
+        assert_eq!(
+            ErrorEnum::IncludeInvalid.message(),
+            ERROR_INCLUDE_INVALID_MESSAGE
+        );
+        assert_eq!(
+            ErrorEnum::ExportFormatInvalid.message(),
+            ERROR_EXPORT_FORMAT_INVALID_MESSAGE
+        );
+        assert_eq!(
+            ErrorEnum::ExportNotReady.message(),
+            ERROR_EXPORT_NOT_READY_MESSAGE
+        );
+        assert_eq!(
+            ErrorEnum::ImportFileInvalid.message(),
+            ERROR_IMPORT_FILE_INVALID_MESSAGE
+        );
+        assert_eq!(
+            ErrorEnum::ImportDuplicate.message(),
+            ERROR_IMPORT_DUPLICATE_MESSAGE
+        );
     }
 
     // ----------------------------
@@ -107,6 +164,12 @@ mod tests {
         assert_eq!(err, AppError::Forbidden);
     }
 
+    #[test]
+    fn test_into_app_error_for_no_permission_update() {
+        let err = ErrorEnum::NoPermissionUpdate.into_app_error();
+        assert_eq!(err, AppError::Forbidden);
+    }
+
     #[test]
     fn test_into_app_error_for_other_errors() {
         let err = ErrorEnum::NameExists.into_app_error();
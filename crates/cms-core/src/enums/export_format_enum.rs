@@ -0,0 +1,87 @@
+use salvo::oapi::ToSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::consts::enum_consts::*;
+
+/// 导出格式
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default, ToSchema)]
+#[salvo(schema(name = "Core::Enum::ExportFormatEnum"))]
+pub enum ExportFormatEnum {
+    #[default]
+    Csv,
+    Ndjson,
+}
+
+impl ExportFormatEnum {
+    pub fn as_value(&self) -> &'static str {
+        match self {
+            ExportFormatEnum::Csv => EXPORT_FORMAT_CSV_VALUE,
+            ExportFormatEnum::Ndjson => EXPORT_FORMAT_NDJSON_VALUE,
+        }
+    }
+
+    pub fn as_title(&self) -> &'static str {
+        match self {
+            ExportFormatEnum::Csv => EXPORT_FORMAT_CSV_TITLE,
+            ExportFormatEnum::Ndjson => EXPORT_FORMAT_NDJSON_TITLE,
+        }
+    }
+
+    pub fn from_string(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            EXPORT_FORMAT_CSV_VALUE => Some(ExportFormatEnum::Csv),
+            EXPORT_FORMAT_NDJSON_VALUE | "jsonl" => Some(ExportFormatEnum::Ndjson),
+            _ => None,
+        }
+    }
+
+    /// 响应的 Content-Type
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ExportFormatEnum::Csv => "text/csv; charset=utf-8",
+            ExportFormatEnum::Ndjson => "application/x-ndjson; charset=utf-8",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExportFormatEnum;
+    use crate::consts::enum_consts::*;
+
+    #[test]
+    fn test_as_value() {
+        assert_eq!(ExportFormatEnum::Csv.as_value(), EXPORT_FORMAT_CSV_VALUE);
+        assert_eq!(
+            ExportFormatEnum::Ndjson.as_value(),
+            EXPORT_FORMAT_NDJSON_VALUE
+        );
+    }
+
+    #[test]
+    fn test_as_title() {
+        assert_eq!(ExportFormatEnum::Csv.as_title(), EXPORT_FORMAT_CSV_TITLE);
+        assert_eq!(
+            ExportFormatEnum::Ndjson.as_title(),
+            EXPORT_FORMAT_NDJSON_TITLE
+        );
+    }
+
+    #[test]
+    fn test_from_string() {
+        assert_eq!(
+            ExportFormatEnum::from_string("CSV"),
+            Some(ExportFormatEnum::Csv)
+        );
+        assert_eq!(
+            ExportFormatEnum::from_string("jsonl"),
+            Some(ExportFormatEnum::Ndjson)
+        );
+        assert_eq!(ExportFormatEnum::from_string("xlsx"), None);
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(ExportFormatEnum::default(), ExportFormatEnum::Csv);
+    }
+}
@@ -0,0 +1,47 @@
+use salvo::oapi::ToSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::consts::enum_consts::*;
+
+/// 导出任务状态
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, ToSchema)]
+#[salvo(schema(name = "Core::Enum::ExportStatusEnum"))]
+pub enum ExportStatusEnum {
+    Pending,
+    Running,
+    Finished,
+    Failed,
+}
+
+impl ExportStatusEnum {
+    pub fn as_value(&self) -> &'static str {
+        match self {
+            ExportStatusEnum::Pending => EXPORT_STATUS_PENDING_VALUE,
+            ExportStatusEnum::Running => EXPORT_STATUS_RUNNING_VALUE,
+            ExportStatusEnum::Finished => EXPORT_STATUS_FINISHED_VALUE,
+            ExportStatusEnum::Failed => EXPORT_STATUS_FAILED_VALUE,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExportStatusEnum;
+    use crate::consts::enum_consts::*;
+
+    #[test]
+    fn test_as_value() {
+        assert_eq!(
+            ExportStatusEnum::Pending.as_value(),
+            EXPORT_STATUS_PENDING_VALUE
+        );
+        assert_eq!(
+            ExportStatusEnum::Finished.as_value(),
+            EXPORT_STATUS_FINISHED_VALUE
+        );
+        assert_eq!(
+            ExportStatusEnum::Failed.as_value(),
+            EXPORT_STATUS_FAILED_VALUE
+        );
+    }
+}
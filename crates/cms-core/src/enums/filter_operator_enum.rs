@@ -0,0 +1,157 @@
+use salvo::oapi::ToSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::consts::enum_consts::*;
+
+/// 列表筛选操作符
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, ToSchema)]
+#[salvo(schema(name = "Core::Enum::FilterOperatorEnum"))]
+pub enum FilterOperatorEnum {
+    Eq,
+    Ne,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+    Like,
+    In,
+    Null,
+}
+
+impl FilterOperatorEnum {
+    pub fn as_value(&self) -> &'static str {
+        match self {
+            FilterOperatorEnum::Eq => FILTER_OPERATOR_EQ_VALUE,
+            FilterOperatorEnum::Ne => FILTER_OPERATOR_NE_VALUE,
+            FilterOperatorEnum::Gt => FILTER_OPERATOR_GT_VALUE,
+            FilterOperatorEnum::Gte => FILTER_OPERATOR_GTE_VALUE,
+            FilterOperatorEnum::Lt => FILTER_OPERATOR_LT_VALUE,
+            FilterOperatorEnum::Lte => FILTER_OPERATOR_LTE_VALUE,
+            FilterOperatorEnum::Like => FILTER_OPERATOR_LIKE_VALUE,
+            FilterOperatorEnum::In => FILTER_OPERATOR_IN_VALUE,
+            FilterOperatorEnum::Null => FILTER_OPERATOR_NULL_VALUE,
+        }
+    }
+
+    pub fn as_title(&self) -> &'static str {
+        match self {
+            FilterOperatorEnum::Eq => FILTER_OPERATOR_EQ_TITLE,
+            FilterOperatorEnum::Ne => FILTER_OPERATOR_NE_TITLE,
+            FilterOperatorEnum::Gt => FILTER_OPERATOR_GT_TITLE,
+            FilterOperatorEnum::Gte => FILTER_OPERATOR_GTE_TITLE,
+            FilterOperatorEnum::Lt => FILTER_OPERATOR_LT_TITLE,
+            FilterOperatorEnum::Lte => FILTER_OPERATOR_LTE_TITLE,
+            FilterOperatorEnum::Like => FILTER_OPERATOR_LIKE_TITLE,
+            FilterOperatorEnum::In => FILTER_OPERATOR_IN_TITLE,
+            FilterOperatorEnum::Null => FILTER_OPERATOR_NULL_TITLE,
+        }
+    }
+
+    pub fn from_string(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            FILTER_OPERATOR_EQ_VALUE => Some(FilterOperatorEnum::Eq),
+            FILTER_OPERATOR_NE_VALUE => Some(FilterOperatorEnum::Ne),
+            FILTER_OPERATOR_GT_VALUE => Some(FilterOperatorEnum::Gt),
+            FILTER_OPERATOR_GTE_VALUE => Some(FilterOperatorEnum::Gte),
+            FILTER_OPERATOR_LT_VALUE => Some(FilterOperatorEnum::Lt),
+            FILTER_OPERATOR_LTE_VALUE => Some(FilterOperatorEnum::Lte),
+            FILTER_OPERATOR_LIKE_VALUE => Some(FilterOperatorEnum::Like),
+            FILTER_OPERATOR_IN_VALUE => Some(FilterOperatorEnum::In),
+            FILTER_OPERATOR_NULL_VALUE => Some(FilterOperatorEnum::Null),
+            _ => None,
+        }
+    }
+
+    /// 文本类操作符
+    pub fn text() -> Vec<Self> {
+        vec![FilterOperatorEnum::Eq, FilterOperatorEnum::Like]
+    }
+
+    /// 列表类操作符
+    pub fn list() -> Vec<Self> {
+        vec![FilterOperatorEnum::Eq, FilterOperatorEnum::In]
+    }
+
+    /// 范围类操作符
+    pub fn range() -> Vec<Self> {
+        vec![
+            FilterOperatorEnum::Gt,
+            FilterOperatorEnum::Gte,
+            FilterOperatorEnum::Lt,
+            FilterOperatorEnum::Lte,
+        ]
+    }
+
+    /// 比较类操作符
+    pub fn comparison() -> Vec<Self> {
+        vec![
+            FilterOperatorEnum::Eq,
+            FilterOperatorEnum::Ne,
+            FilterOperatorEnum::Gt,
+            FilterOperatorEnum::Gte,
+            FilterOperatorEnum::Lt,
+            FilterOperatorEnum::Lte,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FilterOperatorEnum;
+    use crate::consts::enum_consts::*;
+
+    #[test]
+    fn test_as_value() {
+        assert_eq!(FilterOperatorEnum::Eq.as_value(), FILTER_OPERATOR_EQ_VALUE);
+        assert_eq!(
+            FilterOperatorEnum::Gte.as_value(),
+            FILTER_OPERATOR_GTE_VALUE
+        );
+        assert_eq!(
+            FilterOperatorEnum::Null.as_value(),
+            FILTER_OPERATOR_NULL_VALUE
+        );
+    }
+
+    #[test]
+    fn test_as_title() {
+        assert_eq!(FilterOperatorEnum::Eq.as_title(), FILTER_OPERATOR_EQ_TITLE);
+        assert_eq!(
+            FilterOperatorEnum::Like.as_title(),
+            FILTER_OPERATOR_LIKE_TITLE
+        );
+    }
+
+    #[test]
+    fn test_from_string() {
+        assert_eq!(
+            FilterOperatorEnum::from_string("GTE"),
+            Some(FilterOperatorEnum::Gte)
+        );
+        assert_eq!(
+            FilterOperatorEnum::from_string("in"),
+            Some(FilterOperatorEnum::In)
+        );
+        assert_eq!(FilterOperatorEnum::from_string("between"), None);
+    }
+
+    #[test]
+    fn test_operator_groups() {
+        assert_eq!(
+            FilterOperatorEnum::text(),
+            vec![FilterOperatorEnum::Eq, FilterOperatorEnum::Like]
+        );
+        assert_eq!(
+            FilterOperatorEnum::list(),
+            vec![FilterOperatorEnum::Eq, FilterOperatorEnum::In]
+        );
+        assert!(!FilterOperatorEnum::range().contains(&FilterOperatorEnum::Eq));
+    }
+
+    #[test]
+    fn test_comparison() {
+        let list = FilterOperatorEnum::comparison();
+        assert_eq!(list.len(), 6);
+        assert!(!list.contains(&FilterOperatorEnum::Like));
+    }
+}
@@ -1,15 +1,23 @@
+mod audit_action_enum;
 mod editor_type_enum;
 mod enable_enum;
 mod error_enum;
+mod export_format_enum;
+mod export_status_enum;
+mod filter_operator_enum;
 mod platform_enum;
 mod primary_id_enum;
 mod select_value_enum;
 mod token_type_enum;
 mod view_mode_enum;
 
+pub use audit_action_enum::AuditActionEnum;
 pub use editor_type_enum::EditorTypeEnum;
 pub use enable_enum::EnableEnum;
 pub use error_enum::ErrorEnum;
+pub use export_format_enum::ExportFormatEnum;
+pub use export_status_enum::ExportStatusEnum;
+pub use filter_operator_enum::FilterOperatorEnum;
 pub use platform_enum::PlatformEnum;
 pub use primary_id_enum::PrimaryIdEnum;
 pub use select_value_enum::SelectValueEnum;
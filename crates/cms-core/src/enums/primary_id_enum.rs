@@ -1,4 +1,5 @@
 use salvo::oapi::ToSchema;
+use sea_orm::{ColIdx, QueryResult, TryGetError, TryGetable};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
 use std::str::FromStr;
@@ -62,6 +63,17 @@ impl PrimaryIdEnum {
     }
 }
 
+// 从查询结果读取，兼容整型和 UUID 主键
+impl TryGetable for PrimaryIdEnum {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+        if let Ok(id) = i64::try_get_by(res, index) {
+            return Ok(Self::BigInt(id));
+        }
+        let uuid = Uuid::try_get_by(res, index)?;
+        Ok(Self::Uuid(uuid))
+    }
+}
+
 // 序列化实现
 impl Serialize for PrimaryIdEnum {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -7,7 +7,9 @@ use thiserror::Error;
 use tracing::error;
 use validator::ValidationErrors;
 
+use crate::consts::enum_consts::ERROR_VERSION_CONFLICT_MESSAGE;
 use crate::domain::response::{AppResponse, BaseErrorResponse};
+use crate::utils::header_utils;
 use crate::utils::validate_utils::validate_error_hash;
 
 // 自定义错误类型
@@ -34,6 +36,9 @@ pub enum AppError {
     #[error("Forbidden")]
     Forbidden,
 
+    #[error("Conflict: current version is {0}")]
+    Conflict(i32),
+
     #[error("Database error: {0}")]
     Database(String),
 
@@ -85,10 +90,20 @@ impl Into<AppResponse<HashMap<String, String>>> for AppError {
                 code = 401;
                 message = String::from("Unauthorized");
             }
+            AppError::Forbidden => {
+                code = 403;
+                message = String::from("Forbidden");
+            }
             AppError::NotFound(msg) => {
                 code = 404;
                 message = msg;
             }
+            AppError::Conflict(version_no) => {
+                code = 409;
+                message = String::from(ERROR_VERSION_CONFLICT_MESSAGE);
+                let map = HashMap::from([(String::from("version_no"), version_no.to_string())]);
+                data = Some(map);
+            }
             AppError::Validation(err) => {
                 code = 400;
                 message = String::from("Validation failed");
@@ -105,9 +120,13 @@ impl Into<AppResponse<HashMap<String, String>>> for AppError {
 // 为自定义错误实现 Salvo 的 Writer
 #[async_trait]
 impl Writer for AppError {
-    async fn write(mut self, _req: &mut Request, _depot: &mut Depot, res: &mut Response) {
+    async fn write(mut self, _req: &mut Request, depot: &mut Depot, res: &mut Response) {
         let cloned_self = self.clone(); // 克隆 self 以保留原始错误信息
+        if matches!(self, AppError::Conflict(_)) {
+            res.status_code(StatusCode::CONFLICT);
+        }
         let response: AppResponse<HashMap<String, String>> = self.into();
+        let response = response.with_request_id(header_utils::request_id(depot));
 
         error!("Error occurred: {:?}", cloned_self); // 使用克隆的错误信息进行日志记录
 
@@ -160,7 +179,7 @@ impl EndpointOutRegister for AppError {
             //     "Proxy Authentication Required",
             // ),
             (StatusCode::REQUEST_TIMEOUT, "Request Timeout"),
-            // (StatusCode::CONFLICT, "Conflict"),
+            (StatusCode::CONFLICT, "Conflict"),
             // (StatusCode::GONE, "Gone"),
             // (StatusCode::LENGTH_REQUIRED, "Length Required"),
             // (StatusCode::PRECONDITION_FAILED, "Precondition Failed"),
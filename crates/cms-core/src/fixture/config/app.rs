@@ -1,24 +1,76 @@
-use sea_orm::DatabaseBackend;
-use sea_orm::MockDatabase;
+use migration::{Migrator, MigratorTrait};
+use sea_orm::DatabaseConnection;
+use std::env;
+use std::sync::Arc;
+use uuid::Uuid;
 
 use crate::config::AppState;
-use crate::config::RabbitMQConfig;
-use crate::config::RedisConfig;
+use crate::config::CmsConfig;
+use crate::config::DatabaseConfig;
+use crate::config::SQLITE_MEMORY_NAME;
+use crate::service::{MemoryCacheStore, MemoryPublisher};
 
 pub struct FakerAppState {}
 impl FakerAppState {
+    /// 使用 SQLite 内存数据库并执行全部迁移，每次调用都是一个新的数据库
+    ///
+    /// 缓存和消息发布使用内存实现，不依赖 Redis 和 RabbitMQ。
     pub async fn init() -> AppState {
-        let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
-        let redis_config = RedisConfig::from_env().expect("Failed to load redis config");
-        let rabbitmq_config = RabbitMQConfig::from_env().expect("Failed to load queue config");
+        Self::init_with(
+            Arc::new(MemoryCacheStore::new()),
+            Arc::new(MemoryPublisher::new()),
+        )
+        .await
+    }
+
+    /// 使用指定的内存缓存和消息发布，便于测试中断言
+    pub async fn init_with(
+        cache: Arc<MemoryCacheStore>,
+        publisher: Arc<MemoryPublisher>,
+    ) -> AppState {
+        let db = Self::connect(SQLITE_MEMORY_NAME, None).await;
+        Migrator::up(&db, None)
+            .await
+            .expect("Failed to run migrations");
+
+        AppState::new(CmsConfig::default(), db, Vec::new(), cache, publisher)
+    }
 
-        let redis = redis_config.build_client().await.unwrap();
-        let rabbitmq = rabbitmq_config.build_pool().await.unwrap();
+    /// 使用临时文件的 SQLite 数据库，允许多个连接
+    ///
+    /// 内存数据库只有一个连接，事务未提交时不能在事务外查询，如导入时查询关联数据。
+    pub async fn init_file() -> AppState {
+        let path = env::temp_dir().join(format!("cms-test-{}.db", Uuid::new_v4()));
+        let name = path.display().to_string();
 
-        AppState {
+        // 修改表结构后其他连接可能仍使用旧的表结构，迁移只使用一个连接
+        let db = Self::connect(&name, Some(1)).await;
+        Migrator::up(&db, None)
+            .await
+            .expect("Failed to run migrations");
+        let _ = db.close().await;
+
+        let db = Self::connect(&name, None).await;
+        AppState::new(
+            CmsConfig::default(),
             db,
-            redis,
-            rabbitmq,
-        }
+            Vec::new(),
+            Arc::new(MemoryCacheStore::new()),
+            Arc::new(MemoryPublisher::new()),
+        )
+    }
+
+    async fn connect(name: &str, max_connections: Option<u32>) -> DatabaseConnection {
+        let db_config = DatabaseConfig {
+            protocol: Some("sqlite".to_string()),
+            name: name.to_string(),
+            max_connections,
+            sqlx_logging: Some(false),
+            ..Default::default()
+        };
+        db_config
+            .build_connection()
+            .await
+            .expect("Failed to connect sqlite database")
     }
 }
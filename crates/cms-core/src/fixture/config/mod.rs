@@ -1,3 +1,5 @@
 mod app;
+mod service;
 
 pub use app::FakerAppState;
+pub use service::FakerService;
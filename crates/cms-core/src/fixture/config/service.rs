@@ -0,0 +1,20 @@
+use salvo::prelude::*;
+
+use crate::middleware::{jwt_authorizor_init, read_your_writes, tenant};
+
+use super::FakerAppState;
+
+pub struct FakerService {}
+impl FakerService {
+    /// 使用测试数据库启动路由，与服务端一样注入 JWT 认证、应用状态、读写分离和多租户
+    pub async fn init(router: Router) -> Service {
+        let state = FakerAppState::init().await;
+        let router = Router::new()
+            .hoop(jwt_authorizor_init(&state.config.current().jwt))
+            .hoop(affix_state::inject(state))
+            .hoop(read_your_writes)
+            .hoop(tenant)
+            .push(router);
+        Service::new(router)
+    }
+}
@@ -1,6 +1,11 @@
+use sea_orm::sea_query::{Alias, IntoIden, Query};
+use sea_orm::{ConnectionTrait, DatabaseConnection};
 use uuid::Uuid;
 
-use crate::domain::entity::editor::Model as EditorModel;
+use crate::domain::entity::editor::{
+    Column as EditorColumn, Entity as EditorEntity, Model as EditorModel,
+};
+use crate::utils::time_utils;
 
 pub const EDITOR_NAME_SYSTEM: &str = "system";
 pub const EDITOR_NAME_ADMIN: &str = "admin";
@@ -25,3 +30,38 @@ pub fn faker_model_by_name(name: &str) -> EditorModel {
         avatar_path: "".to_owned(),
     }
 }
+
+/// 写入测试用户，users 表中其他字段使用默认值
+pub async fn faker_insert_by_names(db: &DatabaseConnection, names: &[&str]) -> Vec<EditorModel> {
+    let now = time_utils::current_time();
+    let models: Vec<EditorModel> = names.iter().map(|name| faker_model_by_name(name)).collect();
+    for model in models.iter() {
+        let stmt = Query::insert()
+            .into_table(EditorEntity)
+            .columns([
+                EditorColumn::Id.into_iden(),
+                EditorColumn::No.into_iden(),
+                EditorColumn::Name.into_iden(),
+                EditorColumn::Phone.into_iden(),
+                EditorColumn::AvatarPath.into_iden(),
+                EditorColumn::Email.into_iden(),
+                Alias::new("created_at").into_iden(),
+                Alias::new("updated_at").into_iden(),
+            ])
+            .values_panic([
+                model.id.into(),
+                model.no.clone().into(),
+                model.name.clone().into(),
+                model.phone.clone().into(),
+                model.avatar_path.clone().into(),
+                model.email.clone().into(),
+                now.into(),
+                now.into(),
+            ])
+            .to_owned();
+        db.execute(db.get_database_backend().build(&stmt))
+            .await
+            .expect("Failed to insert faker editor");
+    }
+    models
+}
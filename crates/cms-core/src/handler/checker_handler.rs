@@ -5,13 +5,15 @@ use crate::domain::{
     AppResult,
     response::{BaseBooleanResponse, BaseStringResponse},
     result_ok,
+    vo::{ReadinessVO, ReplicaStatusVO},
 };
+use crate::service::{DatabaseService, HealthService};
 
 /// Service Status
 ///
 /// Check service status
 #[endpoint(
-    operation_id = "auth_service_health_checker",
+    operation_id = "core_service_health_checker",
     tags("Core/Checker"),
     status_codes(200, 500),
     responses(
@@ -26,7 +28,7 @@ pub async fn health() -> AppResult<String> {
 ///
 ///  Check database status
 #[endpoint(
-    operation_id = "auth_database_health_checker",
+    operation_id = "core_database_health_checker",
     tags("Core/Checker"),
     status_codes(200, 500),
     responses(
@@ -39,30 +41,143 @@ pub async fn database(depot: &mut Depot) -> AppResult<bool> {
     result_ok(res.is_ok())
 }
 
-// #[cfg(test)]
-// mod tests {
-//     use salvo::prelude::*;
-//     use salvo::test::TestClient;
-
-//     use crate::handler;
-
-//     #[tokio::test]
-//     async fn test_service_health() {
-//         let service = Service::new(handler::init_router());
-
-//         let response = TestClient::get(format!("http://127.0.0.1:5800/checker/health"))
-//             .send(&service)
-//             .await;
-//         assert_eq!(response.status_code.unwrap(), StatusCode::OK);
-//     }
-
-//     #[tokio::test]
-//     async fn test_database_health() {
-//         let service = Service::new(handler::init_router());
-
-//         let response = TestClient::get(format!("http://127.0.0.1:5800/checker/database"))
-//             .send(&service)
-//             .await;
-//         assert_eq!(response.status_code.unwrap(), StatusCode::OK);
-//     }
-// }
+/// Replica Status
+///
+/// Check read replica status and replication lag
+#[endpoint(
+    operation_id = "core_replica_health_checker",
+    tags("Core/Checker"),
+    status_codes(200, 500)
+)]
+pub async fn replicas(depot: &mut Depot) -> AppResult<Vec<ReplicaStatusVO>> {
+    let state = depot.obtain::<AppState>().unwrap();
+    let list = DatabaseService::replica_status(state).await;
+    result_ok(list)
+}
+
+/// Liveness
+///
+/// Check the service process is running, used as the Kubernetes liveness probe
+#[endpoint(
+    operation_id = "core_liveness_checker",
+    tags("Core/Checker"),
+    status_codes(200),
+    responses(
+        (status_code = 200, body = inline(BaseStringResponse))
+    )
+)]
+pub async fn liveness() -> AppResult<String> {
+    result_ok("OK".to_string())
+}
+
+/// Readiness
+///
+/// Check database, Redis and RabbitMQ, used as the Kubernetes readiness probe.
+/// Returns 503 when a required dependency is down.
+#[endpoint(
+    operation_id = "core_readiness_checker",
+    tags("Core/Checker"),
+    status_codes(200, 503)
+)]
+pub async fn readiness(depot: &mut Depot, res: &mut Response) -> AppResult<ReadinessVO> {
+    let state = depot.obtain::<AppState>().unwrap();
+    let vo = HealthService::readiness(state).await;
+    if !vo.ready {
+        res.status_code(StatusCode::SERVICE_UNAVAILABLE);
+    }
+    result_ok(vo)
+}
+
+#[cfg(test)]
+mod tests {
+    use salvo::prelude::*;
+    use salvo::test::{ResponseExt, TestClient};
+    use serde_json::{Value as JsonValue, json};
+
+    use std::sync::Arc;
+
+    use crate::fixture::config::{FakerAppState, FakerService};
+    use crate::handler;
+    use crate::service::{MemoryCacheStore, MemoryPublisher};
+
+    #[tokio::test]
+    async fn test_service_health() {
+        let service = FakerService::init(handler::init_router()).await;
+
+        let mut response = TestClient::get("http://127.0.0.1:5800/checker/health")
+            .send(&service)
+            .await;
+        assert_eq!(response.status_code.unwrap(), StatusCode::OK);
+        let json: JsonValue = response.take_json().await.unwrap();
+        assert_eq!(json.get("data"), Some(&json!("OK")));
+    }
+
+    #[tokio::test]
+    async fn test_database_health() {
+        let service = FakerService::init(handler::init_router()).await;
+
+        let mut response = TestClient::get("http://127.0.0.1:5800/checker/database")
+            .send(&service)
+            .await;
+        assert_eq!(response.status_code.unwrap(), StatusCode::OK);
+        let json: JsonValue = response.take_json().await.unwrap();
+        assert_eq!(json.get("data"), Some(&json!(true)));
+    }
+
+    #[tokio::test]
+    async fn test_replicas_health() {
+        let service = FakerService::init(handler::init_router()).await;
+
+        let mut response = TestClient::get("http://127.0.0.1:5800/checker/replicas")
+            .send(&service)
+            .await;
+        assert_eq!(response.status_code.unwrap(), StatusCode::OK);
+        let json: JsonValue = response.take_json().await.unwrap();
+        assert_eq!(json.get("data"), Some(&json!([])));
+    }
+
+    #[tokio::test]
+    async fn test_liveness() {
+        let state = FakerAppState::init().await;
+        let service = Service::new(handler::probe_router(state));
+
+        let mut response = TestClient::get("http://127.0.0.1:5800/healthz")
+            .send(&service)
+            .await;
+        assert_eq!(response.status_code.unwrap(), StatusCode::OK);
+        let json: JsonValue = response.take_json().await.unwrap();
+        assert_eq!(json.get("data"), Some(&json!("OK")));
+    }
+
+    #[tokio::test]
+    async fn test_readiness() {
+        let cache = Arc::new(MemoryCacheStore::new());
+        let publisher = Arc::new(MemoryPublisher::new());
+        let state = FakerAppState::init_with(cache.clone(), publisher).await;
+        let service = Service::new(handler::probe_router(state));
+
+        let mut response = TestClient::get("http://127.0.0.1:5800/readyz")
+            .send(&service)
+            .await;
+        assert_eq!(response.status_code.unwrap(), StatusCode::OK);
+        let json: JsonValue = response.take_json().await.unwrap();
+        assert_eq!(json["data"]["ready"], json!(true));
+        assert_eq!(json["data"]["dependencies"][0]["name"], json!("database"));
+        assert_eq!(
+            json["data"]["dependencies"][0]["pending_migrations"],
+            json!(0)
+        );
+
+        cache.set_available(false);
+        let mut response = TestClient::get("http://127.0.0.1:5800/readyz")
+            .send(&service)
+            .await;
+        assert_eq!(
+            response.status_code.unwrap(),
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+        let json: JsonValue = response.take_json().await.unwrap();
+        assert_eq!(json["data"]["ready"], json!(false));
+        assert_eq!(json["data"]["dependencies"][1]["healthy"], json!(false));
+    }
+}
@@ -0,0 +1,64 @@
+use salvo::fs::NamedFile;
+use salvo::oapi::extract::PathParam;
+use salvo::prelude::*;
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::domain::{AppResult, result_ok, vo::ExportJobVO};
+use crate::enums::ErrorEnum;
+use crate::error::AppError;
+use crate::service::ExportService;
+use crate::utils::get_current_editor;
+
+/// 当前用户创建的导出任务
+fn fetch_own_job(depot: &Depot, id: Uuid) -> Result<ExportJobVO, AppError> {
+    let state = depot.obtain::<AppState>().unwrap();
+    let job = ExportService::fetch_job(&id, state)?;
+    if job.editor_id != get_current_editor(depot).editor_id {
+        return Err(ErrorEnum::RecordNotFound.into());
+    }
+    Ok(job)
+}
+
+/// Export Job
+///
+/// Background export job status
+#[endpoint(
+    operation_id = "core_export_job_view",
+    security(["bearer" = ["bearer"]]),
+    tags("Core/Export")
+)]
+pub async fn job_view(depot: &mut Depot, id: PathParam<Uuid>) -> AppResult<ExportJobVO> {
+    let job = fetch_own_job(depot, id.into_inner())?;
+    result_ok(job)
+}
+
+/// Export Download
+///
+/// Download file of finished background export job
+#[endpoint(
+    operation_id = "core_export_job_download",
+    security(["bearer" = ["bearer"]]),
+    tags("Core/Export")
+)]
+pub async fn job_download(
+    req: &mut Request,
+    depot: &mut Depot,
+    res: &mut Response,
+    id: PathParam<Uuid>,
+) -> Result<(), AppError> {
+    let job = fetch_own_job(depot, id.into_inner())?;
+    if !job.is_finished() {
+        return Err(ErrorEnum::ExportNotReady.into());
+    }
+
+    let state = depot.obtain::<AppState>().unwrap();
+    let file = NamedFile::builder(ExportService::job_file_path(&job, state))
+        .attached_name(&job.file_name)
+        .content_type(job.format_enum().content_type().parse().unwrap())
+        .build()
+        .await
+        .map_err(|_| Into::<AppError>::into(ErrorEnum::RecordNotFound))?;
+    file.send(req.headers(), res).await;
+    Ok(())
+}
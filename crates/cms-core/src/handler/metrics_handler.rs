@@ -0,0 +1,38 @@
+use salvo::http::header::CONTENT_TYPE;
+use salvo::prelude::*;
+
+use crate::config::AppState;
+use crate::service::{METRICS_CONTENT_TYPE, MetricsService};
+
+/// Prometheus 指标
+#[handler]
+pub async fn metrics(depot: &mut Depot, res: &mut Response) {
+    let state = depot.obtain::<AppState>().ok();
+    let body = MetricsService::render(state).await;
+    let _ = res.add_header(CONTENT_TYPE, METRICS_CONTENT_TYPE, true);
+    res.render(body);
+}
+
+#[cfg(test)]
+mod tests {
+    use salvo::test::{ResponseExt, TestClient};
+
+    use super::*;
+    use crate::fixture::config::FakerAppState;
+    use crate::handler;
+
+    #[tokio::test]
+    async fn test_metrics() {
+        let state = FakerAppState::init().await;
+        let service = Service::new(handler::metrics_router(state));
+
+        let mut response = TestClient::get("http://127.0.0.1:5800/metrics")
+            .send(&service)
+            .await;
+        assert_eq!(response.status_code.unwrap(), StatusCode::OK);
+        let content_type = response.headers().get(CONTENT_TYPE).unwrap();
+        assert_eq!(content_type, METRICS_CONTENT_TYPE);
+        let text = response.take_string().await.unwrap();
+        assert!(text.contains("cms_active_sessions"));
+    }
+}
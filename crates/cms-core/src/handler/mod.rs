@@ -1,11 +1,48 @@
 use salvo::prelude::*;
 
 mod checker_handler;
+mod export_handler;
+mod metrics_handler;
+mod resource_handler;
+
+use crate::config::AppState;
+use crate::middleware::jwt_verify_access;
 
 pub fn init_router() -> Router {
-    Router::new().push(
-        Router::with_path("/checker")
-            .push(Router::with_path("/health").get(checker_handler::health))
-            .push(Router::with_path("/database").get(checker_handler::database)),
-    )
+    Router::new().push(checker_router()).push(export_router())
+}
+
+/// 后台导出任务的查询和下载，创建导出任务的服务都需要挂载
+///
+/// 导出文件保存在服务本地的 `web.export_dir`，由创建任务的服务提供下载。
+pub fn export_router() -> Router {
+    Router::with_path("/exports/{id}")
+        .hoop(jwt_verify_access)
+        .get(export_handler::job_view)
+        .push(Router::with_path("download").get(export_handler::job_download))
+}
+
+/// 服务状态检查，各服务共用
+pub fn checker_router() -> Router {
+    Router::with_path("/checker")
+        .push(Router::with_path("/health").get(checker_handler::health))
+        .push(Router::with_path("/database").get(checker_handler::database))
+        .push(Router::with_path("/replicas").get(checker_handler::replicas))
+}
+
+/// Prometheus 指标，挂载在服务根路径，不经过认证
+pub fn metrics_router(state: AppState) -> Router {
+    Router::with_path("/metrics")
+        .hoop(affix_state::inject(state))
+        .get(metrics_handler::metrics)
+}
+
+/// Kubernetes 存活和就绪探针，挂载在服务根路径
+///
+/// 只注入 AppState，不经过认证和多租户，数据库不可用时就绪检查仍然返回 503。
+pub fn probe_router(state: AppState) -> Router {
+    Router::new()
+        .hoop(affix_state::inject(state))
+        .push(Router::with_path("/healthz").get(checker_handler::liveness))
+        .push(Router::with_path("/readyz").get(checker_handler::readiness))
 }
@@ -0,0 +1,676 @@
+/// 生成资源的标准管理端接口及路由
+///
+/// 在调用模块中生成 `manager_paginate`、`manager_export`、`manager_create`、`manager_query`、
+/// `manager_form`、`check_field_unique`、`update_bool_field`、`manager_view`、`manager_update`、
+/// `manager_delete` 及回收站 `manager_trash`、`manager_restore`、`manager_purge`，变更历史 `manager_history`，
+/// 批量操作 `bulk_update_bool_field`、`bulk_move`、`bulk_sort`、`bulk_delete`、`bulk_restore`
+/// 接口，以及挂载这些接口的 `manage_router()`。
+///
+/// ```ignore
+/// cms_core::resource_manage_router! {
+///     path: "apps",
+///     service: AppService,
+///     create_form: AppStoreForm,
+///     update_form: AppStoreForm,
+///     query: AppPaginateQuery,
+///     operation_id: "mate_app",
+///     tag: "Mate/Manager/App",
+/// }
+/// ```
+#[macro_export]
+macro_rules! resource_manage_router {
+    (
+        path: $path:literal,
+        service: $service:ident,
+        create_form: $create_form:ident,
+        update_form: $update_form:ident,
+        query: $query:ident,
+        operation_id: $operation_id:literal,
+        tag: $tag:literal $(,)?
+    ) => {
+        // endpoint 生成的代码依赖 Writer trait
+        use ::salvo::Writer as _;
+
+        /// Paginate List
+        ///
+        /// paginate query list
+        #[::salvo::oapi::endpoint(
+            operation_id = concat!($operation_id, "_manager_paginate"),
+            security(["bearer" = ["bearer"]]),
+            tags($tag)
+        )]
+        pub async fn manager_paginate(
+            depot: &mut ::salvo::Depot,
+            query: $query,
+            list_query: ::cms_core::domain::query::ListQuery<$service>,
+            fields_query: ::cms_core::domain::query::FieldsQuery<$service>,
+        ) -> $crate::domain::AppResult<
+            $crate::domain::vo::PaginateResultVO<
+                $crate::domain::vo::SparseVO<
+                    <$service as $crate::service::ResourceService>::MasterVO,
+                >,
+            >,
+        > {
+            let state = depot.obtain::<$crate::config::AppState>().unwrap();
+
+            let mut dto: <$service as $crate::service::ResourceService>::QueryDTO = query.into();
+            dto.load_models = Some(
+                <$service as $crate::service::ResourceService>::resolve_load_models(
+                    &$crate::enums::ViewModeEnum::ManagerList,
+                    fields_query.includes.as_deref(),
+                    &fields_query.fields,
+                )?,
+            );
+            dto.editor = $crate::utils::get_current_editor(depot);
+            dto.sorts = list_query.sorts;
+            dto.filters = list_query.filters;
+            dto.fields = fields_query.fields;
+
+            let vo = <$service as $crate::service::ResourceService>::paginage(
+                &$crate::enums::PlatformEnum::Manager,
+                &dto,
+                state,
+            )
+            .await?;
+            let vo = vo.map(|item| $crate::domain::vo::SparseVO::new(item, dto.fields.clone()));
+            $crate::domain::result_ok(vo)
+        }
+
+        /// Export
+        ///
+        /// export list as csv or ndjson, large exports run in background
+        #[::salvo::oapi::endpoint(
+            operation_id = concat!($operation_id, "_manager_export"),
+            security(["bearer" = ["bearer"]]),
+            tags($tag),
+            responses(
+                (status_code = 200, description = "CSV or NDJSON file, or background export job", body = $crate::domain::vo::ExportJobVO)
+            )
+        )]
+        pub async fn manager_export(
+            depot: &mut ::salvo::Depot,
+            res: &mut ::salvo::Response,
+            query: $query,
+            list_query: ::cms_core::domain::query::ListQuery<$service>,
+            fields_query: ::cms_core::domain::query::FieldsQuery<$service>,
+            export_query: ::cms_core::domain::query::ExportQuery,
+        ) -> Result<(), $crate::error::AppError> {
+            let state = depot.obtain::<$crate::config::AppState>().unwrap();
+            let format = export_query.format_enum()?;
+
+            let mut dto: <$service as $crate::service::ResourceService>::QueryDTO = query.into();
+            dto.load_models = Some(
+                <$service as $crate::service::ResourceService>::resolve_load_models(
+                    &$crate::enums::ViewModeEnum::ManagerList,
+                    fields_query.includes.as_deref(),
+                    &fields_query.fields,
+                )?,
+            );
+            dto.editor = $crate::utils::get_current_editor(depot);
+            dto.sorts = list_query.sorts;
+            dto.filters = list_query.filters;
+            dto.fields = fields_query.fields;
+
+            let platform = $crate::enums::PlatformEnum::Manager;
+            let total =
+                $crate::service::ExportService::count::<$service>(&platform, &dto, state).await?;
+            if export_query.background.unwrap_or(false) || total > $crate::service::EXPORT_SYNC_LIMIT {
+                let job = $crate::service::ExportService::start_job::<$service>(
+                    $path,
+                    platform,
+                    dto,
+                    format,
+                    total,
+                    state.clone(),
+                )?;
+                res.render(::salvo::writing::Json($crate::domain::response::AppResponse::success(job)));
+                return Ok(());
+            }
+
+            let file_name = format!("{}.{}", $path, format.as_value());
+            res.add_header(::salvo::http::header::CONTENT_TYPE, format.content_type(), true)
+                .ok();
+            res.add_header(
+                ::salvo::http::header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", file_name),
+                true,
+            )
+            .ok();
+            let sender = res.channel();
+            $crate::service::ExportService::stream::<$service>(
+                platform,
+                dto,
+                format,
+                sender,
+                state.clone(),
+            );
+            Ok(())
+        }
+
+        /// Create
+        ///
+        /// Create record
+        #[::salvo::oapi::endpoint(
+            operation_id = concat!($operation_id, "_manager_create"),
+            security(["bearer" = ["bearer"]]),
+            tags($tag)
+        )]
+        pub async fn manager_create(
+            depot: &mut ::salvo::Depot,
+            json: ::salvo::oapi::extract::JsonBody<$create_form>,
+        ) -> $crate::domain::AppResult<bool> {
+            let form = json.into_inner();
+            ::validator::Validate::validate(&form)?;
+
+            let state = depot.obtain::<$crate::config::AppState>().unwrap();
+            let mut dto: <$service as $crate::service::ResourceService>::StoreDTO = form.into();
+            dto.editor = $crate::utils::get_current_editor(depot);
+
+            <$service as $crate::service::ResourceService>::store(
+                &$crate::enums::PlatformEnum::Manager,
+                &dto,
+                state,
+            )
+            .await?;
+            $crate::domain::result_ok(true)
+        }
+
+        /// Update
+        ///
+        /// Update record
+        #[::salvo::oapi::endpoint(
+            operation_id = concat!($operation_id, "_manager_update"),
+            security(["bearer" = ["bearer"]]),
+            tags($tag)
+        )]
+        pub async fn manager_update(
+            req: &mut ::salvo::Request,
+            depot: &mut ::salvo::Depot,
+            id: ::salvo::oapi::extract::PathParam<i64>,
+            json: ::salvo::oapi::extract::JsonBody<$update_form>,
+        ) -> $crate::domain::AppResult<bool> {
+            let form = json.into_inner();
+            ::validator::Validate::validate(&form)?;
+
+            let state = depot.obtain::<$crate::config::AppState>().unwrap();
+            let mut dto: <$service as $crate::service::ResourceService>::StoreDTO = form.into();
+            dto.id = id.into_inner();
+            dto.editor = $crate::utils::get_current_editor(depot);
+            // If-Match 请求头优先作为版本号
+            if let Some(version_no) = $crate::utils::header_utils::if_match_version(req) {
+                dto.version_no = Some(version_no);
+            }
+
+            <$service as $crate::service::ResourceService>::store(
+                &$crate::enums::PlatformEnum::Manager,
+                &dto,
+                state,
+            )
+            .await?;
+            $crate::domain::result_ok(true)
+        }
+
+        /// Delete
+        ///
+        /// Delete record
+        #[::salvo::oapi::endpoint(
+            operation_id = concat!($operation_id, "_manager_delete"),
+            security(["bearer" = ["bearer"]]),
+            tags($tag)
+        )]
+        pub async fn manager_delete(
+            depot: &mut ::salvo::Depot,
+            id: ::salvo::oapi::extract::PathParam<i64>,
+        ) -> $crate::domain::AppResult<bool> {
+            let state = depot.obtain::<$crate::config::AppState>().unwrap();
+
+            let dto = $crate::domain::dto::ModelLogicDeleteDTO {
+                id: $crate::enums::PrimaryIdEnum::BigInt(id.into_inner()),
+                editor: $crate::utils::get_current_editor(depot),
+            };
+
+            <$service as $crate::service::ResourceService>::logic_delete(&dto, state).await?;
+            $crate::domain::result_ok(true)
+        }
+
+        /// Form Options
+        ///
+        /// store form options
+        #[::salvo::oapi::endpoint(
+            operation_id = concat!($operation_id, "_manager_form"),
+            security(["bearer" = ["bearer"]]),
+            tags($tag)
+        )]
+        pub async fn manager_form(
+            depot: &mut ::salvo::Depot,
+            res: &mut ::salvo::Response,
+        ) -> $crate::domain::AppResult<
+            <$service as $crate::service::ResourceService>::FormOptionVO,
+        > {
+            let state = depot.obtain::<$crate::config::AppState>().unwrap();
+            let version = $crate::service::CacheService::get_cache_version(state.cache.as_ref());
+            let vo = <$service as $crate::service::ResourceService>::form_options(
+                &$crate::enums::PlatformEnum::Manager,
+                state,
+            )
+            .await?;
+            // 选项数据按租户和缓存版本生成 ETag，Redis 不可用时按响应内容生成
+            if let Some(version) = version {
+                let scope = $crate::service::TenantService::cache_key("options");
+                $crate::utils::header_utils::set_etag(
+                    depot,
+                    $crate::utils::header_utils::cache_version_etag(&scope, version),
+                );
+            }
+            let _ = res.add_header(
+                ::salvo::http::header::VARY,
+                $crate::utils::header_utils::OPTIONS_VARY,
+                true,
+            );
+            $crate::domain::result_ok(vo)
+        }
+
+        /// Query Options
+        ///
+        /// Paginate query options
+        #[::salvo::oapi::endpoint(
+            operation_id = concat!($operation_id, "_manager_query"),
+            security(["bearer" = ["bearer"]]),
+            tags($tag)
+        )]
+        pub async fn manager_query(
+            depot: &mut ::salvo::Depot,
+            res: &mut ::salvo::Response,
+        ) -> $crate::domain::AppResult<
+            <$service as $crate::service::ResourceService>::QueryOptionVO,
+        > {
+            let state = depot.obtain::<$crate::config::AppState>().unwrap();
+            let version = $crate::service::CacheService::get_cache_version(state.cache.as_ref());
+            let vo = <$service as $crate::service::ResourceService>::query_options(
+                &$crate::enums::PlatformEnum::Manager,
+                state,
+            )
+            .await?;
+            // 选项数据按租户和缓存版本生成 ETag，Redis 不可用时按响应内容生成
+            if let Some(version) = version {
+                let scope = $crate::service::TenantService::cache_key("options");
+                $crate::utils::header_utils::set_etag(
+                    depot,
+                    $crate::utils::header_utils::cache_version_etag(&scope, version),
+                );
+            }
+            let _ = res.add_header(
+                ::salvo::http::header::VARY,
+                $crate::utils::header_utils::OPTIONS_VARY,
+                true,
+            );
+            $crate::domain::result_ok(vo)
+        }
+
+        /// Field Unique
+        ///
+        /// Field unique check
+        #[::salvo::oapi::endpoint(
+            operation_id = concat!($operation_id, "_check_field_unique"),
+            security(["bearer" = ["bearer"]]),
+            tags($tag)
+        )]
+        pub async fn check_field_unique(
+            depot: &mut ::salvo::Depot,
+            // endpoint 无法解析参数类型中的 `$crate`，因此使用完整路径
+            json: ::salvo::oapi::extract::JsonBody<::cms_core::domain::form::FieldValueUniqueForm>,
+        ) -> $crate::domain::AppResult<bool> {
+            let form = json.into_inner();
+            ::validator::Validate::validate(&form)?;
+
+            let dto: $crate::domain::dto::FieldValueUniqueDTO = form.into();
+            let state = depot.obtain::<$crate::config::AppState>().unwrap();
+            let value =
+                <$service as $crate::service::ResourceService>::field_unique(&dto, state).await?;
+            $crate::domain::result_ok(value)
+        }
+
+        /// Update Bool Field
+        ///
+        /// Update Bool Field
+        #[::salvo::oapi::endpoint(
+            operation_id = concat!($operation_id, "_update_bool_field"),
+            security(["bearer" = ["bearer"]]),
+            tags($tag)
+        )]
+        pub async fn update_bool_field(
+            req: &mut ::salvo::Request,
+            depot: &mut ::salvo::Depot,
+            id: ::salvo::oapi::extract::PathParam<i64>,
+            json: ::salvo::oapi::extract::JsonBody<::cms_core::domain::form::FieldBoolUpdateForm>,
+        ) -> $crate::domain::AppResult<bool> {
+            let form = json.into_inner();
+            ::validator::Validate::validate(&form)?;
+
+            // 因为设置 id 所以必须指定 dto 类型
+            let mut dto: $crate::domain::dto::FieldBoolUpdateDTO = form.into();
+            dto.id = id.into_inner();
+            dto.editor = $crate::utils::get_current_editor(depot);
+            dto.version_no = $crate::utils::header_utils::if_match_version(req);
+
+            let state = depot.obtain::<$crate::config::AppState>().unwrap();
+            let value =
+                <$service as $crate::service::ResourceService>::update_bool_field(&dto, state)
+                    .await?;
+            $crate::domain::result_ok(value)
+        }
+
+        /// View
+        ///
+        /// View record
+        #[::salvo::oapi::endpoint(
+            operation_id = concat!($operation_id, "_manager_view"),
+            security(["bearer" = ["bearer"]]),
+            tags($tag)
+        )]
+        pub async fn manager_view(
+            depot: &mut ::salvo::Depot,
+            id: ::salvo::oapi::extract::PathParam<i64>,
+            fields_query: ::cms_core::domain::query::FieldsQuery<$service>,
+        ) -> $crate::domain::AppResult<
+            $crate::domain::vo::SparseVO<<$service as $crate::service::ResourceService>::MasterVO>,
+        > {
+            let state = depot.obtain::<$crate::config::AppState>().unwrap();
+            let load_models = <$service as $crate::service::ResourceService>::resolve_load_models(
+                &$crate::enums::ViewModeEnum::ManagerDetail,
+                fields_query.includes.as_deref(),
+                &fields_query.fields,
+            )?;
+            let fields = fields_query.fields;
+            let dto = $crate::domain::dto::ModelViewDTO {
+                id: $crate::enums::PrimaryIdEnum::BigInt(id.into_inner()),
+                load_models: Some(load_models),
+                editor: $crate::utils::get_current_editor(depot),
+                fields: Some(fields.clone()),
+                ..Default::default()
+            };
+
+            let vo = <$service as $crate::service::ResourceService>::view(
+                &$crate::enums::PlatformEnum::Manager,
+                &dto,
+                state,
+            )
+            .await?;
+            // 未加载关联数据时按版本号生成 ETag，可直接用于更新时的 If-Match
+            if dto.load_models.as_ref().is_none_or(|list| list.is_empty()) {
+                if let Some(etag) = $crate::utils::header_utils::vo_etag(&vo) {
+                    $crate::utils::header_utils::set_etag(depot, etag);
+                }
+            }
+            $crate::domain::result_ok($crate::domain::vo::SparseVO::new(vo, fields))
+        }
+
+        /// Trash List
+        ///
+        /// paginate query deleted records
+        #[::salvo::oapi::endpoint(
+            operation_id = concat!($operation_id, "_manager_trash"),
+            security(["bearer" = ["bearer"]]),
+            tags($tag)
+        )]
+        pub async fn manager_trash(
+            depot: &mut ::salvo::Depot,
+            query: $query,
+        ) -> $crate::domain::AppResult<
+            $crate::domain::vo::PaginateResultVO<
+                <$service as $crate::service::ResourceService>::MasterVO,
+            >,
+        > {
+            let state = depot.obtain::<$crate::config::AppState>().unwrap();
+
+            let mut dto: <$service as $crate::service::ResourceService>::QueryDTO = query.into();
+            dto.editor = $crate::utils::get_current_editor(depot);
+
+            let vo =
+                <$service as $crate::service::ResourceService>::trash_paginage(&dto, state).await?;
+            $crate::domain::result_ok(vo)
+        }
+
+        /// Restore
+        ///
+        /// Restore deleted record
+        #[::salvo::oapi::endpoint(
+            operation_id = concat!($operation_id, "_manager_restore"),
+            security(["bearer" = ["bearer"]]),
+            tags($tag)
+        )]
+        pub async fn manager_restore(
+            depot: &mut ::salvo::Depot,
+            id: ::salvo::oapi::extract::PathParam<i64>,
+        ) -> $crate::domain::AppResult<bool> {
+            let state = depot.obtain::<$crate::config::AppState>().unwrap();
+
+            let dto = $crate::domain::dto::ModelLogicDeleteDTO {
+                id: $crate::enums::PrimaryIdEnum::BigInt(id.into_inner()),
+                editor: $crate::utils::get_current_editor(depot),
+            };
+
+            <$service as $crate::service::ResourceService>::restore(&dto, state).await?;
+            $crate::domain::result_ok(true)
+        }
+
+        /// Purge
+        ///
+        /// Permanently delete record
+        #[::salvo::oapi::endpoint(
+            operation_id = concat!($operation_id, "_manager_purge"),
+            security(["bearer" = ["bearer"]]),
+            tags($tag)
+        )]
+        pub async fn manager_purge(
+            depot: &mut ::salvo::Depot,
+            id: ::salvo::oapi::extract::PathParam<i64>,
+        ) -> $crate::domain::AppResult<bool> {
+            let state = depot.obtain::<$crate::config::AppState>().unwrap();
+
+            let dto = $crate::domain::dto::ModelLogicDeleteDTO {
+                id: $crate::enums::PrimaryIdEnum::BigInt(id.into_inner()),
+                editor: $crate::utils::get_current_editor(depot),
+            };
+
+            <$service as $crate::service::ResourceService>::purge(&dto, state).await?;
+            $crate::domain::result_ok(true)
+        }
+
+        /// History
+        ///
+        /// paginate query change history of record
+        #[::salvo::oapi::endpoint(
+            operation_id = concat!($operation_id, "_manager_history"),
+            security(["bearer" = ["bearer"]]),
+            tags($tag)
+        )]
+        pub async fn manager_history(
+            depot: &mut ::salvo::Depot,
+            id: ::salvo::oapi::extract::PathParam<i64>,
+            query: ::cms_core::domain::query::AuditPaginateQuery,
+        ) -> $crate::domain::AppResult<$crate::domain::vo::PaginateResultVO<$crate::domain::vo::AuditVO>>
+        {
+            let state = depot.obtain::<$crate::config::AppState>().unwrap();
+
+            let dto: $crate::domain::dto::AuditQueryDTO = query.into();
+            let vo = <$service as $crate::service::ResourceService>::history(
+                id.into_inner(),
+                &dto,
+                state,
+            )
+            .await?;
+            $crate::domain::result_ok(vo)
+        }
+
+        /// Bulk Update Bool Field
+        ///
+        /// Update bool field of records in one transaction
+        #[::salvo::oapi::endpoint(
+            operation_id = concat!($operation_id, "_bulk_update_bool_field"),
+            security(["bearer" = ["bearer"]]),
+            tags($tag)
+        )]
+        pub async fn bulk_update_bool_field(
+            depot: &mut ::salvo::Depot,
+            json: ::salvo::oapi::extract::JsonBody<::cms_core::domain::form::BulkBoolUpdateForm>,
+        ) -> $crate::domain::AppResult<$crate::domain::vo::BulkResultVO> {
+            let form = json.into_inner();
+            ::validator::Validate::validate(&form)?;
+
+            let mut dto: $crate::domain::dto::BulkUpdateDTO<_> = form.into();
+            dto.editor = $crate::utils::get_current_editor(depot);
+
+            let state = depot.obtain::<$crate::config::AppState>().unwrap();
+            let vo =
+                <$service as $crate::service::ResourceService>::bulk_update_bool_field(&dto, state).await?;
+            $crate::domain::result_ok(vo)
+        }
+
+        /// Bulk Move
+        ///
+        /// Move records to another parent in one transaction
+        #[::salvo::oapi::endpoint(
+            operation_id = concat!($operation_id, "_bulk_move"),
+            security(["bearer" = ["bearer"]]),
+            tags($tag)
+        )]
+        pub async fn bulk_move(
+            depot: &mut ::salvo::Depot,
+            json: ::salvo::oapi::extract::JsonBody<::cms_core::domain::form::BulkMoveForm>,
+        ) -> $crate::domain::AppResult<$crate::domain::vo::BulkResultVO> {
+            let form = json.into_inner();
+            ::validator::Validate::validate(&form)?;
+
+            let mut dto: $crate::domain::dto::BulkUpdateDTO<_> = form.into();
+            dto.editor = $crate::utils::get_current_editor(depot);
+
+            let state = depot.obtain::<$crate::config::AppState>().unwrap();
+            let vo =
+                <$service as $crate::service::ResourceService>::bulk_move(&dto, state).await?;
+            $crate::domain::result_ok(vo)
+        }
+
+        /// Bulk Sort
+        ///
+        /// Update sort of records in one transaction
+        #[::salvo::oapi::endpoint(
+            operation_id = concat!($operation_id, "_bulk_sort"),
+            security(["bearer" = ["bearer"]]),
+            tags($tag)
+        )]
+        pub async fn bulk_sort(
+            depot: &mut ::salvo::Depot,
+            json: ::salvo::oapi::extract::JsonBody<::cms_core::domain::form::BulkSortForm>,
+        ) -> $crate::domain::AppResult<$crate::domain::vo::BulkResultVO> {
+            let form = json.into_inner();
+            ::validator::Validate::validate(&form)?;
+
+            let mut dto: $crate::domain::dto::BulkUpdateDTO<_> = form.into();
+            dto.editor = $crate::utils::get_current_editor(depot);
+
+            let state = depot.obtain::<$crate::config::AppState>().unwrap();
+            let vo =
+                <$service as $crate::service::ResourceService>::bulk_sort(&dto, state).await?;
+            $crate::domain::result_ok(vo)
+        }
+
+        /// Bulk Delete
+        ///
+        /// Move records to trash in one transaction
+        #[::salvo::oapi::endpoint(
+            operation_id = concat!($operation_id, "_bulk_delete"),
+            security(["bearer" = ["bearer"]]),
+            tags($tag)
+        )]
+        pub async fn bulk_delete(
+            depot: &mut ::salvo::Depot,
+            json: ::salvo::oapi::extract::JsonBody<::cms_core::domain::form::BulkIdsForm>,
+        ) -> $crate::domain::AppResult<$crate::domain::vo::BulkResultVO> {
+            let form = json.into_inner();
+            ::validator::Validate::validate(&form)?;
+
+            let mut dto: $crate::domain::dto::BulkUpdateDTO<_> = form.into();
+            dto.editor = $crate::utils::get_current_editor(depot);
+
+            let state = depot.obtain::<$crate::config::AppState>().unwrap();
+            let vo =
+                <$service as $crate::service::ResourceService>::bulk_delete(&dto, state).await?;
+            $crate::domain::result_ok(vo)
+        }
+
+        /// Bulk Restore
+        ///
+        /// Restore records from trash in one transaction
+        #[::salvo::oapi::endpoint(
+            operation_id = concat!($operation_id, "_bulk_restore"),
+            security(["bearer" = ["bearer"]]),
+            tags($tag)
+        )]
+        pub async fn bulk_restore(
+            depot: &mut ::salvo::Depot,
+            json: ::salvo::oapi::extract::JsonBody<::cms_core::domain::form::BulkIdsForm>,
+        ) -> $crate::domain::AppResult<$crate::domain::vo::BulkResultVO> {
+            let form = json.into_inner();
+            ::validator::Validate::validate(&form)?;
+
+            let mut dto: $crate::domain::dto::BulkUpdateDTO<_> = form.into();
+            dto.editor = $crate::utils::get_current_editor(depot);
+
+            let state = depot.obtain::<$crate::config::AppState>().unwrap();
+            let vo =
+                <$service as $crate::service::ResourceService>::bulk_restore(&dto, state).await?;
+            $crate::domain::result_ok(vo)
+        }
+
+        /// 管理端路由
+        pub fn manage_router() -> ::salvo::Router {
+            ::salvo::Router::with_path($path)
+                .get(manager_paginate)
+                // 创建接口支持 Idempotency-Key 防止重试时重复创建
+                .push(
+                    ::salvo::Router::new()
+                        .hoop($crate::middleware::idempotency)
+                        .post(manager_create),
+                )
+                .push(
+                    ::salvo::Router::with_path("query")
+                        .hoop($crate::middleware::cache_control(
+                            $crate::utils::header_utils::OPTIONS_CACHE_CONTROL,
+                        ))
+                        .get(manager_query),
+                )
+                .push(
+                    ::salvo::Router::with_path("form")
+                        .hoop($crate::middleware::cache_control(
+                            $crate::utils::header_utils::OPTIONS_CACHE_CONTROL,
+                        ))
+                        .get(manager_form),
+                )
+                .push(::salvo::Router::with_path("export").get(manager_export))
+                .push(::salvo::Router::with_path("unique").post(check_field_unique))
+                .push(
+                    ::salvo::Router::with_path("bulk")
+                        .push(::salvo::Router::with_path("bool").patch(bulk_update_bool_field))
+                        .push(::salvo::Router::with_path("move").patch(bulk_move))
+                        .push(::salvo::Router::with_path("sort").patch(bulk_sort))
+                        .push(::salvo::Router::with_path("delete").post(bulk_delete))
+                        .push(::salvo::Router::with_path("restore").patch(bulk_restore)),
+                )
+                .push(
+                    ::salvo::Router::with_path("trash")
+                        .get(manager_trash)
+                        .push(::salvo::Router::with_path("{id}").delete(manager_purge))
+                        .push(::salvo::Router::with_path("{id}/restore").patch(manager_restore)),
+                )
+                .push(::salvo::Router::with_path("{id}/bool").patch(update_bool_field))
+                .push(::salvo::Router::with_path("{id}/history").get(manager_history))
+                .push(
+                    ::salvo::Router::with_path("{id}")
+                        .get(manager_view)
+                        .patch(manager_update)
+                        .delete(manager_delete),
+                )
+        }
+    };
+}
@@ -3,6 +3,7 @@ pub mod consts;
 pub mod domain;
 pub mod enums;
 pub mod error;
+#[cfg(any(test, feature = "fixture"))]
 pub mod fixture;
 pub mod handler;
 pub mod middleware;
@@ -0,0 +1,72 @@
+use salvo::prelude::*;
+
+use crate::utils::header_utils::DEPOT_CACHE_CONTROL_KEY;
+
+/// 指定路由 GET 响应的 Cache-Control
+///
+/// ```ignore
+/// Router::with_path("apps/form")
+///     .hoop(cache_control("private, max-age=60"))
+///     .get(manager_form)
+/// ```
+pub struct CacheControl {
+    value: String,
+}
+
+pub fn cache_control(value: &str) -> CacheControl {
+    CacheControl {
+        value: value.to_owned(),
+    }
+}
+
+#[async_trait]
+impl Handler for CacheControl {
+    async fn handle(
+        &self,
+        _req: &mut Request,
+        depot: &mut Depot,
+        _res: &mut Response,
+        _ctrl: &mut FlowCtrl,
+    ) {
+        depot.insert(DEPOT_CACHE_CONTROL_KEY, self.value.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use salvo::http::header::CACHE_CONTROL;
+    use salvo::test::TestClient;
+
+    use super::*;
+    use crate::domain::{AppResult, result_ok};
+    use crate::utils::header_utils::{DEFAULT_CACHE_CONTROL, OPTIONS_CACHE_CONTROL};
+
+    #[handler]
+    async fn success() -> AppResult<bool> {
+        result_ok(true)
+    }
+
+    #[tokio::test]
+    async fn test_cache_control() {
+        let router = Router::new()
+            .push(
+                Router::with_path("form")
+                    .hoop(cache_control(OPTIONS_CACHE_CONTROL))
+                    .get(success),
+            )
+            .push(Router::with_path("list").get(success));
+        let service = Service::new(router);
+
+        let response = TestClient::get("http://127.0.0.1:5800/form")
+            .send(&service)
+            .await;
+        let value = response.headers().get(CACHE_CONTROL).unwrap();
+        assert_eq!(value, OPTIONS_CACHE_CONTROL);
+
+        let response = TestClient::get("http://127.0.0.1:5800/list")
+            .send(&service)
+            .await;
+        let value = response.headers().get(CACHE_CONTROL).unwrap();
+        assert_eq!(value, DEFAULT_CACHE_CONTROL);
+    }
+}
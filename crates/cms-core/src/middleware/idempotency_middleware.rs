@@ -0,0 +1,280 @@
+use salvo::http::{Method, StatusCode};
+use salvo::prelude::*;
+use serde_json::Value as JsonValue;
+
+use crate::config::AppState;
+use crate::consts::enum_consts::{
+    ERROR_IDEMPOTENCY_KEY_REUSED_MESSAGE, ERROR_IDEMPOTENCY_PROCESSING_MESSAGE,
+};
+use crate::domain::dto::IdempotencyRecordDTO;
+use crate::domain::response::AppResponse;
+use crate::utils::{get_current_editor, header_utils};
+
+/// 请求头 Idempotency-Key
+pub const HEADER_IDEMPOTENCY_KEY: &str = "Idempotency-Key";
+
+/// 重放响应时的标记头
+pub const HEADER_IDEMPOTENT_REPLAYED: &str = "Idempotent-Replayed";
+
+/// 处理中记录的保存时间（秒），请求异常中断时到期后可重试
+const PROCESSING_TTL: u64 = 60;
+
+/// 响应的保存时间（秒）
+const RESPONSE_TTL: u64 = 86_400;
+
+/// 幂等请求
+///
+/// POST 请求带有 `Idempotency-Key` 时，按编辑用户和键在缓存中保存请求指纹及成功的响应。
+/// 相同键的重试直接返回保存的响应；键已用于不同的请求时返回 422，相同请求仍在处理时返回 409。
+/// 失败的响应不保存，客户端可使用相同的键重试。
+#[handler]
+pub async fn idempotency(
+    req: &mut Request,
+    depot: &mut Depot,
+    res: &mut Response,
+    ctrl: &mut FlowCtrl,
+) {
+    if req.method() != Method::POST {
+        return;
+    }
+    let key = match req.header::<String>(HEADER_IDEMPOTENCY_KEY) {
+        Some(key) if !key.trim().is_empty() => key.trim().to_owned(),
+        _ => return,
+    };
+    let cache = match depot.obtain::<AppState>() {
+        Ok(state) => state.cache.clone(),
+        Err(_) => return,
+    };
+
+    let editor = get_current_editor(depot);
+    let redis_key = format!("idempotency:{}:{}", editor.editor_id, key);
+    let fingerprint = request_fingerprint(req).await;
+
+    let processing = IdempotencyRecordDTO::processing(&fingerprint);
+    let value = serde_json::to_string(&processing).unwrap_or_default();
+    // 缓存不可用时按普通请求处理
+    let inserted = match cache.set_nx(&redis_key, &value, Some(PROCESSING_TTL)) {
+        Ok(inserted) => inserted,
+        Err(_) => return,
+    };
+    if !inserted {
+        let stored = cache.get(&redis_key).ok().flatten();
+        let record =
+            stored.and_then(|json| serde_json::from_str::<IdempotencyRecordDTO>(&json).ok());
+        match record {
+            Some(record) if record.fingerprint != fingerprint => {
+                render_error(
+                    depot,
+                    res,
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    ERROR_IDEMPOTENCY_KEY_REUSED_MESSAGE,
+                );
+            }
+            Some(IdempotencyRecordDTO {
+                status: Some(status),
+                body: Some(body),
+                ..
+            }) => {
+                res.status_code(StatusCode::from_u16(status).unwrap_or(StatusCode::OK));
+                let _ = res.add_header(HEADER_IDEMPOTENT_REPLAYED, "true", true);
+                res.render(Text::Json(body));
+            }
+            _ => {
+                render_error(
+                    depot,
+                    res,
+                    StatusCode::CONFLICT,
+                    ERROR_IDEMPOTENCY_PROCESSING_MESSAGE,
+                );
+            }
+        }
+        ctrl.skip_rest();
+        return;
+    }
+
+    ctrl.call_next(req, depot, res).await;
+
+    // AppResponse 的 Writer 将成功响应写入 depot 的 res_v
+    let status = res.status_code.unwrap_or(StatusCode::OK);
+    match depot.get::<String>("res_v") {
+        Ok(body) if status.is_success() && is_success_body(body) => {
+            let record = IdempotencyRecordDTO {
+                fingerprint,
+                status: Some(status.as_u16()),
+                body: Some(body.clone()),
+            };
+            let value = serde_json::to_string(&record).unwrap_or_default();
+            let _ = cache.set(&redis_key, &value, Some(RESPONSE_TTL));
+        }
+        _ => {
+            let _ = cache.del(&redis_key);
+        }
+    }
+}
+
+/// 请求指纹：方法、路径、查询参数和请求体的 MD5
+async fn request_fingerprint(req: &mut Request) -> String {
+    let mut content = format!("{} {}\n", req.method(), req.uri()).into_bytes();
+    if let Ok(payload) = req.payload().await {
+        content.extend_from_slice(payload);
+    }
+    format!("{:x}", md5::compute(content))
+}
+
+/// 响应内容中的状态码是否为成功
+fn is_success_body(body: &str) -> bool {
+    serde_json::from_str::<JsonValue>(body)
+        .ok()
+        .and_then(|json| json.get("code").and_then(|code| code.as_u64()))
+        .is_some_and(|code| code == 200)
+}
+
+fn render_error(depot: &Depot, res: &mut Response, status: StatusCode, message: &str) {
+    let response: AppResponse<()> = AppResponse::error(status.as_u16() as u32, message)
+        .with_request_id(header_utils::request_id(depot));
+    res.status_code(status);
+    res.render(Json(response));
+}
+
+#[cfg(test)]
+mod tests {
+    use salvo::affix_state;
+    use salvo::test::{ResponseExt, TestClient};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use tokio::sync::Notify;
+
+    use super::*;
+    use crate::fixture::config::FakerAppState;
+
+    /// 测试接口的调用次数及行为
+    #[derive(Default)]
+    struct Counter {
+        calls: AtomicUsize,
+        fail: AtomicBool,
+        blocked: AtomicBool,
+        started: Notify,
+        release: Notify,
+    }
+
+    #[handler]
+    async fn create(depot: &mut Depot) -> AppResponse<String> {
+        let counter = depot.obtain::<Arc<Counter>>().unwrap().clone();
+        if counter.blocked.load(Ordering::SeqCst) {
+            counter.started.notify_one();
+            counter.release.notified().await;
+        }
+        let calls = counter.calls.fetch_add(1, Ordering::SeqCst) + 1;
+        if counter.fail.load(Ordering::SeqCst) {
+            return AppResponse::error(400, "failed");
+        }
+        AppResponse::success(format!("created {}", calls))
+    }
+
+    async fn init_service() -> (Service, Arc<Counter>) {
+        let state = FakerAppState::init().await;
+        let counter = Arc::new(Counter::default());
+        let router = Router::new()
+            .hoop(affix_state::inject(state).inject(counter.clone()))
+            .hoop(idempotency)
+            .post(create);
+        (Service::new(router), counter)
+    }
+
+    async fn post(service: &Service, key: &str, body: &str) -> Response {
+        TestClient::post("http://127.0.0.1:5800/")
+            .add_header(HEADER_IDEMPOTENCY_KEY, key, true)
+            .text(body.to_owned())
+            .send(service)
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_idempotency_replay() {
+        let (service, counter) = init_service().await;
+
+        let mut res = post(&service, "key-1", "name=app").await;
+        assert!(res.headers().get(HEADER_IDEMPOTENT_REPLAYED).is_none());
+        let first = res.take_string().await.unwrap();
+
+        // 相同键的重试返回保存的响应，不再调用接口
+        let mut res = post(&service, "key-1", "name=app").await;
+        assert_eq!(res.status_code, Some(StatusCode::OK));
+        assert_eq!(
+            res.headers().get(HEADER_IDEMPOTENT_REPLAYED).unwrap(),
+            "true"
+        );
+        let replayed: JsonValue = res.take_json().await.unwrap();
+        let first: JsonValue = serde_json::from_str(&first).unwrap();
+        assert_eq!(replayed.get("data"), first.get("data"));
+        assert_eq!(counter.calls.load(Ordering::SeqCst), 1);
+
+        // 不同的键按新请求处理
+        post(&service, "key-2", "name=app").await;
+        assert_eq!(counter.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_idempotency_key_reused() {
+        let (service, counter) = init_service().await;
+
+        post(&service, "key-1", "name=app").await;
+        let mut res = post(&service, "key-1", "name=mate").await;
+        assert_eq!(res.status_code, Some(StatusCode::UNPROCESSABLE_ENTITY));
+        let json: JsonValue = res.take_json().await.unwrap();
+        assert_eq!(
+            json.get("message").and_then(JsonValue::as_str),
+            Some(ERROR_IDEMPOTENCY_KEY_REUSED_MESSAGE)
+        );
+        assert_eq!(counter.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_idempotency_processing() {
+        let (service, counter) = init_service().await;
+        counter.blocked.store(true, Ordering::SeqCst);
+
+        let service = Arc::new(service);
+        let first = {
+            let service = service.clone();
+            tokio::spawn(async move { post(&service, "key-1", "name=app").await })
+        };
+        // 第一个请求处理中时，相同的请求返回 409
+        counter.started.notified().await;
+        let mut res = post(&service, "key-1", "name=app").await;
+        assert_eq!(res.status_code, Some(StatusCode::CONFLICT));
+        let json: JsonValue = res.take_json().await.unwrap();
+        assert_eq!(
+            json.get("message").and_then(JsonValue::as_str),
+            Some(ERROR_IDEMPOTENCY_PROCESSING_MESSAGE)
+        );
+        assert_eq!(counter.calls.load(Ordering::SeqCst), 0);
+
+        counter.blocked.store(false, Ordering::SeqCst);
+        counter.release.notify_one();
+        let res = first.await.unwrap();
+        assert_eq!(res.status_code, Some(StatusCode::OK));
+        assert_eq!(counter.calls.load(Ordering::SeqCst), 1);
+
+        let res = post(&service, "key-1", "name=app").await;
+        assert!(res.headers().get(HEADER_IDEMPOTENT_REPLAYED).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_idempotency_release_after_failure() {
+        let (service, counter) = init_service().await;
+        counter.fail.store(true, Ordering::SeqCst);
+
+        let mut res = post(&service, "key-1", "name=app").await;
+        let json: JsonValue = res.take_json().await.unwrap();
+        assert_eq!(json.get("code"), Some(&serde_json::json!(400)));
+
+        // 失败的响应不保存，相同的键可以重试
+        counter.fail.store(false, Ordering::SeqCst);
+        let mut res = post(&service, "key-1", "name=app").await;
+        assert!(res.headers().get(HEADER_IDEMPOTENT_REPLAYED).is_none());
+        let json: JsonValue = res.take_json().await.unwrap();
+        assert_eq!(json.get("code"), Some(&serde_json::json!(200)));
+        assert_eq!(counter.calls.load(Ordering::SeqCst), 2);
+    }
+}
@@ -6,9 +6,9 @@ use crate::config::JwtConfig;
 use crate::domain::dto::JwtClaimsDTO;
 use crate::domain::response::AppResponse;
 use crate::service::JwtService;
+use crate::utils::header_utils;
 
-pub fn jwt_authorizor_init() -> JwtAuth<JwtClaimsDTO, ConstDecoder> {
-    let cfg = JwtConfig::from_env().expect("Failed to load jwt config");
+pub fn jwt_authorizor_init(cfg: &JwtConfig) -> JwtAuth<JwtClaimsDTO, ConstDecoder> {
     let secret_bytes = cfg.secret_bytes();
     let auth: JwtAuth<JwtClaimsDTO, ConstDecoder> =
         JwtAuth::new(ConstDecoder::from_secret(&secret_bytes))
@@ -23,7 +23,9 @@ pub fn jwt_verify_access(depot: &mut Depot, res: &mut Response, ctrl: &mut FlowC
     let result = JwtService::verify_access_token(depot);
     if let Err(err) = result {
         let response: AppResponse<HashMap<String, String>> = err.into();
-        res.render(Json(response));
+        res.render(Json(
+            response.with_request_id(header_utils::request_id(depot)),
+        ));
         ctrl.skip_rest();
     }
 }
@@ -33,7 +35,9 @@ pub fn jwt_verify_refresh(depot: &mut Depot, res: &mut Response, ctrl: &mut Flow
     let result = JwtService::verify_refresh_token(depot);
     if let Err(err) = result {
         let response: AppResponse<HashMap<String, String>> = err.into();
-        res.render(Json(response));
+        res.render(Json(
+            response.with_request_id(header_utils::request_id(depot)),
+        ));
         ctrl.skip_rest();
     }
 }
@@ -0,0 +1,54 @@
+use salvo::prelude::*;
+use std::time::Instant;
+
+use crate::service::MetricsService;
+use crate::utils::route_utils;
+
+/// HTTP 请求指标
+///
+/// 按请求方法、路由模板和状态码记录请求数和耗时。需要作为 Service 的中间件使用，
+/// 未匹配路由的请求记录为 `unmatched`。
+#[handler]
+pub async fn metrics(
+    req: &mut Request,
+    depot: &mut Depot,
+    res: &mut Response,
+    ctrl: &mut FlowCtrl,
+) {
+    let route = route_utils::route_pattern(req, res);
+    let method = req.method().to_string();
+    let started = Instant::now();
+    ctrl.call_next(req, depot, res).await;
+
+    let status = res.status_code.unwrap_or(StatusCode::OK).as_u16();
+    MetricsService::record_http(&method, &route, status, started.elapsed());
+}
+
+#[cfg(test)]
+mod tests {
+    use salvo::test::TestClient;
+
+    use super::*;
+
+    #[handler]
+    async fn hello() -> &'static str {
+        "hello"
+    }
+
+    #[tokio::test]
+    async fn test_metrics() {
+        let router = Router::with_path("metrics-test/{id}").get(hello);
+        let service = Service::new(router).hoop(metrics);
+        TestClient::get("http://127.0.0.1:5800/metrics-test/42")
+            .send(&service)
+            .await;
+        TestClient::get("http://127.0.0.1:5800/metrics-test-missing/42")
+            .send(&service)
+            .await;
+
+        let text = MetricsService::render(None).await;
+        assert!(text.contains(r#"route="/metrics-test/{id}",status="200""#));
+        assert!(text.contains(r#"route="unmatched",status="404""#));
+        assert!(!text.contains("metrics-test-missing"));
+    }
+}
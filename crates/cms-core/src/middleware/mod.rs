@@ -1,3 +1,15 @@
+mod cache_middleware;
+mod idempotency_middleware;
 mod jwt_middleware;
+mod metrics_middleware;
+mod read_your_writes_middleware;
+mod request_id_middleware;
+mod tenant_middleware;
 
+pub use cache_middleware::{CacheControl, cache_control};
+pub use idempotency_middleware::{HEADER_IDEMPOTENCY_KEY, HEADER_IDEMPOTENT_REPLAYED, idempotency};
 pub use jwt_middleware::{jwt_authorizor_init, jwt_verify_access, jwt_verify_refresh};
+pub use metrics_middleware::metrics;
+pub use read_your_writes_middleware::{HEADER_READ_YOUR_WRITES, read_your_writes};
+pub use request_id_middleware::{HEADER_REQUEST_ID, request_id};
+pub use tenant_middleware::tenant;
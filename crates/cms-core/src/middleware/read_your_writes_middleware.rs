@@ -0,0 +1,66 @@
+use salvo::http::Method;
+use salvo::prelude::*;
+
+use crate::config::AppState;
+
+/// 请求头 Read-Your-Writes，值为 true 时读取使用主库
+pub const HEADER_READ_YOUR_WRITES: &str = "Read-Your-Writes";
+
+/// 读写分离
+///
+/// 写入请求以及带有 `Read-Your-Writes: true` 的读取请求，读取也使用主库，
+/// 避免写入后立即读取时因副本延迟读到旧数据。需要在注入 AppState 之后使用。
+#[handler]
+pub async fn read_your_writes(req: &mut Request, depot: &mut Depot) {
+    let is_read = matches!(*req.method(), Method::GET | Method::HEAD | Method::OPTIONS);
+    let is_forced = req
+        .header::<String>(HEADER_READ_YOUR_WRITES)
+        .is_some_and(|value| value == "1" || value.eq_ignore_ascii_case("true"));
+    if is_read && !is_forced {
+        return;
+    }
+
+    if let Ok(state) = depot.obtain::<AppState>() {
+        let state = state.read_your_writes();
+        depot.inject(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use salvo::affix_state;
+    use salvo::test::{ResponseExt, TestClient};
+
+    use super::*;
+    use crate::fixture::config::FakerAppState;
+
+    #[handler]
+    async fn read_primary(depot: &mut Depot) -> String {
+        let state = depot.obtain::<AppState>().unwrap();
+        state.read_primary.to_string()
+    }
+
+    async fn send(method: Method, header: Option<&str>) -> String {
+        let state = FakerAppState::init().await;
+        let router = Router::new()
+            .hoop(affix_state::inject(state))
+            .hoop(read_your_writes)
+            .goal(read_primary);
+        let mut request = match method {
+            Method::POST => TestClient::post("http://127.0.0.1:5800/"),
+            _ => TestClient::get("http://127.0.0.1:5800/"),
+        };
+        if let Some(value) = header {
+            request = request.add_header(HEADER_READ_YOUR_WRITES, value, true);
+        }
+        request.send(router).await.take_string().await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_read_your_writes() {
+        assert_eq!(send(Method::GET, None).await, "false");
+        assert_eq!(send(Method::GET, Some("false")).await, "false");
+        assert_eq!(send(Method::GET, Some("true")).await, "true");
+        assert_eq!(send(Method::POST, None).await, "true");
+    }
+}
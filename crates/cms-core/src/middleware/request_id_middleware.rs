@@ -0,0 +1,134 @@
+use salvo::prelude::*;
+use std::time::Instant;
+use tracing::{Instrument, field, info, info_span};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use uuid::Uuid;
+
+use crate::utils::{get_current_editor, header_utils, route_utils, trace_utils};
+
+/// 请求头 X-Request-Id，客户端未提供时生成
+pub const HEADER_REQUEST_ID: &str = "X-Request-Id";
+
+/// 客户端提供的请求 ID 最大长度
+const REQUEST_ID_MAX_LEN: usize = 128;
+
+/// 请求 ID 和请求日志
+///
+/// 沿用请求头 `X-Request-Id` 或生成新的 ID，写入响应头和响应体。后续处理在 `request` span
+/// 中执行，span 记录请求 ID、方法、路由和编辑者，请求完成时记录状态码和耗时。需要作为
+/// Service 的中间件使用，未匹配路由的请求也有请求 ID。
+///
+/// 导出链路追踪时 span 以 `GET /core/users/{id}` 的格式命名，请求头有 W3C `traceparent`
+/// 时作为其子 span。
+#[handler]
+pub async fn request_id(
+    req: &mut Request,
+    depot: &mut Depot,
+    res: &mut Response,
+    ctrl: &mut FlowCtrl,
+) {
+    let id = req
+        .header::<String>(HEADER_REQUEST_ID)
+        .filter(|id| is_valid(id))
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+    depot.insert(header_utils::DEPOT_REQUEST_ID_KEY, id.clone());
+    let _ = res.add_header(HEADER_REQUEST_ID, &id, true);
+
+    let route = route_utils::route_pattern(req, res);
+    let span = info_span!(
+        "request",
+        otel.name = %format!("{} {}", req.method(), route),
+        otel.kind = "server",
+        otel.status_code = field::Empty,
+        http.response.status_code = field::Empty,
+        request_id = %id,
+        method = %req.method(),
+        route = %route,
+        editor_id = field::Empty,
+    );
+    // 沿用请求头 traceparent 中的链路
+    let _ = span.set_parent(trace_utils::http_context(req.headers()));
+    let started = Instant::now();
+    ctrl.call_next(req, depot, res)
+        .instrument(span.clone())
+        .await;
+
+    let editor = get_current_editor(depot);
+    if !editor.editor_id.is_nil() {
+        span.record("editor_id", field::display(editor.editor_id));
+    }
+    let status = res.status_code.unwrap_or(StatusCode::OK).as_u16();
+    span.record("http.response.status_code", status);
+    if status >= 500 {
+        span.record("otel.status_code", "error");
+    }
+    let latency_ms = started.elapsed().as_millis() as u64;
+    span.in_scope(|| info!(status, latency_ms, "Request completed"));
+}
+
+/// 只接受可打印 ASCII 字符，避免日志注入
+fn is_valid(id: &str) -> bool {
+    !id.is_empty() && id.len() <= REQUEST_ID_MAX_LEN && id.bytes().all(|b| b.is_ascii_graphic())
+}
+
+#[cfg(test)]
+mod tests {
+    use salvo::test::{ResponseExt, TestClient};
+
+    use super::*;
+    use crate::domain::{AppResult, result_ok};
+    use crate::error::AppError;
+
+    #[handler]
+    async fn success() -> AppResult<bool> {
+        result_ok(true)
+    }
+
+    #[handler]
+    async fn failure() -> AppResult<bool> {
+        Err(AppError::BadRequest("bad".to_string()))
+    }
+
+    fn service() -> Service {
+        let router = Router::new()
+            .push(Router::with_path("success").get(success))
+            .push(Router::with_path("failure").get(failure));
+        Service::new(router).hoop(request_id)
+    }
+
+    #[tokio::test]
+    async fn test_request_id_generated() {
+        let mut response = TestClient::get("http://127.0.0.1:5800/success")
+            .send(&service())
+            .await;
+        let id = response.headers().get(HEADER_REQUEST_ID).unwrap();
+        let id = id.to_str().unwrap().to_string();
+        assert!(Uuid::parse_str(&id).is_ok());
+
+        let json: serde_json::Value = response.take_json().await.unwrap();
+        assert_eq!(json["request_id"], id);
+    }
+
+    #[tokio::test]
+    async fn test_request_id_propagated() {
+        let mut response = TestClient::get("http://127.0.0.1:5800/failure")
+            .add_header(HEADER_REQUEST_ID, "abc-123", true)
+            .send(&service())
+            .await;
+        assert_eq!(
+            response.headers().get(HEADER_REQUEST_ID).unwrap(),
+            "abc-123"
+        );
+        let json: serde_json::Value = response.take_json().await.unwrap();
+        assert_eq!(json["code"], 400);
+        assert_eq!(json["request_id"], "abc-123");
+
+        // 不可打印字符重新生成
+        let response = TestClient::get("http://127.0.0.1:5800/missing")
+            .add_header(HEADER_REQUEST_ID, "a b", true)
+            .send(&service())
+            .await;
+        let id = response.headers().get(HEADER_REQUEST_ID).unwrap();
+        assert!(Uuid::parse_str(id.to_str().unwrap()).is_ok());
+    }
+}
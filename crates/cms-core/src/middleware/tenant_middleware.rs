@@ -0,0 +1,118 @@
+use salvo::http::header::HOST;
+use salvo::jwt_auth::{JwtAuthDepotExt, JwtAuthState};
+use salvo::prelude::*;
+use std::collections::HashMap;
+
+use crate::config::AppState;
+use crate::domain::dto::JwtClaimsDTO;
+use crate::domain::response::AppResponse;
+use crate::error::AppError;
+use crate::service::{HEADER_TENANT_ID, TenantService};
+use crate::utils::header_utils;
+
+/// 多租户
+///
+/// 根据 Token 中的租户或请求的域名确定租户，后续处理中的查询自动限制为该租户，新建的记录
+/// 属于该租户。需要在 JWT 认证和注入 AppState 之后使用，没有 AppState 时返回错误，不再继续处理。
+#[handler]
+pub async fn tenant(req: &mut Request, depot: &mut Depot, res: &mut Response, ctrl: &mut FlowCtrl) {
+    let state = depot.obtain::<AppState>().ok().cloned();
+    let claims = match depot.jwt_auth_state() {
+        JwtAuthState::Authorized => depot
+            .jwt_auth_data::<JwtClaimsDTO>()
+            .map(|data| data.claims.clone()),
+        _ => None,
+    };
+    let header = req.header::<String>(HEADER_TENANT_ID);
+    let host = req
+        .uri()
+        .host()
+        .map(str::to_owned)
+        .or_else(|| req.header::<String>(HOST));
+
+    let result = match state {
+        Some(state) => {
+            TenantService::resolve(claims.as_ref(), header.as_deref(), host.as_deref(), &state)
+                .await
+        }
+        None => Err(AppError::Internal),
+    };
+    match result {
+        Ok(current) => {
+            TenantService::scope(current, ctrl.call_next(req, depot, res)).await;
+        }
+        Err(err) => {
+            let response: AppResponse<HashMap<String, String>> = err.into();
+            res.render(Json(
+                response.with_request_id(header_utils::request_id(depot)),
+            ));
+            ctrl.skip_rest();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use salvo::affix_state;
+    use salvo::test::{ResponseExt, TestClient};
+    use sea_orm::*;
+
+    use super::*;
+    use crate::domain::entity::tenant::ActiveModel as TenantActiveModel;
+    use crate::fixture::config::FakerAppState;
+    use crate::utils::time_utils;
+
+    #[handler]
+    async fn current_tenant() -> String {
+        TenantService::current_tenant_id().to_string()
+    }
+
+    #[tokio::test]
+    async fn test_tenant() {
+        let state = FakerAppState::init().await;
+        let now = time_utils::current_time();
+        let model = TenantActiveModel {
+            name: Set("site".to_owned()),
+            host: Set("site.example.com".to_owned()),
+            is_enabled: Set(true),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        }
+        .insert(&state.db)
+        .await
+        .unwrap();
+
+        let router = Router::new()
+            .hoop(affix_state::inject(state))
+            .hoop(tenant)
+            .goal(current_tenant);
+        let service = Service::new(router);
+
+        let body = TestClient::get("http://site.example.com:5800/")
+            .send(&service)
+            .await
+            .take_string()
+            .await
+            .unwrap();
+        assert_eq!(body, model.id.to_string());
+
+        let body = TestClient::get("http://127.0.0.1:5800/")
+            .send(&service)
+            .await
+            .take_string()
+            .await
+            .unwrap();
+        assert_eq!(body, "0");
+
+        // 没有注入 AppState 时不继续处理
+        let service = Service::new(Router::new().hoop(tenant).goal(current_tenant));
+        let json: serde_json::Value = TestClient::get("http://127.0.0.1:5800/")
+            .send(&service)
+            .await
+            .take_json()
+            .await
+            .unwrap();
+        assert_eq!(json.get("code"), Some(&serde_json::json!(500)));
+    }
+}
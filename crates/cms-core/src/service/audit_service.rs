@@ -0,0 +1,112 @@
+use sea_orm::*;
+
+use crate::config::AppState;
+use crate::domain::{
+    HandleResult,
+    dto::{AuditQueryDTO, AuditStoreDTO},
+    entity::audit::{
+        ActiveModel as AuditActiveModel, Column as AuditColumn, Entity as AuditEntity,
+        Model as AuditModel,
+    },
+    handle_ok,
+    vo::{AuditVO, PaginateResultVO},
+};
+use crate::enums::ErrorEnum;
+use crate::error::AppError;
+use crate::utils::{parameter_utils, time_utils};
+
+pub struct AuditService {}
+
+impl AuditService {
+    /// 写入审计记录
+    pub async fn store<C: ConnectionTrait>(
+        dto: &AuditStoreDTO,
+        db: &C,
+    ) -> HandleResult<AuditModel> {
+        let model = AuditActiveModel {
+            entity_type: Set(dto.entity_type.to_owned()),
+            entity_id: Set(dto.entity_id),
+            action: Set(dto.action.as_value().to_string()),
+            editor_type: Set(dto.editor.editor_type.string_value()),
+            editor_id: Set(dto.editor.editor_id),
+            version_no: Set(dto.version_no),
+            changes: Set(dto.changes.clone()),
+            created_at: Set(time_utils::current_time()),
+            ..Default::default()
+        };
+        let model = model.insert(db).await?;
+
+        handle_ok(model)
+    }
+
+    /// 分页查询记录的变更历史
+    pub async fn paginage(
+        dto: &AuditQueryDTO,
+        state: &AppState,
+    ) -> HandleResult<PaginateResultVO<AuditVO>> {
+        let db = state.db_read();
+        let page = parameter_utils::page_no_set(Some(dto.page));
+        let page_size = parameter_utils::page_size_set(Some(dto.page_size));
+
+        let query = Self::scope_entity_query(&dto.entity_type, dto.entity_id)
+            .order_by_desc(AuditColumn::Id);
+        let paginator = query.paginate(db, page_size);
+        let total = paginator.num_items().await?;
+        let models = paginator.fetch_page(page - 1).await?;
+        let list: Vec<AuditVO> = models.iter().map(|model| model.into()).collect();
+
+        let vo = PaginateResultVO {
+            page_size,
+            current_page: page,
+            total: Some(total),
+            next_cursor: None,
+            list,
+        };
+
+        handle_ok(vo)
+    }
+
+    /// 查询记录的某条变更历史
+    pub async fn fetch_by_id(
+        entity_type: &str,
+        entity_id: i64,
+        id: i64,
+        state: &AppState,
+    ) -> HandleResult<AuditModel> {
+        if id < 1 {
+            return Err(ErrorEnum::ParamIdInvalid.into());
+        }
+
+        let db = &state.db;
+        let model = Self::scope_entity_query(entity_type, entity_id)
+            .filter(AuditColumn::Id.eq(id))
+            .one(db)
+            .await?
+            .ok_or_else(|| Into::<AppError>::into(ErrorEnum::RecordNotFound))?;
+
+        handle_ok(model)
+    }
+
+    /// 查询某条变更之后的全部历史，按时间倒序
+    pub async fn list_after(
+        entity_type: &str,
+        entity_id: i64,
+        id: i64,
+        state: &AppState,
+    ) -> HandleResult<Vec<AuditModel>> {
+        let db = &state.db;
+        let models = Self::scope_entity_query(entity_type, entity_id)
+            .filter(AuditColumn::Id.gt(id))
+            .order_by_desc(AuditColumn::Id)
+            .all(db)
+            .await?;
+
+        handle_ok(models)
+    }
+
+    fn scope_entity_query(entity_type: &str, entity_id: i64) -> Select<AuditEntity> {
+        AuditEntity::find()
+            .filter(AuditColumn::EntityType.eq(entity_type))
+            .filter(AuditColumn::EntityId.eq(entity_id))
+    }
+}
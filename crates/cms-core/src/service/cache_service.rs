@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+use tracing::error;
+
+use crate::utils::time_utils;
+
+use super::CacheStore;
+
+pub struct CacheService {}
+
+/// 选项数据缓存版本的键
+const CACHE_VERSION_KEY: &str = "cache_version:options";
+
+/// JWT 键的值，与 Redis 中布尔值 true 的存储格式一致
+const JWT_KEY_VALUE: &str = "1";
+
+impl CacheService {
+    pub fn set_jwt_key(cache: &dyn CacheStore, jwt_id: &str, expired_time: i64) {
+        let current_timestamp = time_utils::current_timestamp();
+        let mut seconds: i64 = expired_time - current_timestamp;
+        if seconds < 0 {
+            seconds = 1;
+        }
+        let key = Self::to_jwt_key(jwt_id);
+        if let Err(err) = cache.set(&key, JWT_KEY_VALUE, Some(seconds as u64)) {
+            error!("Failed to set jwt key: {}", err);
+        }
+    }
+
+    pub fn has_jwt_key(cache: &dyn CacheStore, jwt_id: &str) -> bool {
+        let value = cache.get(&Self::to_jwt_key(jwt_id)).ok().flatten();
+        value.is_some_and(|value| value == JWT_KEY_VALUE)
+    }
+
+    pub fn del_jwt_key(cache: &dyn CacheStore, jwt_id: &str) {
+        if let Err(err) = cache.del(&Self::to_jwt_key(jwt_id)) {
+            error!("Failed to delete jwt key: {}", err);
+        }
+    }
+
+    fn to_jwt_key(id: &str) -> String {
+        format!("jwt:{}", id)
+    }
+
+    /// 选项数据的缓存版本，缓存不可用时返回空
+    pub fn get_cache_version(cache: &dyn CacheStore) -> Option<i64> {
+        let version = cache.get(CACHE_VERSION_KEY).ok()?;
+        let version = match version {
+            Some(value) => value.parse::<i64>().ok()?,
+            None => 0,
+        };
+        Some(version)
+    }
+
+    /// 资源变更后递增缓存版本，缓存不可用时忽略
+    pub fn touch_cache_version(cache: &dyn CacheStore) {
+        let _ = cache.incr(CACHE_VERSION_KEY, 1);
+    }
+
+    pub async fn set_json_list<T: Serialize + Send + Sync>(
+        cache: &dyn CacheStore,
+        key: &str,
+        list: &Vec<T>,
+    ) {
+        let result = serde_json::to_value(list)
+            .map_err(Into::into)
+            .and_then(|value| cache.set_json(key, &value));
+        if let Err(err) = result {
+            error!("Failed to set json list: {}", err);
+        }
+    }
+
+    pub async fn get_json_list<T: for<'a> Deserialize<'a>>(
+        cache: &dyn CacheStore,
+        key: &str,
+    ) -> Vec<T> {
+        cache
+            .get_json(key)
+            .ok()
+            .flatten()
+            .and_then(|value| serde_json::from_value(value).ok())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::service::MemoryCacheStore;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct TestItem {
+        id: i32,
+        name: String,
+    }
+
+    // 测试 JWT key 设置
+    #[test]
+    fn test_set_has_del_jwt_key() {
+        let cache = MemoryCacheStore::new();
+        let jwt_uuid = Uuid::new_v4();
+        let jwt_uuid = jwt_uuid.to_string();
+        let jwt_id = jwt_uuid.as_str();
+
+        // 当前时间 +5 秒过期
+        let current = time_utils::current_timestamp();
+        CacheService::set_jwt_key(&cache, jwt_id, current + 5);
+
+        assert!(CacheService::has_jwt_key(&cache, jwt_id));
+
+        CacheService::del_jwt_key(&cache, jwt_id);
+        assert!(!CacheService::has_jwt_key(&cache, jwt_id));
+    }
+
+    // 测试过期时间小于当前时间的情况
+    #[test]
+    fn test_set_jwt_key_expired() {
+        let cache = MemoryCacheStore::new();
+        let jwt_uuid = Uuid::new_v4();
+        let jwt_uuid = jwt_uuid.to_string();
+        let jwt_id = jwt_uuid.as_str();
+
+        let past_time = time_utils::current_timestamp() - 10; // 已过期
+        CacheService::set_jwt_key(&cache, jwt_id, past_time);
+
+        // 应该设置为 1 秒有效期
+        assert!(CacheService::has_jwt_key(&cache, jwt_id));
+    }
+
+    // 测试缓存版本
+    #[test]
+    fn test_cache_version() {
+        let cache = MemoryCacheStore::new();
+        assert_eq!(CacheService::get_cache_version(&cache), Some(0));
+
+        CacheService::touch_cache_version(&cache);
+        CacheService::touch_cache_version(&cache);
+        assert_eq!(CacheService::get_cache_version(&cache), Some(2));
+    }
+
+    // 测试 JSON 列表的写入和读取
+    #[tokio::test]
+    async fn test_set_get_json_list() {
+        let cache = MemoryCacheStore::new();
+        let key = "json_list_key";
+
+        let items = vec![
+            TestItem {
+                id: 1,
+                name: "Alice".to_string(),
+            },
+            TestItem {
+                id: 2,
+                name: "Bob".to_string(),
+            },
+        ];
+
+        CacheService::set_json_list(&cache, key, &items).await;
+
+        let retrieved: Vec<TestItem> = CacheService::get_json_list(&cache, key).await;
+        assert_eq!(retrieved, items);
+
+        let missing: Vec<TestItem> = CacheService::get_json_list(&cache, "missing").await;
+        assert!(missing.is_empty());
+    }
+}
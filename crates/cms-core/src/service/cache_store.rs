@@ -0,0 +1,33 @@
+use serde_json::Value as JsonValue;
+use std::fmt::Debug;
+
+use crate::domain::HandleResult;
+
+/// 键值缓存
+///
+/// 生产环境使用 Redis，测试使用内存实现。ex 为过期时间（秒），为空时不过期。
+pub trait CacheStore: Debug + Send + Sync {
+    /// 写入字符串
+    fn set(&self, key: &str, value: &str, ex: Option<u64>) -> HandleResult<()>;
+
+    /// 键不存在时写入，返回是否写入成功
+    fn set_nx(&self, key: &str, value: &str, ex: Option<u64>) -> HandleResult<bool>;
+
+    /// 读取字符串
+    fn get(&self, key: &str) -> HandleResult<Option<String>>;
+
+    /// 删除键
+    fn del(&self, key: &str) -> HandleResult<()>;
+
+    /// 整数自增，返回自增后的值
+    fn incr(&self, key: &str, delta: i64) -> HandleResult<i64>;
+
+    /// 写入 JSON
+    fn set_json(&self, key: &str, value: &JsonValue) -> HandleResult<()>;
+
+    /// 读取 JSON
+    fn get_json(&self, key: &str) -> HandleResult<Option<JsonValue>>;
+
+    /// 检查缓存服务是否可用
+    fn ping(&self) -> HandleResult<()>;
+}
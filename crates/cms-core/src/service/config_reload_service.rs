@@ -0,0 +1,289 @@
+use serde_json::Value as JsonValue;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::{Duration, SystemTime};
+use tracing::level_filters::LevelFilter;
+use tracing::{Level, error, info, warn};
+use tracing_subscriber::filter::Targets;
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::{Registry, fmt, reload};
+
+use crate::config::{CmsConfig, SharedConfig, WebConfig};
+use crate::domain::dto::ConfigChangeDTO;
+
+use super::TelemetryService;
+
+/// 可热加载的配置项，其他配置项修改后需要重启
+pub const RELOADABLE_KEYS: [&str; 4] = [
+    "web.log_level",
+    "web.cors_origins",
+    "jwt.access_expire_days",
+    "jwt.refresh_expire_days",
+];
+
+/// 检查配置文件是否修改的间隔
+const WATCH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// 日志级别，热加载时修改
+static LOG_LEVEL: OnceLock<reload::Handle<LevelFilter, Registry>> = OnceLock::new();
+
+pub struct ConfigReloadService {}
+
+impl ConfigReloadService {
+    /// 初始化日志，日志级别可以热加载
+    ///
+    /// `web.log_format` 为 json 时每行输出一个 JSON 对象，包含当前 span 的字段，如请求 ID。
+    /// 配置 `web.otlp_endpoint` 时 span 同时通过 OTLP 导出，服务名为 `service_name`。
+    pub fn init_tracing(config: &WebConfig, service_name: &str) {
+        let level = LevelFilter::from_level(config.tracing_level());
+        let (filter, handle) = reload::Layer::new(level);
+        let json = config.is_json_log();
+        let telemetry = TelemetryService::init(config, service_name);
+        let tracer = telemetry.as_ref().ok().cloned().flatten();
+        tracing_subscriber::registry()
+            .with(filter)
+            .with(json.then(|| {
+                fmt::layer()
+                    .json()
+                    .flatten_event(true)
+                    .with_span_list(false)
+            }))
+            .with((!json).then(fmt::layer))
+            .with(tracer.map(|tracer| {
+                // 导出使用的 HTTP 客户端的日志不作为 span 事件导出
+                let targets = Targets::new()
+                    .with_default(LevelFilter::TRACE)
+                    .with_target("opentelemetry", LevelFilter::OFF)
+                    .with_target("opentelemetry_sdk", LevelFilter::OFF)
+                    .with_target("opentelemetry_otlp", LevelFilter::OFF)
+                    .with_target("reqwest", LevelFilter::OFF)
+                    .with_target("hyper_util", LevelFilter::OFF);
+                tracing_opentelemetry::layer()
+                    .with_tracer(tracer)
+                    .with_filter(targets)
+            }))
+            .init();
+        let _ = LOG_LEVEL.set(handle);
+
+        match telemetry {
+            Ok(Some(_)) => info!(
+                "Exporting traces to {}",
+                config.otlp_traces_endpoint().unwrap_or_default()
+            ),
+            Ok(None) => {}
+            Err(err) => error!("Failed to create OTLP exporter: {}", err),
+        }
+    }
+
+    /// 监听配置文件修改和 SIGHUP 信号，重新加载配置
+    pub fn watch(config: SharedConfig) {
+        let file = CmsConfig::config_file(None);
+        #[cfg(unix)]
+        tokio::spawn(Self::watch_hangup(config.clone(), file.clone()));
+        if let Some(file) = file {
+            tokio::spawn(Self::watch_file(config, file));
+        }
+    }
+
+    #[cfg(unix)]
+    async fn watch_hangup(config: SharedConfig, file: Option<PathBuf>) {
+        use tokio::signal::unix::{SignalKind, signal};
+
+        let mut hangup = match signal(SignalKind::hangup()) {
+            Ok(hangup) => hangup,
+            Err(err) => {
+                error!("Failed to listen for SIGHUP: {}", err);
+                return;
+            }
+        };
+        while hangup.recv().await.is_some() {
+            info!("Received SIGHUP, reloading config");
+            Self::reload_and_log(&config, file.as_deref());
+        }
+    }
+
+    async fn watch_file(config: SharedConfig, file: PathBuf) {
+        let mut modified = modified_time(&file);
+        let mut interval = tokio::time::interval(WATCH_INTERVAL);
+        loop {
+            interval.tick().await;
+            let latest = modified_time(&file);
+            if latest != modified {
+                modified = latest;
+                info!("Config file {} changed, reloading config", file.display());
+                Self::reload_and_log(&config, Some(&file));
+            }
+        }
+    }
+
+    fn reload_and_log(config: &SharedConfig, file: Option<&Path>) {
+        match Self::reload(config, file) {
+            Ok(changes) if changes.is_empty() => info!("Config reloaded, nothing changed"),
+            Ok(changes) => {
+                for change in changes.reloaded {
+                    info!("Config reloaded: {}", change);
+                }
+                for key in changes.restart_required {
+                    warn!("Config {} changed, restart required to apply", key);
+                }
+            }
+            Err(errors) => {
+                for err in errors {
+                    error!("Config reload failed: {}", err);
+                }
+            }
+        }
+    }
+
+    /// 重新加载配置，只替换可热加载的配置项
+    ///
+    /// 新配置校验失败时不做任何修改，返回全部错误。
+    pub fn reload(
+        config: &SharedConfig,
+        file: Option<&Path>,
+    ) -> Result<ConfigChangeDTO, Vec<String>> {
+        let loaded = CmsConfig::load(file)?;
+        let current = config.current();
+        let changes = Self::diff(&current, &loaded);
+        if changes.reloaded.is_empty() {
+            return Ok(changes);
+        }
+
+        let mut next = current.as_ref().clone();
+        next.web.reload_from(&loaded.web);
+        next.jwt.reload_from(&loaded.jwt);
+        Self::set_log_level(next.web.tracing_level());
+        config.replace(next);
+        Ok(changes)
+    }
+
+    /// 比较两个配置，区分可热加载和需要重启的配置项
+    ///
+    /// 需要重启的配置项可能包含密码，只返回配置项名称。
+    pub fn diff(current: &CmsConfig, loaded: &CmsConfig) -> ConfigChangeDTO {
+        let current = flatten(current);
+        let mut loaded = flatten(loaded);
+        let mut changes = ConfigChangeDTO::default();
+        for (key, value) in current {
+            let next = loaded.remove(&key).unwrap_or(JsonValue::Null);
+            if next == value {
+                continue;
+            }
+            if RELOADABLE_KEYS.contains(&key.as_str()) {
+                changes
+                    .reloaded
+                    .push(format!("{}: {} -> {}", key, value, next));
+            } else {
+                changes.restart_required.push(key);
+            }
+        }
+        changes.restart_required.extend(
+            loaded
+                .into_iter()
+                .filter(|(_, value)| !value.is_null())
+                .map(|(key, _)| key),
+        );
+        changes
+    }
+
+    fn set_log_level(level: Level) {
+        let Some(handle) = LOG_LEVEL.get() else {
+            return;
+        };
+        if let Err(err) = handle.modify(|filter| *filter = LevelFilter::from_level(level)) {
+            error!("Failed to change log level: {}", err);
+        }
+    }
+}
+
+/// 转换为 `web.port` 格式的配置项
+fn flatten(config: &CmsConfig) -> BTreeMap<String, JsonValue> {
+    let mut values = BTreeMap::new();
+    if let Ok(JsonValue::Object(sections)) = serde_json::to_value(config) {
+        for (section, items) in sections {
+            if let JsonValue::Object(items) = items {
+                for (key, value) in items {
+                    values.insert(format!("{}.{}", section, key), value);
+                }
+            }
+        }
+    }
+    values
+}
+
+fn modified_time(file: &Path) -> Option<SystemTime> {
+    std::fs::metadata(file)
+        .and_then(|meta| meta.modified())
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+
+    use super::*;
+
+    /// 写入配置文件，`web` 和 `jwt` 分组中追加指定的配置项
+    fn write_config(path: &Path, web: &str, jwt: &str) {
+        let content = format!(
+            "[web]\nhost = \"0.0.0.0\"\nport = 5800\n{}\n\
+             [db]\nprotocol = \"postgres\"\nname = \"cms\"\n\
+             [jwt]\n{}\n",
+            web, jwt
+        );
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_diff() {
+        let current = CmsConfig::default();
+        let mut loaded = CmsConfig::default();
+        loaded.db.name = "salvo_cms".to_string();
+        loaded.db.password = "secret".to_string();
+        assert!(ConfigReloadService::diff(&current, &current).is_empty());
+
+        let changes = ConfigReloadService::diff(&current, &loaded);
+        assert!(changes.reloaded.is_empty());
+        assert_eq!(changes.restart_required, vec!["db.name", "db.password"]);
+    }
+
+    #[tokio::test]
+    async fn test_reload() {
+        let path = env::temp_dir().join(format!("cms-reload-{}.toml", uuid::Uuid::new_v4()));
+        write_config(&path, "log_level = \"warn\"", "access_expire_days = 3");
+        let current = CmsConfig::load(Some(&path)).unwrap();
+        let shared = SharedConfig::new(current.clone());
+
+        write_config(
+            &path,
+            "log_level = \"debug\"\ncors_origins = [\"https://a.example.com\"]",
+            "access_expire_days = 1\nsecret_key = \"changed\"",
+        );
+        let changes = ConfigReloadService::reload(&shared, Some(&path)).unwrap();
+        assert_eq!(
+            changes.reloaded,
+            vec![
+                "jwt.access_expire_days: 3 -> 1",
+                r#"web.cors_origins: [] -> ["https://a.example.com"]"#,
+                r#"web.log_level: "warn" -> "debug""#,
+            ]
+        );
+        assert_eq!(changes.restart_required, vec!["jwt.secret_key"]);
+
+        let reloaded = shared.current();
+        assert_eq!(reloaded.web.tracing_level(), Level::DEBUG);
+        assert!(!reloaded.web.is_origin_allowed("https://b.example.com"));
+        assert_eq!(reloaded.jwt.get_access_expire_days(), 1);
+        // 签名密钥需要重启，不会替换
+        assert_eq!(reloaded.jwt.secret_bytes(), current.jwt.secret_bytes());
+
+        // 配置有误时保留原配置
+        write_config(&path, "", "access_expire_days = 90");
+        let errors = ConfigReloadService::reload(&shared, Some(&path)).unwrap_err();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(shared.current().jwt.get_access_expire_days(), 1);
+    }
+}
@@ -0,0 +1,80 @@
+use sea_orm::{ConnectionTrait, DatabaseBackend, DatabaseConnection, Statement};
+
+use crate::config::AppState;
+use crate::domain::vo::ReplicaStatusVO;
+
+/// PostgreSQL 副本的复制延迟，已回放全部 WAL 时为 0，主库也为 0
+const POSTGRES_LAG_SQL: &str = "SELECT (CASE \
+    WHEN NOT pg_is_in_recovery() THEN 0 \
+    WHEN pg_last_wal_receive_lsn() = pg_last_wal_replay_lsn() THEN 0 \
+    ELSE EXTRACT(EPOCH FROM now() - pg_last_xact_replay_timestamp()) \
+    END)::float8 AS lag";
+
+pub struct DatabaseService {}
+
+impl DatabaseService {
+    /// 全部只读副本的状态
+    pub async fn replica_status(state: &AppState) -> Vec<ReplicaStatusVO> {
+        let mut list = Vec::with_capacity(state.db_replicas.len());
+        for (index, db) in state.db_replicas.iter().enumerate() {
+            let healthy = db.ping().await.is_ok();
+            let lag_seconds = match healthy {
+                true => Self::replica_lag(db).await,
+                false => None,
+            };
+            list.push(ReplicaStatusVO {
+                index,
+                healthy,
+                lag_seconds,
+            });
+        }
+        list
+    }
+
+    /// 副本的复制延迟（秒），不支持的数据库返回空
+    async fn replica_lag(db: &DatabaseConnection) -> Option<f64> {
+        match db.get_database_backend() {
+            DatabaseBackend::Postgres => {
+                let stmt = Statement::from_string(DatabaseBackend::Postgres, POSTGRES_LAG_SQL);
+                let row = db.query_one(stmt).await.ok()??;
+                row.try_get::<Option<f64>>("", "lag").ok()?
+            }
+            DatabaseBackend::MySql => {
+                let stmt = Statement::from_string(DatabaseBackend::MySql, "SHOW REPLICA STATUS");
+                // 不是副本时没有记录
+                let Some(row) = db.query_one(stmt).await.ok()? else {
+                    return Some(0.0);
+                };
+                let seconds = row
+                    .try_get::<Option<u64>>("", "Seconds_Behind_Source")
+                    .ok()??;
+                Some(seconds as f64)
+            }
+            DatabaseBackend::Sqlite => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixture::config::FakerAppState;
+
+    #[tokio::test]
+    async fn test_replica_status() {
+        let mut state = FakerAppState::init().await;
+        assert!(DatabaseService::replica_status(&state).await.is_empty());
+
+        let replica = FakerAppState::init().await;
+        state.db_replicas = vec![replica.db.clone()].into();
+        let list = DatabaseService::replica_status(&state).await;
+        assert_eq!(
+            list,
+            vec![ReplicaStatusVO {
+                index: 0,
+                healthy: true,
+                lag_seconds: None,
+            }]
+        );
+    }
+}
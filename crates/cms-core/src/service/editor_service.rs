@@ -29,7 +29,7 @@ impl EditorService {
         if uuid.is_nil() {
             return handle_ok(None);
         }
-        let db = &state.db;
+        let db = state.db_read();
         let opt = EditorEntity::find_by_id(*uuid).one(db).await?;
         if let Some(editor) = opt {
             handle_ok(Some(editor.into()))
@@ -66,7 +66,7 @@ impl EditorService {
             return handle_ok(HashMap::<String, EditorLoadVO>::new());
         }
 
-        let db = &state.db;
+        let db = state.db_read();
         let models = EditorEntity::find()
             .filter(EditorColumn::Id.is_in(filted_ids))
             .all(db)
@@ -85,7 +85,6 @@ impl EditorService {
 mod tests {
     use super::*;
 
-    use crate::domain::entity::editor::Model as EditorModel;
     use crate::fixture::{config::FakerAppState, model::editors};
 
     #[tokio::test]
@@ -93,21 +92,14 @@ mod tests {
         let editor_system = editors::faker_model_by_name(editors::EDITOR_NAME_SYSTEM);
         let editor_admin = editors::faker_model_by_name(editors::EDITOR_NAME_ADMIN);
 
-        let mut state = FakerAppState::init().await;
-        state.db = MockDatabase::new(DatabaseBackend::Postgres)
-            .append_query_results([
-                // nil uuid not query
-                Vec::<EditorModel>::new(),
-                vec![editor_admin.clone()],
-            ])
-            .append_query_results([vec![editor_system.clone()]])
-            .into_connection();
+        let state = FakerAppState::init().await;
+        editors::faker_insert_by_names(&state.db, &[editors::EDITOR_NAME_ADMIN]).await;
 
         let uuid = Uuid::nil().to_string();
         let result = EditorService::load_by_uuid_str(uuid.as_str(), &state).await;
         assert!(result.unwrap().is_none());
 
-        let uuid = editor_system.id.clone().to_string();
+        let uuid = editor_system.id.to_string();
         let result = EditorService::load_by_uuid_str(uuid.as_str(), &state).await;
         assert!(result.unwrap().is_none());
 
@@ -115,7 +107,7 @@ mod tests {
         let result = EditorService::load_by_uuid_str(uuid.as_str(), &state).await;
         assert!(result.unwrap().is_none());
 
-        let admin_uuid = editor_admin.id.clone().to_string();
+        let admin_uuid = editor_admin.id.to_string();
         let editor_vo: EditorLoadVO = editor_admin.into();
         let result = EditorService::load_by_uuid_str(admin_uuid.as_str(), &state).await;
         assert_eq!(result.unwrap().unwrap(), editor_vo);
@@ -126,17 +118,8 @@ mod tests {
         let editor_system = editors::faker_model_by_name(editors::EDITOR_NAME_SYSTEM);
         let editor_admin = editors::faker_model_by_name(editors::EDITOR_NAME_ADMIN);
 
-        let mut state = FakerAppState::init().await;
-        let mut exec_sqls = Vec::<Transaction>::with_capacity(2);
-        let sql_text = r#"SELECT "users"."id", "users"."no", "users"."name", "users"."phone", "users"."avatar_path", "users"."email" FROM "users" WHERE "users"."id" = $1 LIMIT $2"#;
-        state.db = MockDatabase::new(DatabaseBackend::Postgres)
-            .append_query_results([
-                // nil uuid not query
-                Vec::<EditorModel>::new(),
-                vec![editor_admin.clone()],
-            ])
-            .append_query_results([vec![editor_system.clone()]])
-            .into_connection();
+        let state = FakerAppState::init().await;
+        editors::faker_insert_by_names(&state.db, &[editors::EDITOR_NAME_ADMIN]).await;
 
         let uuid = Uuid::nil();
         let result = EditorService::load_by_uuid(&uuid, &state).await;
@@ -147,45 +130,26 @@ mod tests {
 
         let uuid = Uuid::new_v4();
         let result = EditorService::load_by_uuid(&uuid, &state).await;
-        let sql = Transaction::from_sql_and_values(
-            DatabaseBackend::Postgres,
-            sql_text,
-            [uuid.into(), 1u64.into()],
-        );
-        exec_sqls.push(sql);
         assert!(result.unwrap().is_none());
 
-        let admin_uuid = editor_admin.id.clone();
+        let admin_uuid = editor_admin.id;
         let editor_vo: EditorLoadVO = editor_admin.into();
         let result = EditorService::load_by_uuid(&admin_uuid, &state).await;
-        let sql = Transaction::from_sql_and_values(
-            DatabaseBackend::Postgres,
-            sql_text,
-            [admin_uuid.into(), 1u64.into()],
-        );
-        exec_sqls.push(sql);
         assert_eq!(result.unwrap().unwrap(), editor_vo);
-
-        // check transaction log
-        let logs = state.db.into_transaction_log();
-        assert_eq!(logs.len(), 2);
-        assert_eq!(logs[0], exec_sqls[0]);
-        assert_eq!(logs[1], exec_sqls[1]);
     }
 
     #[tokio::test]
     async fn test_batch_load_by_uuids_str() {
-        let editor_system = editors::faker_model_by_name(editors::EDITOR_NAME_SYSTEM);
-        let editor_admin = editors::faker_model_by_name(editors::EDITOR_NAME_ADMIN);
         let editor_guest = editors::faker_model_by_name(editors::EDITOR_NAME_GUEST);
 
-        let mut state = FakerAppState::init().await;
-        state.db = MockDatabase::new(DatabaseBackend::Postgres)
-            .append_query_results([
-                // nil uuid not query
-                vec![editor_admin.clone(), editor_system.clone()],
-            ])
-            .into_connection();
+        let state = FakerAppState::init().await;
+        let models = editors::faker_insert_by_names(
+            &state.db,
+            &[editors::EDITOR_NAME_SYSTEM, editors::EDITOR_NAME_ADMIN],
+        )
+        .await;
+        let editor_system = models[0].clone();
+        let editor_admin = models[1].clone();
 
         let uuids = Vec::<&str>::new();
         let result = EditorService::batch_load_by_uuids_str(&uuids, &state).await;
@@ -193,9 +157,9 @@ mod tests {
 
         let uuids: Vec<String> = vec![
             Uuid::nil(),
-            editor_system.id.clone(),
-            editor_admin.id.clone(),
-            editor_guest.id.clone(),
+            editor_system.id,
+            editor_admin.id,
+            editor_guest.id,
             Uuid::new_v4(),
         ]
         .iter()
@@ -205,64 +169,50 @@ mod tests {
         let result = EditorService::batch_load_by_uuids_str(&uuids, &state).await;
         assert!(result.is_ok());
         let map = result.unwrap();
-        assert_eq!(map.len(), 2);
+        // 空 UUID 不查询，系统用户的 ID 为空 UUID
+        assert_eq!(map.len(), 1);
         assert_eq!(
             map.get(&editor_admin.id.to_string()).unwrap(),
             &editor_admin.into()
         );
-        assert_eq!(
-            map.get(&editor_system.id.to_string()).unwrap(),
-            &editor_system.into()
-        );
+        assert!(!map.contains_key(&editor_system.id.to_string()));
     }
 
     #[tokio::test]
     async fn test_batch_load_by_uuids() {
-        let editor_system = editors::faker_model_by_name(editors::EDITOR_NAME_SYSTEM);
-        let editor_admin = editors::faker_model_by_name(editors::EDITOR_NAME_ADMIN);
         let editor_guest = editors::faker_model_by_name(editors::EDITOR_NAME_GUEST);
 
-        let mut state = FakerAppState::init().await;
-        state.db = MockDatabase::new(DatabaseBackend::Postgres)
-            .append_query_results([
-                // nil uuid not query
-                vec![editor_admin.clone(), editor_system.clone()],
-            ])
-            .into_connection();
+        let state = FakerAppState::init().await;
+        let models = editors::faker_insert_by_names(
+            &state.db,
+            &[editors::EDITOR_NAME_SYSTEM, editors::EDITOR_NAME_ADMIN],
+        )
+        .await;
+        let editor_system = models[0].clone();
+        let editor_admin = models[1].clone();
 
         let uuids = Vec::<Uuid>::new();
         let result = EditorService::batch_load_by_uuids(&uuids, &state).await;
         assert!(result.unwrap().is_empty());
 
         let empty_uuid = Uuid::new_v4();
-        let admin_uuid = editor_admin.id.clone();
-        let guest_uuid = editor_guest.id.clone();
+        let admin_uuid = editor_admin.id;
+        let guest_uuid = editor_guest.id;
         let uuids = vec![
             Uuid::nil(),
-            editor_system.id.clone(),
-            admin_uuid.clone(),
-            guest_uuid.clone(),
-            empty_uuid.clone(),
+            editor_system.id,
+            admin_uuid,
+            guest_uuid,
+            empty_uuid,
         ];
         let result = EditorService::batch_load_by_uuids(&uuids, &state).await;
         assert!(result.is_ok());
         let map = result.unwrap();
-        assert_eq!(map.len(), 2);
+        assert_eq!(map.len(), 1);
         assert_eq!(
             map.get(&editor_admin.id.to_string()).unwrap(),
             &editor_admin.into()
         );
-        assert_eq!(
-            map.get(&editor_system.id.to_string()).unwrap(),
-            &editor_system.into()
-        );
-        let exec_sql = Transaction::from_sql_and_values(
-            DatabaseBackend::Postgres,
-            r#"SELECT "users"."id", "users"."no", "users"."name", "users"."phone", "users"."avatar_path", "users"."email" FROM "users" WHERE "users"."id" IN ($1, $2, $3)"#,
-            [admin_uuid.into(), guest_uuid.into(), empty_uuid.into()],
-        );
-        let logs = state.db.into_transaction_log();
-        assert_eq!(logs.len(), 1);
-        assert_eq!(logs[0], exec_sql);
+        assert!(!map.contains_key(&guest_uuid.to_string()));
     }
 }
@@ -0,0 +1,283 @@
+use salvo::http::body::BodySender;
+use salvo::oapi::ToSchema;
+use sea_orm::PaginatorTrait;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::time::Duration;
+use tokio::io::{AsyncWrite, AsyncWriteExt, BufWriter};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::config::AppState;
+use crate::domain::{
+    HandleResult,
+    dto::ResourceQueryDTO,
+    handle_ok,
+    vo::{ExportJobVO, SparseVO},
+};
+use crate::enums::{ErrorEnum, ExportFormatEnum, ExportStatusEnum, PlatformEnum, ViewModeEnum};
+use crate::error::AppError;
+use crate::service::{ResourceService, TenantService};
+use crate::utils::export_utils;
+
+/// 直接下载的最大记录数，超过时转为后台导出
+pub const EXPORT_SYNC_LIMIT: u64 = 10_000;
+
+/// 每批查询的记录数
+const EXPORT_BATCH_SIZE: u64 = 500;
+
+/// 后台导出任务的保存时间（秒）
+const EXPORT_JOB_TTL: u64 = 86_400;
+
+/// 列表导出
+///
+/// 查询条件与分页接口一致，按批次查询并逐行写出，CSV 的列由 VO 的 OpenAPI 文档决定。
+pub struct ExportService {}
+
+impl ExportService {
+    /// 导出的记录总数
+    pub async fn count<S: ResourceService>(
+        platform: &PlatformEnum,
+        dto: &S::QueryDTO,
+        state: &AppState,
+    ) -> HandleResult<u64> {
+        let query = S::list_query(platform, dto)?;
+        let total = query.count(state.db_read()).await?;
+        handle_ok(total)
+    }
+
+    /// 按批次查询并写出全部记录，返回导出的记录数
+    pub async fn write_rows<S, W>(
+        platform: &PlatformEnum,
+        dto: &S::QueryDTO,
+        format: &ExportFormatEnum,
+        writer: &mut W,
+        state: &AppState,
+    ) -> HandleResult<u64>
+    where
+        S: ResourceService,
+        S::MasterVO: ToSchema,
+        W: AsyncWrite + Unpin + Send,
+    {
+        let fields = dto.fields().cloned().unwrap_or_default();
+        let columns = export_utils::schema_columns::<S::MasterVO>(dto.fields());
+        if *format == ExportFormatEnum::Csv {
+            write_bytes(writer, export_utils::CSV_BOM).await?;
+            write_bytes(writer, &export_utils::csv_record(&columns)).await?;
+        }
+
+        let view_enum = ViewModeEnum::platform_to_list_mode(platform);
+        let query = S::list_query(platform, dto)?;
+        let mut paginator = query.paginate(state.db_read(), EXPORT_BATCH_SIZE);
+        let mut exported: u64 = 0;
+        while let Some(models) = paginator.fetch_and_next().await? {
+            let mut list: Vec<S::MasterVO> = models
+                .iter()
+                .map(|model| S::master_vo(&view_enum, dto.editor(), model))
+                .collect();
+            if let Some(load_models) = dto.load_models() {
+                S::load_relations(&mut list, load_models, state).await?;
+            }
+
+            for item in list {
+                let json = serde_json::to_value(SparseVO::new(item, fields.clone()))?;
+                let row = match format {
+                    ExportFormatEnum::Csv => export_utils::csv_row(&columns, &json),
+                    ExportFormatEnum::Ndjson => export_utils::ndjson_row(&json),
+                };
+                write_bytes(writer, &row).await?;
+                exported += 1;
+            }
+        }
+
+        writer.flush().await.map_err(io_error)?;
+        handle_ok(exported)
+    }
+
+    /// 直接下载：在后台任务中写入响应流
+    pub fn stream<S>(
+        platform: PlatformEnum,
+        dto: S::QueryDTO,
+        format: ExportFormatEnum,
+        sender: BodySender,
+        state: AppState,
+    ) where
+        S: ResourceService + 'static,
+        S::QueryDTO: Send + 'static,
+        S::MasterVO: ToSchema,
+    {
+        TenantService::spawn(async move {
+            let mut writer = BufWriter::new(sender);
+            if let Err(err) =
+                Self::write_rows::<S, _>(&platform, &dto, &format, &mut writer, &state).await
+            {
+                error!("Export stream failed: {:?}", err);
+            }
+        });
+    }
+
+    /// 创建后台导出任务，生成的文件通过任务ID下载
+    pub fn start_job<S>(
+        name: &str,
+        platform: PlatformEnum,
+        dto: S::QueryDTO,
+        format: ExportFormatEnum,
+        total: u64,
+        state: AppState,
+    ) -> HandleResult<ExportJobVO>
+    where
+        S: ResourceService + 'static,
+        S::QueryDTO: Send + 'static,
+        S::MasterVO: ToSchema,
+    {
+        let mut job = ExportJobVO::new(name, &format, total, dto.editor().editor_id);
+        Self::save_job(&job, &state)?;
+
+        let vo = job.clone();
+        TenantService::spawn(async move {
+            job.set_status(ExportStatusEnum::Running);
+            let _ = Self::save_job(&job, &state);
+
+            match Self::write_file::<S>(&job, &platform, &dto, &format, &state).await {
+                Ok(exported) => {
+                    job.exported = exported;
+                    job.set_status(ExportStatusEnum::Finished);
+                }
+                Err(err) => {
+                    error!("Export job {} failed: {:?}", job.id, err);
+                    job.message = Some(err.to_string());
+                    job.set_status(ExportStatusEnum::Failed);
+                }
+            }
+            let _ = Self::save_job(&job, &state);
+        });
+
+        handle_ok(vo)
+    }
+
+    /// 查询后台导出任务
+    pub fn fetch_job(id: &Uuid, state: &AppState) -> HandleResult<ExportJobVO> {
+        let json = state.cache.get(&Self::job_key(id))?;
+        let job = json
+            .and_then(|json| serde_json::from_str::<ExportJobVO>(&json).ok())
+            .ok_or_else(|| Into::<AppError>::into(ErrorEnum::RecordNotFound))?;
+        handle_ok(job)
+    }
+
+    /// 后台导出任务生成的文件，保存在 `web.export_dir` 目录
+    pub fn job_file_path(job: &ExportJobVO, state: &AppState) -> PathBuf {
+        let dir = state.config.current().web.export_dir();
+        dir.join(format!("{}.{}", job.id, job.format))
+    }
+
+    /// 删除过期的导出文件，文件的修改时间超过任务的保存时间时任务已不能查询，返回删除数量
+    pub async fn purge_expired_files(state: &AppState) -> HandleResult<u64> {
+        let dir = state.config.current().web.export_dir();
+        let mut entries = match tokio::fs::read_dir(&dir).await {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == ErrorKind::NotFound => return handle_ok(0),
+            Err(err) => return Err(io_error(err)),
+        };
+
+        let ttl = Duration::from_secs(EXPORT_JOB_TTL);
+        let mut count: u64 = 0;
+        while let Some(entry) = entries.next_entry().await.map_err(io_error)? {
+            let metadata = entry.metadata().await.map_err(io_error)?;
+            let expired = metadata
+                .modified()
+                .ok()
+                .and_then(|time| time.elapsed().ok())
+                .is_some_and(|elapsed| elapsed > ttl);
+            if metadata.is_file() && expired {
+                tokio::fs::remove_file(entry.path())
+                    .await
+                    .map_err(io_error)?;
+                count += 1;
+            }
+        }
+
+        handle_ok(count)
+    }
+
+    async fn write_file<S>(
+        job: &ExportJobVO,
+        platform: &PlatformEnum,
+        dto: &S::QueryDTO,
+        format: &ExportFormatEnum,
+        state: &AppState,
+    ) -> HandleResult<u64>
+    where
+        S: ResourceService,
+        S::MasterVO: ToSchema,
+    {
+        let path = Self::job_file_path(job, state);
+        if let Some(dir) = path.parent() {
+            tokio::fs::create_dir_all(dir).await.map_err(io_error)?;
+        }
+        let file = tokio::fs::File::create(&path).await.map_err(io_error)?;
+        let mut writer = BufWriter::new(file);
+        Self::write_rows::<S, _>(platform, dto, format, &mut writer, state).await
+    }
+
+    fn save_job(job: &ExportJobVO, state: &AppState) -> HandleResult<()> {
+        let json = serde_json::to_string(job)?;
+        state
+            .cache
+            .set(&Self::job_key(&job.id), &json, Some(EXPORT_JOB_TTL))?;
+        handle_ok(())
+    }
+
+    fn job_key(id: &Uuid) -> String {
+        format!("export:{}", id)
+    }
+}
+
+async fn write_bytes<W: AsyncWrite + Unpin + Send>(
+    writer: &mut W,
+    bytes: &[u8],
+) -> HandleResult<()> {
+    writer.write_all(bytes).await.map_err(io_error)?;
+    handle_ok(())
+}
+
+fn io_error(err: std::io::Error) -> AppError {
+    error!("Export write failed: {:?}", err);
+    AppError::Internal
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use std::env;
+    use std::fs::{self, File};
+    use std::time::SystemTime;
+
+    use super::*;
+    use crate::config::CmsConfig;
+    use crate::fixture::config::FakerAppState;
+
+    #[tokio::test]
+    async fn test_purge_expired_files() {
+        let state = FakerAppState::init().await;
+        let dir = env::temp_dir().join(format!("cms-exports-{}", Uuid::new_v4()));
+        let mut config = CmsConfig::default();
+        config.web = serde_json::from_value(json!({ "export_dir": dir })).unwrap();
+        state.config.replace(config);
+
+        // 目录不存在时忽略
+        assert_eq!(ExportService::purge_expired_files(&state).await.unwrap(), 0);
+
+        fs::create_dir_all(&dir).unwrap();
+        let expired = dir.join("expired.csv");
+        let file = File::create(&expired).unwrap();
+        let modified = SystemTime::now() - Duration::from_secs(EXPORT_JOB_TTL + 60);
+        file.set_modified(modified).unwrap();
+        let recent = dir.join("recent.csv");
+        File::create(&recent).unwrap();
+
+        assert_eq!(ExportService::purge_expired_files(&state).await.unwrap(), 1);
+        assert!(!expired.exists());
+        assert!(recent.exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,177 @@
+use migration::{Migrator, MigratorTrait};
+use sea_orm::{ConnectionTrait, DatabaseConnection, Statement};
+use std::collections::HashSet;
+use std::future::Future;
+use std::time::{Duration, Instant};
+use tracing::Span;
+
+use crate::config::AppState;
+use crate::domain::vo::{DependencyStatusVO, ReadinessVO};
+use crate::domain::{HandleResult, handle_ok};
+use crate::error::AppError;
+
+/// 单个依赖检查的超时时间
+const CHECK_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// 已执行的迁移记录
+const MIGRATION_SQL: &str = "SELECT version FROM seaql_migrations";
+
+pub struct HealthService {}
+
+impl HealthService {
+    /// 检查数据库、Redis 和 RabbitMQ，必需的依赖都可用时就绪
+    ///
+    /// 数据库和 Redis 为必需的依赖：Token 校验依赖 Redis。RabbitMQ 只用于后台任务，不可用时
+    /// 仍然就绪。
+    pub async fn readiness(state: &AppState) -> ReadinessVO {
+        let (database, redis, rabbitmq) = tokio::join!(
+            Self::check_database(state),
+            Self::check_redis(state),
+            Self::check_rabbitmq(state),
+        );
+        let dependencies = vec![database, redis, rabbitmq];
+        ReadinessVO {
+            ready: dependencies
+                .iter()
+                .all(|item| item.healthy || !item.required),
+            dependencies,
+        }
+    }
+
+    /// 主库可连接且没有待执行的迁移
+    async fn check_database(state: &AppState) -> DependencyStatusVO {
+        let mut pending = None;
+        let mut status = Self::check("database", true, async {
+            state.db.ping().await?;
+            let count = Self::pending_migrations(&state.db).await?;
+            pending = Some(count);
+            if count > 0 {
+                return Err(AppError::Database(format!("{} pending migrations", count)));
+            }
+            handle_ok(())
+        })
+        .await;
+        status.pending_migrations = pending;
+        status
+    }
+
+    async fn check_redis(state: &AppState) -> DependencyStatusVO {
+        let cache = state.cache.clone();
+        let span = Span::current();
+        Self::check("redis", true, async move {
+            // Redis 客户端为同步调用，避免阻塞异步线程；沿用请求的 span
+            tokio::task::spawn_blocking(move || span.in_scope(|| cache.ping()))
+                .await
+                .map_err(|err| AppError::Redis(err.to_string()))?
+        })
+        .await
+    }
+
+    async fn check_rabbitmq(state: &AppState) -> DependencyStatusVO {
+        Self::check("rabbitmq", false, state.publisher.ping()).await
+    }
+
+    /// 待执行的迁移数量
+    pub async fn pending_migrations(db: &DatabaseConnection) -> HandleResult<usize> {
+        let stmt = Statement::from_string(db.get_database_backend(), MIGRATION_SQL);
+        let applied = db
+            .query_all(stmt)
+            .await?
+            .iter()
+            .filter_map(|row| row.try_get::<String>("", "version").ok())
+            .collect::<HashSet<_>>();
+        let count = Migrator::migrations()
+            .iter()
+            .filter(|migration| !applied.contains(migration.name()))
+            .count();
+        handle_ok(count)
+    }
+
+    /// 执行检查并记录耗时，超时视为不可用
+    async fn check<F>(name: &str, required: bool, future: F) -> DependencyStatusVO
+    where
+        F: Future<Output = HandleResult<()>>,
+    {
+        let started = Instant::now();
+        let result = tokio::time::timeout(CHECK_TIMEOUT, future).await;
+        let error = match result {
+            Ok(Ok(())) => None,
+            Ok(Err(err)) => Some(err.to_string()),
+            Err(_) => Some(format!("timed out after {}s", CHECK_TIMEOUT.as_secs())),
+        };
+        DependencyStatusVO {
+            name: name.to_string(),
+            required,
+            healthy: error.is_none(),
+            latency_ms: started.elapsed().as_millis() as u64,
+            error,
+            pending_migrations: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::fixture::config::FakerAppState;
+    use crate::service::{MemoryCacheStore, MemoryPublisher};
+
+    #[tokio::test]
+    async fn test_readiness() {
+        let cache = Arc::new(MemoryCacheStore::new());
+        let publisher = Arc::new(MemoryPublisher::new());
+        let state = FakerAppState::init_with(cache.clone(), publisher.clone()).await;
+
+        let vo = HealthService::readiness(&state).await;
+        assert!(vo.ready);
+        let names: Vec<_> = vo
+            .dependencies
+            .iter()
+            .map(|item| item.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["database", "redis", "rabbitmq"]);
+        assert!(vo.dependencies.iter().all(|item| item.healthy));
+        assert_eq!(vo.dependencies[0].pending_migrations, Some(0));
+
+        // RabbitMQ 不是必需的依赖
+        publisher.set_available(false);
+        let vo = HealthService::readiness(&state).await;
+        assert!(vo.ready);
+        assert!(!vo.dependencies[2].healthy);
+        assert!(vo.dependencies[2].error.is_some());
+
+        cache.set_available(false);
+        let vo = HealthService::readiness(&state).await;
+        assert!(!vo.ready);
+        assert!(!vo.dependencies[1].healthy);
+    }
+
+    #[tokio::test]
+    async fn test_pending_migrations() {
+        let state = FakerAppState::init().await;
+        assert_eq!(
+            HealthService::pending_migrations(&state.db).await.unwrap(),
+            0
+        );
+
+        let stmt = Statement::from_string(
+            state.db.get_database_backend(),
+            "DELETE FROM seaql_migrations WHERE version LIKE 'm20250604%'",
+        );
+        state.db.execute(stmt).await.unwrap();
+        assert_eq!(
+            HealthService::pending_migrations(&state.db).await.unwrap(),
+            2
+        );
+
+        let vo = HealthService::readiness(&state).await;
+        assert!(!vo.ready);
+        assert_eq!(vo.dependencies[0].pending_migrations, Some(2));
+        assert_eq!(
+            vo.dependencies[0].error.as_deref(),
+            Some("Database error: 2 pending migrations")
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use salvo::oapi::{ToSchema, extract::FormFile};
+use sea_orm::{ActiveModelTrait, DatabaseTransaction, TransactionTrait};
+use serde::de::DeserializeOwned;
+use std::collections::{HashMap, HashSet};
+use validator::Validate;
+
+use super::resource_service::primary_id;
+use crate::config::AppState;
+use crate::domain::{
+    HandleResult,
+    dto::{EditorCurrentDTO, ResourceStoreDTO},
+    handle_ok,
+    vo::ImportResultVO,
+};
+use crate::enums::{AuditActionEnum, ErrorEnum, PlatformEnum};
+use crate::error::AppError;
+use crate::service::{CacheService, ResourceService};
+use crate::utils::{export_utils, import_utils};
+
+/// 每次导入的最大行数
+pub const IMPORT_ROW_LIMIT: usize = 5_000;
+
+/// 每批提交的记录数
+const IMPORT_BATCH_SIZE: usize = 100;
+
+/// CSV 导入
+///
+/// 每行按表单反序列化并执行与创建接口相同的校验和唯一性检查。试运行时只返回每行的结果；否则
+/// 按批次在事务内保存，单行失败只回滚该行。后续行引用本批次中未提交的记录时先提交当前批次。
+pub struct ImportService {}
+
+impl ImportService {
+    /// 读取上传的文件
+    pub async fn read_file(file: &FormFile) -> HandleResult<Vec<u8>> {
+        tokio::fs::read(file.path())
+            .await
+            .map_err(|_| ErrorEnum::ImportFileInvalid.into())
+    }
+
+    /// 导入 CSV 内容
+    pub async fn import<S, F>(
+        platform: &PlatformEnum,
+        bytes: &[u8],
+        dry_run: bool,
+        editor: &EditorCurrentDTO,
+        state: &AppState,
+    ) -> HandleResult<ImportResultVO>
+    where
+        S: ResourceService,
+        F: DeserializeOwned + Validate + ToSchema + Into<S::StoreDTO>,
+    {
+        let records = import_utils::csv_records(bytes)?;
+        if records.len() > IMPORT_ROW_LIMIT {
+            let message = format!("每次最多导入{}行", IMPORT_ROW_LIMIT);
+            return Err(AppError::BadRequest(message));
+        }
+
+        let properties = export_utils::schema_properties::<F>();
+        let mut result = ImportResultVO {
+            dry_run,
+            ..Default::default()
+        };
+        // 本次导入的记录键及ID，试运行时ID为 0
+        let mut keys: HashMap<String, i64> = HashMap::new();
+        // 当前批次中未提交的记录键
+        let mut pending: HashSet<String> = HashSet::new();
+        let mut batch_size = 0;
+        let mut txn = if dry_run {
+            None
+        } else {
+            Some(state.db.begin().await?)
+        };
+
+        for (line, record) in records.iter() {
+            let json = import_utils::record_to_json(&properties, record);
+            let mut dto: S::StoreDTO = match Self::parse_form::<F>(json) {
+                Ok(form) => form.into(),
+                Err(err) => {
+                    result.push_failure(*line, err);
+                    continue;
+                }
+            };
+            dto.set_editor(editor.clone());
+
+            let mut reference_id = None;
+            if let Some(key) = S::import_reference(&dto, record) {
+                if pending.contains(&key) {
+                    txn = Self::commit_batch(txn, state).await?;
+                    pending.clear();
+                    batch_size = 0;
+                }
+                reference_id = keys.get(&key).copied();
+            }
+
+            let key = S::import_key(&dto, record);
+            if key.as_ref().is_some_and(|key| keys.contains_key(key)) {
+                result.push_failure(*line, ErrorEnum::ImportDuplicate.into());
+                continue;
+            }
+
+            let saved = Self::import_row::<S>(
+                platform,
+                &mut dto,
+                record,
+                reference_id,
+                txn.as_ref(),
+                state,
+            )
+            .await;
+            match saved {
+                Ok(id) => {
+                    result.push_success(*line, id);
+                    if let Some(key) = key {
+                        if id.is_some() {
+                            pending.insert(key.clone());
+                        }
+                        keys.insert(key, id.unwrap_or(0));
+                    }
+                }
+                Err(err) => {
+                    result.push_failure(*line, err);
+                    continue;
+                }
+            }
+
+            if txn.is_some() {
+                batch_size += 1;
+                if batch_size >= IMPORT_BATCH_SIZE {
+                    txn = Self::commit_batch(txn, state).await?;
+                    pending.clear();
+                    batch_size = 0;
+                }
+            }
+        }
+
+        if let Some(txn) = txn {
+            txn.commit().await?;
+            CacheService::touch_cache_version(state.cache.as_ref());
+        }
+
+        handle_ok(result)
+    }
+
+    fn parse_form<F: DeserializeOwned + Validate>(json: serde_json::Value) -> HandleResult<F> {
+        let form: F = serde_json::from_value(json)?;
+        form.validate()?;
+        handle_ok(form)
+    }
+
+    /// 校验并保存一行，返回创建的记录ID，试运行时为空
+    async fn import_row<S: ResourceService>(
+        platform: &PlatformEnum,
+        dto: &mut S::StoreDTO,
+        record: &HashMap<String, String>,
+        reference_id: Option<i64>,
+        txn: Option<&DatabaseTransaction>,
+        state: &AppState,
+    ) -> HandleResult<Option<i64>> {
+        S::import_resolve(dto, record, reference_id, state).await?;
+
+        let mut model = <S::ActiveModel as ActiveModelTrait>::default();
+        S::fill_store_model(platform, dto, &mut model, None, state).await?;
+
+        let Some(txn) = txn else {
+            S::check_unique(0, &model, &state.db).await?;
+            return handle_ok(None);
+        };
+
+        let savepoint = txn.begin().await?;
+        let editor = dto.editor();
+        match S::persist_in(
+            AuditActionEnum::Create,
+            Some(dto),
+            model,
+            None,
+            editor,
+            &savepoint,
+        )
+        .await
+        {
+            Ok(model) => {
+                savepoint.commit().await?;
+                handle_ok(Some(primary_id::<S>(&model)))
+            }
+            Err(err) => {
+                savepoint.rollback().await?;
+                Err(err)
+            }
+        }
+    }
+
+    /// 提交当前批次并开始新的事务
+    async fn commit_batch(
+        txn: Option<DatabaseTransaction>,
+        state: &AppState,
+    ) -> HandleResult<Option<DatabaseTransaction>> {
+        match txn {
+            Some(txn) => {
+                txn.commit().await?;
+                handle_ok(Some(state.db.begin().await?))
+            }
+            None => handle_ok(None),
+        }
+    }
+}
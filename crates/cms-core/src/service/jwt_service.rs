@@ -2,6 +2,7 @@ use chrono::Duration;
 use jsonwebtoken::{self, EncodingKey};
 use salvo::prelude::*;
 use sea_orm::*;
+use tracing::{debug, warn};
 use uuid::Uuid;
 
 use crate::config::{AppState, JwtConfig};
@@ -15,7 +16,7 @@ use crate::enums::TokenTypeEnum;
 use crate::error::AppError;
 use crate::utils::time_utils;
 
-use super::RedisService;
+use super::CacheService;
 
 pub struct JwtService {}
 
@@ -24,15 +25,21 @@ impl JwtService {
     pub async fn create(
         user_id: &Uuid,
         user_type: &str,
+        tenant_id: i64,
         state: &AppState,
     ) -> HandleResult<CertificateModel> {
         let uuid = uuid::Uuid::new_v4();
         let uuid_string = uuid.to_string();
-        let access = Self::generate_access_token(&uuid, &user_id, user_type).unwrap();
-        let refresh = Self::generate_refresh_token(&uuid, &user_id, user_type).unwrap();
+        let config = state.config.current();
+        let cfg = &config.jwt;
+        let access =
+            Self::generate_access_token(&uuid, user_id, user_type, tenant_id, cfg).unwrap();
+        let refresh =
+            Self::generate_refresh_token(&uuid, user_id, user_type, tenant_id, cfg).unwrap();
         let now = time_utils::current_time();
         let model = CertificateActiveModel {
             id: Set(uuid.to_owned()),
+            tenant_id: Set(tenant_id),
             user_id: Set(user_id.to_owned()),
             user_type: Set(user_type.to_owned()),
             access_token: Set(access.token_value.to_owned()),
@@ -45,7 +52,7 @@ impl JwtService {
         };
 
         let model: CertificateModel = model.insert(&state.db).await?;
-        RedisService::set_jwt_key(&state.redis, &uuid_string, access.expired_time);
+        CacheService::set_jwt_key(state.cache.as_ref(), &uuid_string, access.expired_time);
 
         handle_ok(model)
     }
@@ -85,26 +92,35 @@ impl JwtService {
         let current_timestamp = time_utils::current_timestamp();
         let refresh_expired_time = time_utils::to_timestamp(&model.refresh_expired_at);
         if current_timestamp > refresh_expired_time {
-            println!("current_time: {}, refresh expired time: {}", current_timestamp, refresh_expired_time);
+            debug!(
+                current_timestamp,
+                refresh_expired_time, "Refresh token expired"
+            );
             let err = AppError::TokenExpired;
             return Err(err);
         }
 
         let user_type = model.user_type.to_owned();
         let user_type = user_type.as_str();
+        let tenant_id = model.tenant_id;
+        let config = state.config.current();
+        let cfg = &config.jwt;
         let mut model: CertificateActiveModel = model.into();
-        let access = Self::generate_access_token(&cert_id, &user_id, user_type).unwrap();
+        let access =
+            Self::generate_access_token(&cert_id, &user_id, user_type, tenant_id, cfg).unwrap();
         model.access_token = Set(access.token_value.to_owned());
         model.access_expired_at = Set(time_utils::from_timestamp(access.expired_time));
 
         if current_timestamp + (3 * 24 * 60 * 60) > refresh_expired_time {
-            let refresh = Self::generate_refresh_token(&cert_id, &user_id, user_type).unwrap();
+            let refresh =
+                Self::generate_refresh_token(&cert_id, &user_id, user_type, tenant_id, cfg)
+                    .unwrap();
             model.refresh_token = Set(refresh.token_value.to_owned());
             model.refresh_expired_at = Set(time_utils::from_timestamp(refresh.expired_time));
         }
         model.updated_at = Set(time_utils::current_time());
         let model: CertificateModel = model.update(db).await?;
-        RedisService::set_jwt_key(&state.redis, &claim_user_id, access.expired_time);
+        CacheService::set_jwt_key(state.cache.as_ref(), &claim_user_id, access.expired_time);
 
         handle_ok(model)
     }
@@ -114,8 +130,9 @@ impl JwtService {
         cert_id: &Uuid,
         user_id: &Uuid,
         user_type: &str,
+        tenant_id: i64,
+        cfg: &JwtConfig,
     ) -> HandleResult<JwtTokenDTO> {
-        let cfg = JwtConfig::from_env().expect("Failed to load jwt config");
         let secret_bytes = cfg.secret_bytes();
         let days = cfg.get_access_expire_days();
         let now = time_utils::current_time();
@@ -127,6 +144,7 @@ impl JwtService {
             user_type: user_type.to_owned(),
             token_type: TokenTypeEnum::AccessToken.as_value(),
             exp: expired_time,
+            tenant_id: Some(tenant_id),
         };
 
         let header = jsonwebtoken::Header::default();
@@ -156,7 +174,7 @@ impl JwtService {
         };
 
         let state = depot.obtain::<AppState>().unwrap();
-        if !RedisService::has_jwt_key(&state.redis, &claims.uuid) {
+        if !CacheService::has_jwt_key(state.cache.as_ref(), &claims.uuid) {
             let err = AppError::Unauthorized;
             return Err(err);
         }
@@ -181,8 +199,9 @@ impl JwtService {
         cert_id: &Uuid,
         user_id: &Uuid,
         user_type: &str,
+        tenant_id: i64,
+        cfg: &JwtConfig,
     ) -> HandleResult<JwtTokenDTO> {
-        let cfg = JwtConfig::from_env().expect("Failed to load jwt config");
         let secret_bytes = cfg.secret_bytes();
         let days = cfg.get_refresh_expire_days();
         let now = time_utils::current_time();
@@ -194,6 +213,7 @@ impl JwtService {
             user_type: user_type.to_owned(),
             token_type: TokenTypeEnum::RefreshToken.as_value(),
             exp: expired_time,
+            tenant_id: Some(tenant_id),
         };
 
         let header = jsonwebtoken::Header::default();
@@ -232,7 +252,10 @@ impl JwtService {
     }
 
     // 删除 Token
-    pub async fn delete_by_claims(dto: Option<&JwtClaimsDTO>, state: &AppState) -> HandleResult<()> {
+    pub async fn delete_by_claims(
+        dto: Option<&JwtClaimsDTO>,
+        state: &AppState,
+    ) -> HandleResult<()> {
         if dto.is_none() {
             return handle_ok(());
         }
@@ -247,17 +270,16 @@ impl JwtService {
         }
         let uuid_string = dto.uuid.to_owned();
         let uuid_str = uuid_string.as_str();
-        RedisService::del_jwt_key(&state.redis, uuid_str);
-        println!("delete_by_claims:  kkkk {}", uuid_str);
+        CacheService::del_jwt_key(state.cache.as_ref(), uuid_str);
         match Uuid::parse_str(uuid_str) {
             Ok(uuid) => {
-                println!("uuid is valid and run .....");
+                debug!("Delete certificate {}", uuid);
                 let _ = CertificateEntity::delete_by_id(uuid)
                     .exec(&state.db)
                     .await?;
             }
             Err(_) => {
-                println!("delete_uuid invalid: {}", uuid_str);
+                warn!("Invalid certificate uuid in claims: {}", uuid_str);
             }
         }
 
@@ -267,7 +289,6 @@ impl JwtService {
 
 #[cfg(test)]
 mod tests {
-    use std::vec;
 
     use super::*;
     use crate::domain::entity::certificate::Model as CertificateModel;
@@ -275,79 +296,53 @@ mod tests {
     use crate::fixture::config::FakerAppState;
     use crate::utils::time_utils::current_timestamp;
 
-    
-    fn cert_table_field_str() -> &'static str {
-        r#""id", "user_type", "user_id", "access_token", "access_expired_at", "refresh_token", "refresh_expired_at", "created_at", "updated_at""#
+    async fn insert_certificate(model: CertificateModel, state: &AppState) -> CertificateModel {
+        model.into_active_model().insert(&state.db).await.unwrap()
     }
 
     #[tokio::test]
     async fn test_create() {
-        let mut state = FakerAppState::init().await;
+        let state = FakerAppState::init().await;
         let user_id = Uuid::new_v4();
         let type_str = EditorTypeEnum::Admin.string_value();
         let user_type = type_str.as_str();
-        let mock_result = MockExecResult {
-            last_insert_id: 1,
-            rows_affected: 1,
-        };
-        let insert_uuid = Uuid::new_v4();
-        let current_time = time_utils::current_time();
-        let cert_model = CertificateModel {
-            id: insert_uuid.clone(),
-            user_id: user_id.clone(),
-            user_type: user_type.to_owned(),
-            access_token: "access_token".to_owned(),
-            access_expired_at: current_time.clone(),
-            refresh_token: "refresh_token".to_owned(),
-            refresh_expired_at: current_time.clone(),
-            created_at: current_time.clone(),
-            updated_at: current_time.clone(),
-        };
-        state.db = MockDatabase::new(DatabaseBackend::Postgres)
-            .append_query_results([vec![cert_model.clone()]])
-            .append_exec_results([mock_result])
-            .into_connection();
 
-        let res = JwtService::create(&user_id, user_type, &state).await;
+        let res = JwtService::create(&user_id, user_type, 2, &state).await;
         assert!(res.is_ok());
         let model = res.unwrap();
-        assert_eq!(model.id, cert_model.id);
-        assert_eq!(model.user_id, cert_model.user_id);
-        assert_eq!(model.user_type, cert_model.user_type);
-        let logs = state.db.into_transaction_log();
-        let log = logs[0].clone();
-        let statements = log.statements();
-        assert_eq!(statements.len(), 1);
-        let statement = statements[0].clone();
-        let table_fields = cert_table_field_str();
-        let sql_text = format!(
-            r#"INSERT INTO "auth_certificates" ({}) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9) RETURNING {}"#,
-            table_fields, table_fields
-        );
-        let model_values: Vec<Value> = vec![user_type.into(), user_id.into()];
-        let state_values = statement.values.unwrap().0;
-        let insert_values: Vec<Value> = vec![state_values[1].clone(), state_values[2].clone()];
-        assert_eq!(model_values, insert_values);
-        assert_eq!(statement.sql, sql_text);
+        assert_eq!(model.user_id, user_id);
+        assert_eq!(model.user_type, user_type);
+
+        let saved = CertificateEntity::find_by_id(model.id)
+            .one(&state.db)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(saved.user_id, user_id);
+        assert_eq!(saved.user_type, user_type);
+        assert_eq!(saved.tenant_id, 2);
+        assert_eq!(saved.access_token, model.access_token);
+        assert_eq!(saved.refresh_token, model.refresh_token);
     }
 
     #[tokio::test]
     async fn test_update_by_claims_fail() {
-        let mut state = FakerAppState::init().await;
+        let state = FakerAppState::init().await;
         let res = JwtService::update_by_claims(None, &state).await;
         assert!(res.is_err());
         let err = res.unwrap_err();
         assert_eq!(err, AppError::Unauthorized);
 
-        let dto_uuid = Uuid::new_v4();   
-        let dto_user_id =  Uuid::new_v4();
+        let dto_uuid = Uuid::new_v4();
+        let dto_user_id = Uuid::new_v4();
         let current_timestamp = time_utils::current_timestamp();
-        let mut dto = JwtClaimsDTO{
+        let mut dto = JwtClaimsDTO {
             uuid: dto_uuid.to_string(),
-             user_id: dto_user_id.to_string(),
-             user_type: EditorTypeEnum::Admin.string_value(),
-             token_type: TokenTypeEnum::None.as_value(),
-             exp: current_timestamp,
+            user_id: dto_user_id.to_string(),
+            user_type: EditorTypeEnum::Admin.string_value(),
+            token_type: TokenTypeEnum::None.as_value(),
+            exp: current_timestamp,
+            tenant_id: None,
         };
         let res = JwtService::update_by_claims(Some(&dto), &state).await;
         assert!(res.is_err());
@@ -360,32 +355,24 @@ mod tests {
         let err = res.unwrap_err();
         assert_eq!(err, AppError::Unauthorized);
 
-        let cert_model = CertificateModel {
-            user_id: dto_user_id.clone(),
-            refresh_expired_at: time_utils::current_time() + Duration::hours(1),
-            ..Default::default()
-        };
-        dto.token_type = TokenTypeEnum::RefreshToken.as_value();
-        state.db = MockDatabase::new(DatabaseBackend::Postgres)
-            .append_query_results([
-                // uuid not exists in db
-                Vec::<CertificateModel>::new(),
-                vec![CertificateModel{
-                    refresh_expired_at: time_utils::current_time() - Duration::minutes(1),
-                    ..cert_model.clone()
-                }],
-                vec![CertificateModel{
-                    refresh_expired_at: time_utils::current_time() + Duration::minutes(3),
-                    ..cert_model.clone()
-                }]
-            ])
-            .into_connection();
         // uuid not exists in db
+        dto.token_type = TokenTypeEnum::RefreshToken.as_value();
         let res = JwtService::update_by_claims(Some(&dto), &state).await;
         assert!(res.is_err());
         let err = res.unwrap_err();
         assert_eq!(err, AppError::TokenNotFound);
+
         // refresh token expired
+        insert_certificate(
+            CertificateModel {
+                id: dto_uuid,
+                user_id: dto_user_id,
+                refresh_expired_at: time_utils::current_time() - Duration::minutes(1),
+                ..Default::default()
+            },
+            &state,
+        )
+        .await;
         let res = JwtService::update_by_claims(Some(&dto), &state).await;
         assert!(res.is_err());
         let err = res.unwrap_err();
@@ -394,85 +381,86 @@ mod tests {
 
     #[tokio::test]
     async fn test_update_by_claims_ok() {
-        let mut state = FakerAppState::init().await;
-        let dto_uuid = Uuid::new_v4();   
-        let dto_user_id =  Uuid::new_v4();
+        let state = FakerAppState::init().await;
+        let dto_uuid = Uuid::new_v4();
+        let dto_user_id = Uuid::new_v4();
         let current_timestamp = time_utils::current_timestamp();
-        let dto = JwtClaimsDTO{
+        let dto = JwtClaimsDTO {
             uuid: dto_uuid.to_string(),
-             user_id: dto_user_id.to_string(),
-             user_type: EditorTypeEnum::Admin.string_value(),
-             token_type: TokenTypeEnum::RefreshToken.as_value(),
-             exp: current_timestamp,
-        };
-        let cert_model = CertificateModel {
-            user_id: dto_user_id.clone(),
-            refresh_expired_at: time_utils::current_time() + Duration::minutes(1),
-            ..Default::default()
+            user_id: dto_user_id.to_string(),
+            user_type: EditorTypeEnum::Admin.string_value(),
+            token_type: TokenTypeEnum::RefreshToken.as_value(),
+            exp: current_timestamp,
+            tenant_id: None,
         };
-        state.db = MockDatabase::new(DatabaseBackend::Postgres)
-            .append_query_results([
-                vec![cert_model.clone()],
-                vec![cert_model.clone()],
-            ])
-            .append_exec_results([
-                MockExecResult {
-                    last_insert_id: 1,
-                    rows_affected: 1,
-                },
-            ])
-            .into_connection();
+        let cert_model = insert_certificate(
+            CertificateModel {
+                id: dto_uuid,
+                user_id: dto_user_id,
+                access_token: "access_token".to_owned(),
+                refresh_token: "refresh_token".to_owned(),
+                refresh_expired_at: time_utils::current_time() + Duration::minutes(1),
+                ..Default::default()
+            },
+            &state,
+        )
+        .await;
+
         // refresh token is ok
         let res = JwtService::update_by_claims(Some(&dto), &state).await;
         assert!(res.is_ok());
-        let logs = state.db.into_transaction_log();
-        let table_fields = cert_table_field_str();
-        let update_sql = format!(
-            r#"UPDATE "auth_certificates" SET "access_token" = $1, "access_expired_at" = $2, "refresh_token" = $3, "refresh_expired_at" = $4, "updated_at" = $5 WHERE "auth_certificates"."id" = $6 RETURNING {}"#,
-            table_fields
-        );
-        let update_log = logs[1].clone();
-        let statements = update_log.statements();
-        let update_statement = statements[0].clone();
-        assert_eq!(update_statement.sql, update_sql);
-        assert_eq!(logs.len(), 2);
+        let saved = CertificateEntity::find_by_id(dto_uuid)
+            .one(&state.db)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(saved, res.unwrap());
+        assert_ne!(saved.access_token, cert_model.access_token);
+        // refresh token 即将过期时一起更新
+        assert_ne!(saved.refresh_token, cert_model.refresh_token);
+        assert!(saved.refresh_expired_at > cert_model.refresh_expired_at);
     }
 
-     #[test]
-     fn test_generate_access_token() {
-         let cert_id = Uuid::new_v4();
-         let user_id = Uuid::new_v4();
-         let user_type = EditorTypeEnum::Admin.string_value();
-
-         let res = JwtService::generate_access_token(&cert_id, &user_id, user_type.as_str());
-         assert!(res.is_ok());
-         let dto = res.unwrap();
-         assert_eq!(dto.token_type, TokenTypeEnum::AccessToken.as_value());
-     }
-
-     #[test]
-     fn test_generate_refresh_token() {
-         let cert_id = Uuid::new_v4();
-         let user_id = Uuid::new_v4();
-         let user_type = EditorTypeEnum::Admin.string_value();
-
-         let res = JwtService::generate_refresh_token(&cert_id, &user_id, user_type.as_str());
-         assert!(res.is_ok());
-     }
-
-     #[tokio::test]
+    #[test]
+    fn test_generate_access_token() {
+        let cert_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let user_type = EditorTypeEnum::Admin.string_value();
+        let cfg = JwtConfig::default();
+
+        let res =
+            JwtService::generate_access_token(&cert_id, &user_id, user_type.as_str(), 0, &cfg);
+        assert!(res.is_ok());
+        let dto = res.unwrap();
+        assert_eq!(dto.token_type, TokenTypeEnum::AccessToken.as_value());
+    }
+
+    #[test]
+    fn test_generate_refresh_token() {
+        let cert_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let user_type = EditorTypeEnum::Admin.string_value();
+        let cfg = JwtConfig::default();
+
+        let res =
+            JwtService::generate_refresh_token(&cert_id, &user_id, user_type.as_str(), 0, &cfg);
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
     async fn test_delete_by_claims() {
-        let mut state = FakerAppState::init().await;
+        let state = FakerAppState::init().await;
         let res = JwtService::delete_by_claims(None, &state).await;
         assert!(res.is_ok());
-        
-        let dto_user_id =  Uuid::new_v4();
-        let mut dto = JwtClaimsDTO{
+
+        let dto_user_id = Uuid::new_v4();
+        let mut dto = JwtClaimsDTO {
             uuid: "".to_string(),
             user_id: dto_user_id.to_string(),
             user_type: EditorTypeEnum::Admin.string_value(),
             token_type: TokenTypeEnum::None.as_value(),
             exp: time_utils::current_timestamp() + 1000,
+            tenant_id: None,
         };
         let res = JwtService::delete_by_claims(Some(&dto), &state).await;
         assert!(res.is_err());
@@ -493,26 +481,33 @@ mod tests {
         let dto_uuid = Uuid::new_v4();
         let dto_uuid_string = dto_uuid.to_string();
         dto.uuid = dto_uuid_string.clone();
-        let dto_uuid_str= dto_uuid_string.as_str();
-        RedisService::set_jwt_key(&state.redis, dto_uuid_str, current_timestamp() + 1000);
-        assert!(RedisService::has_jwt_key(&state.redis, dto_uuid_str));
-        
-        println!("dto uuid: {}", dto.uuid);
-        state.db = MockDatabase::new(DatabaseBackend::Postgres)
-        .append_exec_results([MockExecResult {
-            last_insert_id: 1,
-            rows_affected: 1,
-        }])
-        .into_connection();
+        let dto_uuid_str = dto_uuid_string.as_str();
+        let expired_time = current_timestamp() + 1000;
+        CacheService::set_jwt_key(state.cache.as_ref(), dto_uuid_str, expired_time);
+        assert!(CacheService::has_jwt_key(
+            state.cache.as_ref(),
+            dto_uuid_str
+        ));
+        insert_certificate(
+            CertificateModel {
+                id: dto_uuid,
+                user_id: dto_user_id,
+                ..Default::default()
+            },
+            &state,
+        )
+        .await;
+
         let res = JwtService::delete_by_claims(Some(&dto), &state).await;
-        assert_eq!(RedisService::has_jwt_key(&state.redis, dto_uuid_str), false);
+        assert!(!CacheService::has_jwt_key(
+            state.cache.as_ref(),
+            dto_uuid_str
+        ));
         assert!(res.is_ok());
-        assert_eq!(state.db.into_transaction_log(), [
-            Transaction::from_sql_and_values(
-                DatabaseBackend::Postgres, 
-                r#"DELETE FROM "auth_certificates" WHERE "auth_certificates"."id" = $1"#, 
-                [dto_uuid.into()]
-            )
-        ]);
-    } 
+        let saved = CertificateEntity::find_by_id(dto_uuid)
+            .one(&state.db)
+            .await
+            .unwrap();
+        assert!(saved.is_none());
+    }
 }
@@ -0,0 +1,206 @@
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::domain::{HandleResult, handle_ok};
+use crate::error::AppError;
+
+use super::CacheStore;
+
+/// 缓存的值
+#[derive(Debug, Clone)]
+enum CacheValue {
+    String(String),
+    Json(JsonValue),
+}
+
+#[derive(Debug, Clone)]
+struct CacheEntry {
+    value: CacheValue,
+    expired_at: Option<Instant>,
+}
+
+impl CacheEntry {
+    fn new(value: CacheValue, ex: Option<u64>) -> Self {
+        Self {
+            value,
+            expired_at: ex.map(|ex| Instant::now() + Duration::from_secs(ex)),
+        }
+    }
+
+    fn is_expired(&self) -> bool {
+        self.expired_at.is_some_and(|time| time <= Instant::now())
+    }
+}
+
+/// 内存缓存，用于测试及没有 Redis 的开发环境
+#[derive(Debug, Default)]
+pub struct MemoryCacheStore {
+    entries: Mutex<HashMap<String, CacheEntry>>,
+    /// 模拟缓存服务不可用
+    unavailable: AtomicBool,
+}
+
+impl MemoryCacheStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置缓存服务是否可用，不可用时 ping 失败
+    pub fn set_available(&self, available: bool) {
+        self.unavailable.store(!available, Ordering::Relaxed);
+    }
+
+    /// 在未过期的记录上执行操作，过期的记录先删除
+    fn with_entries<T>(&self, f: impl FnOnce(&mut HashMap<String, CacheEntry>) -> T) -> T {
+        let mut entries = self.entries.lock().unwrap_or_else(|err| err.into_inner());
+        entries.retain(|_, entry| !entry.is_expired());
+        f(&mut entries)
+    }
+}
+
+impl CacheStore for MemoryCacheStore {
+    fn set(&self, key: &str, value: &str, ex: Option<u64>) -> HandleResult<()> {
+        let entry = CacheEntry::new(CacheValue::String(value.to_owned()), ex);
+        self.with_entries(|entries| entries.insert(key.to_owned(), entry));
+        handle_ok(())
+    }
+
+    fn set_nx(&self, key: &str, value: &str, ex: Option<u64>) -> HandleResult<bool> {
+        let entry = CacheEntry::new(CacheValue::String(value.to_owned()), ex);
+        let inserted = self.with_entries(|entries| {
+            if entries.contains_key(key) {
+                return false;
+            }
+            entries.insert(key.to_owned(), entry);
+            true
+        });
+        handle_ok(inserted)
+    }
+
+    fn get(&self, key: &str) -> HandleResult<Option<String>> {
+        let value = self.with_entries(|entries| match entries.get(key) {
+            Some(CacheEntry {
+                value: CacheValue::String(value),
+                ..
+            }) => Some(value.to_owned()),
+            Some(CacheEntry {
+                value: CacheValue::Json(value),
+                ..
+            }) => Some(value.to_string()),
+            None => None,
+        });
+        handle_ok(value)
+    }
+
+    fn del(&self, key: &str) -> HandleResult<()> {
+        self.with_entries(|entries| entries.remove(key));
+        handle_ok(())
+    }
+
+    fn incr(&self, key: &str, delta: i64) -> HandleResult<i64> {
+        self.with_entries(|entries| {
+            // 自增不改变过期时间
+            let entry = entries
+                .entry(key.to_owned())
+                .or_insert_with(|| CacheEntry::new(CacheValue::String(String::from("0")), None));
+            let current = match &entry.value {
+                CacheValue::String(value) => value.parse::<i64>().ok(),
+                CacheValue::Json(_) => None,
+            }
+            .ok_or_else(|| {
+                AppError::Redis(String::from("value is not an integer or out of range"))
+            })?;
+
+            let value = current + delta;
+            entry.value = CacheValue::String(value.to_string());
+            handle_ok(value)
+        })
+    }
+
+    fn set_json(&self, key: &str, value: &JsonValue) -> HandleResult<()> {
+        let entry = CacheEntry::new(CacheValue::Json(value.clone()), None);
+        self.with_entries(|entries| entries.insert(key.to_owned(), entry));
+        handle_ok(())
+    }
+
+    fn get_json(&self, key: &str) -> HandleResult<Option<JsonValue>> {
+        let value = self.with_entries(|entries| match entries.get(key) {
+            Some(CacheEntry {
+                value: CacheValue::Json(value),
+                ..
+            }) => Some(value.clone()),
+            _ => None,
+        });
+        handle_ok(value)
+    }
+
+    fn ping(&self) -> HandleResult<()> {
+        if self.unavailable.load(Ordering::Relaxed) {
+            return Err(AppError::Redis(String::from("cache is unavailable")));
+        }
+        handle_ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_set_get_and_del() {
+        let cache = MemoryCacheStore::new();
+        cache.set("key", "value", None).unwrap();
+        assert_eq!(cache.get("key").unwrap(), Some("value".to_string()));
+
+        cache.del("key").unwrap();
+        assert_eq!(cache.get("key").unwrap(), None);
+    }
+
+    #[test]
+    fn test_set_expired() {
+        let cache = MemoryCacheStore::new();
+        cache.set("key", "value", Some(0)).unwrap();
+        assert_eq!(cache.get("key").unwrap(), None);
+
+        cache.set("key", "value", Some(60)).unwrap();
+        assert_eq!(cache.get("key").unwrap(), Some("value".to_string()));
+    }
+
+    #[test]
+    fn test_set_nx() {
+        let cache = MemoryCacheStore::new();
+        assert!(cache.set_nx("key", "first", Some(60)).unwrap());
+        assert!(!cache.set_nx("key", "second", Some(60)).unwrap());
+        assert_eq!(cache.get("key").unwrap(), Some("first".to_string()));
+
+        cache.set("expired", "value", Some(0)).unwrap();
+        assert!(cache.set_nx("expired", "value", None).unwrap());
+    }
+
+    #[test]
+    fn test_incr() {
+        let cache = MemoryCacheStore::new();
+        assert_eq!(cache.incr("count", 1).unwrap(), 1);
+        assert_eq!(cache.incr("count", 2).unwrap(), 3);
+        assert_eq!(cache.get("count").unwrap(), Some("3".to_string()));
+
+        cache.set("name", "value", None).unwrap();
+        assert!(cache.incr("name", 1).is_err());
+    }
+
+    #[test]
+    fn test_set_get_json() {
+        let cache = MemoryCacheStore::new();
+        let value = json!([{"id": 1, "name": "Alice"}]);
+        cache.set_json("list", &value).unwrap();
+        assert_eq!(cache.get_json("list").unwrap(), Some(value));
+        assert_eq!(cache.get_json("none").unwrap(), None);
+
+        cache.set("name", "value", None).unwrap();
+        assert_eq!(cache.get_json("name").unwrap(), None);
+    }
+}
@@ -0,0 +1,126 @@
+use async_trait::async_trait;
+use serde_json::Value as JsonValue;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::domain::{HandleResult, handle_ok};
+use crate::error::AppError;
+
+use super::MessagePublisher;
+
+/// 已发布的消息
+#[derive(Debug, Clone, PartialEq)]
+pub struct PublishedMessage {
+    pub exchange: String,
+    pub routing_key: String,
+    pub payload: Vec<u8>,
+}
+
+impl PublishedMessage {
+    /// 按 JSON 解析消息内容
+    pub fn json(&self) -> Option<JsonValue> {
+        serde_json::from_slice(&self.payload).ok()
+    }
+}
+
+/// 内存消息发布，记录发布的消息用于测试断言
+#[derive(Debug, Default)]
+pub struct MemoryPublisher {
+    messages: Mutex<Vec<PublishedMessage>>,
+    /// 模拟消息服务不可用
+    unavailable: AtomicBool,
+}
+
+impl MemoryPublisher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 已发布的全部消息
+    pub fn messages(&self) -> Vec<PublishedMessage> {
+        self.messages
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .clone()
+    }
+
+    /// 设置消息服务是否可用，不可用时 ping 失败
+    pub fn set_available(&self, available: bool) {
+        self.unavailable.store(!available, Ordering::Relaxed);
+    }
+
+    /// 清空已发布的消息
+    pub fn clear(&self) {
+        self.messages
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .clear();
+    }
+}
+
+#[async_trait]
+impl MessagePublisher for MemoryPublisher {
+    async fn publish(&self, exchange: &str, routing_key: &str, payload: &[u8]) -> HandleResult<()> {
+        if (exchange.is_empty() && routing_key.is_empty()) || payload.is_empty() {
+            let error = AppError::BadRequest(
+                "Exchange, routing key, and payload must not be empty".to_string(),
+            );
+            return Err(error);
+        }
+
+        let message = PublishedMessage {
+            exchange: exchange.to_owned(),
+            routing_key: routing_key.to_owned(),
+            payload: payload.to_vec(),
+        };
+        self.messages
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .push(message);
+        handle_ok(())
+    }
+
+    async fn ping(&self) -> HandleResult<()> {
+        if self.unavailable.load(Ordering::Relaxed) {
+            return Err(AppError::Queue(String::from("queue is unavailable")));
+        }
+        handle_ok(())
+    }
+
+    async fn close(&self) {
+        self.set_available(false);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_publish() {
+        let publisher = MemoryPublisher::new();
+        let payload = json!({"id": 1}).to_string();
+        publisher
+            .publish("", "queue_test", payload.as_bytes())
+            .await
+            .unwrap();
+
+        let messages = publisher.messages();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].exchange, "");
+        assert_eq!(messages[0].routing_key, "queue_test");
+        assert_eq!(messages[0].json(), Some(json!({"id": 1})));
+
+        publisher.clear();
+        assert!(publisher.messages().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_publish_invalid() {
+        let publisher = MemoryPublisher::new();
+        assert!(publisher.publish("", "", b"payload").await.is_err());
+        assert!(publisher.publish("", "queue_test", b"").await.is_err());
+        assert!(publisher.messages().is_empty());
+    }
+}
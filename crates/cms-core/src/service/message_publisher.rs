@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use std::fmt::Debug;
+
+use crate::domain::HandleResult;
+
+/// 消息发布
+///
+/// 生产环境使用 RabbitMQ，测试使用内存实现记录发布的消息。
+#[async_trait]
+pub trait MessagePublisher: Debug + Send + Sync {
+    /// 发布消息，交换机为空时按路由键发送到同名队列
+    async fn publish(&self, exchange: &str, routing_key: &str, payload: &[u8]) -> HandleResult<()>;
+
+    /// 检查消息服务是否可用
+    async fn ping(&self) -> HandleResult<()>;
+
+    /// 关闭连接，关闭后不能再发布消息
+    async fn close(&self);
+}
@@ -0,0 +1,306 @@
+use lazy_static::lazy_static;
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounterVec, IntGauge, IntGaugeVec, Opts, Registry,
+    TextEncoder,
+};
+use sea_orm::*;
+use std::time::Duration;
+use tracing::{error, field, info_span};
+
+use crate::config::AppState;
+use crate::domain::entity::certificate::{
+    Column as CertificateColumn, Entity as CertificateEntity,
+};
+use crate::domain::{HandleResult, handle_ok};
+use crate::utils::time_utils;
+
+/// Prometheus 文本格式的 Content-Type
+pub const METRICS_CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+/// 全部指标，标签只使用路由模板、状态码等有限取值
+struct Metrics {
+    registry: Registry,
+    http_requests: IntCounterVec,
+    http_duration: HistogramVec,
+    db_pool_connections: IntGaugeVec,
+    db_pool_max_connections: IntGaugeVec,
+    redis_duration: HistogramVec,
+    redis_errors: IntCounterVec,
+    rabbitmq_published: IntCounterVec,
+    rabbitmq_consumed: IntCounterVec,
+    logins: IntCounterVec,
+    active_sessions: IntGauge,
+}
+
+impl Metrics {
+    fn new() -> Self {
+        let registry = Registry::new();
+        let metrics = Self {
+            http_requests: IntCounterVec::new(
+                Opts::new("cms_http_requests_total", "HTTP requests"),
+                &["method", "route", "status"],
+            )
+            .unwrap(),
+            http_duration: HistogramVec::new(
+                HistogramOpts::new(
+                    "cms_http_request_duration_seconds",
+                    "HTTP request latency in seconds",
+                ),
+                &["method", "route", "status"],
+            )
+            .unwrap(),
+            db_pool_connections: IntGaugeVec::new(
+                Opts::new("cms_db_pool_connections", "Database pool connections"),
+                &["pool", "state"],
+            )
+            .unwrap(),
+            db_pool_max_connections: IntGaugeVec::new(
+                Opts::new(
+                    "cms_db_pool_max_connections",
+                    "Database pool maximum connections",
+                ),
+                &["pool"],
+            )
+            .unwrap(),
+            redis_duration: HistogramVec::new(
+                HistogramOpts::new(
+                    "cms_redis_command_duration_seconds",
+                    "Redis command latency in seconds",
+                ),
+                &["command"],
+            )
+            .unwrap(),
+            redis_errors: IntCounterVec::new(
+                Opts::new("cms_redis_command_errors_total", "Failed Redis commands"),
+                &["command"],
+            )
+            .unwrap(),
+            rabbitmq_published: IntCounterVec::new(
+                Opts::new(
+                    "cms_rabbitmq_published_total",
+                    "Published RabbitMQ messages",
+                ),
+                &["result"],
+            )
+            .unwrap(),
+            rabbitmq_consumed: IntCounterVec::new(
+                Opts::new("cms_rabbitmq_consumed_total", "Consumed RabbitMQ messages"),
+                &["result"],
+            )
+            .unwrap(),
+            logins: IntCounterVec::new(
+                Opts::new("cms_logins_total", "Login attempts"),
+                &["platform", "result"],
+            )
+            .unwrap(),
+            active_sessions: IntGauge::new(
+                "cms_active_sessions",
+                "Sessions whose refresh token has not expired",
+            )
+            .unwrap(),
+            registry,
+        };
+
+        let collectors: Vec<Box<dyn prometheus::core::Collector>> = vec![
+            Box::new(metrics.http_requests.clone()),
+            Box::new(metrics.http_duration.clone()),
+            Box::new(metrics.db_pool_connections.clone()),
+            Box::new(metrics.db_pool_max_connections.clone()),
+            Box::new(metrics.redis_duration.clone()),
+            Box::new(metrics.redis_errors.clone()),
+            Box::new(metrics.rabbitmq_published.clone()),
+            Box::new(metrics.rabbitmq_consumed.clone()),
+            Box::new(metrics.logins.clone()),
+            Box::new(metrics.active_sessions.clone()),
+        ];
+        for collector in collectors {
+            metrics.registry.register(collector).unwrap();
+        }
+        metrics
+    }
+}
+
+lazy_static! {
+    static ref METRICS: Metrics = Metrics::new();
+}
+
+pub struct MetricsService {}
+
+impl MetricsService {
+    /// 记录 HTTP 请求，route 为路由模板
+    pub fn record_http(method: &str, route: &str, status: u16, elapsed: Duration) {
+        let status = status.to_string();
+        let labels = [method, route, status.as_str()];
+        METRICS.http_requests.with_label_values(&labels).inc();
+        METRICS
+            .http_duration
+            .with_label_values(&labels)
+            .observe(elapsed.as_secs_f64());
+    }
+
+    /// 执行 Redis 命令并记录耗时，失败时记录错误数
+    ///
+    /// 命令在 `redis` span 中执行，导出链路追踪时 span 以命令命名。
+    pub fn observe_redis<T>(command: &str, f: impl FnOnce() -> HandleResult<T>) -> HandleResult<T> {
+        let span = info_span!(
+            "redis",
+            otel.name = command,
+            otel.kind = "client",
+            otel.status_code = field::Empty,
+            db.system.name = "redis",
+            db.operation.name = command,
+        );
+        let _entered = span.enter();
+        let timer = METRICS
+            .redis_duration
+            .with_label_values(&[command])
+            .start_timer();
+        let result = f();
+        timer.observe_duration();
+        if result.is_err() {
+            METRICS.redis_errors.with_label_values(&[command]).inc();
+            span.record("otel.status_code", "error");
+        }
+        result
+    }
+
+    /// 记录消息发布结果
+    pub fn record_publish(success: bool) {
+        let result = if success { "success" } else { "failure" };
+        METRICS
+            .rabbitmq_published
+            .with_label_values(&[result])
+            .inc();
+    }
+
+    /// 记录消息消费结果：ack、nack 或 error
+    pub fn record_consume(result: &str) {
+        METRICS.rabbitmq_consumed.with_label_values(&[result]).inc();
+    }
+
+    /// 记录登录结果，platform 为 open 或 manager
+    pub fn record_login(platform: &str, success: bool) {
+        let result = if success { "success" } else { "failure" };
+        METRICS.logins.with_label_values(&[platform, result]).inc();
+    }
+
+    /// 输出 Prometheus 文本格式的指标
+    ///
+    /// 连接池使用情况和有效会话数在输出时读取。
+    pub async fn render(state: Option<&AppState>) -> String {
+        if let Some(state) = state {
+            Self::update_pool("primary", &state.db);
+            for (index, db) in state.db_replicas.iter().enumerate() {
+                Self::update_pool(&format!("replica_{}", index), db);
+            }
+            match Self::active_sessions(&state.db).await {
+                Ok(count) => METRICS.active_sessions.set(count as i64),
+                Err(err) => error!("Failed to count active sessions: {}", err),
+            }
+        }
+
+        let mut buffer = Vec::new();
+        if let Err(err) = TextEncoder::new().encode(&METRICS.registry.gather(), &mut buffer) {
+            error!("Failed to encode metrics: {}", err);
+        }
+        String::from_utf8(buffer).unwrap_or_default()
+    }
+
+    /// 刷新令牌未过期的会话数
+    pub async fn active_sessions(db: &DatabaseConnection) -> HandleResult<u64> {
+        let count = CertificateEntity::find()
+            .filter(CertificateColumn::RefreshExpiredAt.gt(time_utils::current_time()))
+            .count(db)
+            .await?;
+        handle_ok(count)
+    }
+
+    fn update_pool(name: &str, db: &DatabaseConnection) {
+        let Some((size, idle, max)) = pool_stats(db) else {
+            return;
+        };
+        let gauge = &METRICS.db_pool_connections;
+        gauge.with_label_values(&[name, "idle"]).set(idle as i64);
+        gauge
+            .with_label_values(&[name, "in_use"])
+            .set(size as i64 - idle as i64);
+        METRICS
+            .db_pool_max_connections
+            .with_label_values(&[name])
+            .set(max as i64);
+    }
+}
+
+/// 连接池的连接数、空闲连接数和最大连接数
+fn pool_stats(db: &DatabaseConnection) -> Option<(u32, usize, u32)> {
+    match db {
+        DatabaseConnection::SqlxPostgresPoolConnection(_) => {
+            let pool = db.get_postgres_connection_pool();
+            Some((
+                pool.size(),
+                pool.num_idle(),
+                pool.options().get_max_connections(),
+            ))
+        }
+        #[cfg(feature = "mysql")]
+        DatabaseConnection::SqlxMySqlPoolConnection(_) => {
+            let pool = db.get_mysql_connection_pool();
+            Some((
+                pool.size(),
+                pool.num_idle(),
+                pool.options().get_max_connections(),
+            ))
+        }
+        #[cfg(feature = "sqlite")]
+        DatabaseConnection::SqlxSqlitePoolConnection(_) => {
+            let pool = db.get_sqlite_connection_pool();
+            Some((
+                pool.size(),
+                pool.num_idle(),
+                pool.options().get_max_connections(),
+            ))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::AppError;
+    use crate::fixture::config::FakerAppState;
+
+    #[tokio::test]
+    async fn test_render() {
+        MetricsService::record_http("GET", "/core/users/{id}", 200, Duration::from_millis(5));
+        MetricsService::record_login("manager", false);
+        MetricsService::record_publish(true);
+        MetricsService::record_consume("ack");
+        let value = MetricsService::observe_redis("get", || Ok(1)).unwrap();
+        assert_eq!(value, 1);
+        let result: HandleResult<()> =
+            MetricsService::observe_redis("set", || Err(AppError::Redis("down".to_string())));
+        assert!(result.is_err());
+
+        let state = FakerAppState::init().await;
+        let text = MetricsService::render(Some(&state)).await;
+        assert!(text.contains(
+            r#"cms_http_requests_total{method="GET",route="/core/users/{id}",status="200"}"#
+        ));
+        assert!(text.contains("cms_http_request_duration_seconds_bucket"));
+        assert!(text.contains(r#"cms_logins_total{platform="manager",result="failure"}"#));
+        assert!(text.contains(r#"cms_rabbitmq_published_total{result="success"}"#));
+        assert!(text.contains(r#"cms_rabbitmq_consumed_total{result="ack"}"#));
+        assert!(text.contains(r#"cms_redis_command_duration_seconds_count{command="get"}"#));
+        assert!(text.contains(r#"cms_redis_command_errors_total{command="set"} "#));
+        assert!(text.contains("cms_active_sessions "));
+    }
+
+    #[tokio::test]
+    async fn test_active_sessions() {
+        let state = FakerAppState::init().await;
+        let count = MetricsService::active_sessions(&state.db).await.unwrap();
+        let total = CertificateEntity::find().count(&state.db).await.unwrap();
+        assert!(count <= total);
+    }
+}
@@ -1,7 +1,41 @@
+mod audit_service;
+mod cache_service;
+mod cache_store;
+mod config_reload_service;
+mod database_service;
 mod editor_service;
+mod export_service;
+mod health_service;
+mod import_service;
 mod jwt_service;
-mod redis_service;
+mod memory_cache_store;
+mod memory_publisher;
+mod message_publisher;
+mod metrics_service;
+mod rabbitmq_publisher;
+mod redis_cache_store;
+mod resource_service;
+mod shutdown_service;
+mod telemetry_service;
+mod tenant_service;
 
+pub use audit_service::AuditService;
+pub use cache_service::CacheService;
+pub use cache_store::CacheStore;
+pub use config_reload_service::{ConfigReloadService, RELOADABLE_KEYS};
+pub use database_service::DatabaseService;
 pub use editor_service::EditorService;
+pub use export_service::{EXPORT_SYNC_LIMIT, ExportService};
+pub use health_service::HealthService;
+pub use import_service::{IMPORT_ROW_LIMIT, ImportService};
 pub use jwt_service::JwtService;
-pub use redis_service::RedisService;
+pub use memory_cache_store::MemoryCacheStore;
+pub use memory_publisher::{MemoryPublisher, PublishedMessage};
+pub use message_publisher::MessagePublisher;
+pub use metrics_service::{METRICS_CONTENT_TYPE, MetricsService};
+pub use rabbitmq_publisher::RabbitMQPublisher;
+pub use redis_cache_store::RedisCacheStore;
+pub use resource_service::ResourceService;
+pub use shutdown_service::ShutdownService;
+pub use telemetry_service::TelemetryService;
+pub use tenant_service::{HEADER_TENANT_ID, TenantService};
@@ -0,0 +1,114 @@
+use async_trait::async_trait;
+use deadpool_lapin::Pool;
+use deadpool_lapin::lapin::{BasicProperties, options::BasicPublishOptions, types::FieldTable};
+use tracing::{Instrument, error, field, info, info_span};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+use crate::domain::{HandleResult, handle_ok};
+use crate::error::AppError;
+use crate::utils::trace_utils;
+
+use super::{MessagePublisher, MetricsService};
+
+/// RabbitMQ 消息发布
+#[derive(Debug, Clone)]
+pub struct RabbitMQPublisher {
+    pool: Pool,
+}
+
+impl RabbitMQPublisher {
+    pub fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+
+    /// 发送消息并等待确认
+    async fn send(
+        &self,
+        exchange: &str,
+        routing_key: &str,
+        payload: &[u8],
+        headers: FieldTable,
+    ) -> HandleResult<()> {
+        let connection = self.pool.get().await?;
+        let channel = connection.create_channel().await?;
+
+        match channel
+            .basic_publish(
+                exchange,
+                routing_key,
+                BasicPublishOptions::default(),
+                payload,
+                BasicProperties::default().with_headers(headers),
+            )
+            .await
+        {
+            Ok(publish_result) => {
+                if let Err(err) = publish_result.await {
+                    error!("Failed to confirm message publication: {}", err);
+                    return Err(err.into());
+                }
+                info!(
+                    "Message published successfully to exchange '{}' with routing key '{}'",
+                    exchange, routing_key
+                );
+                handle_ok(())
+            }
+            Err(err) => {
+                error!("Failed to publish message: {}", err);
+                Err(err.into())
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl MessagePublisher for RabbitMQPublisher {
+    async fn publish(&self, exchange: &str, routing_key: &str, payload: &[u8]) -> HandleResult<()> {
+        if (exchange.is_empty() && routing_key.is_empty()) || payload.is_empty() {
+            let error = AppError::BadRequest(
+                "Exchange, routing key, and payload must not be empty".to_string(),
+            );
+            return Err(error);
+        }
+
+        // 消息头写入发布 span 的 traceparent，消费者的 span 与发布消息的请求关联
+        let destination = if exchange.is_empty() {
+            routing_key
+        } else {
+            exchange
+        };
+        let span = info_span!(
+            "publish",
+            otel.name = %format!("publish {}", destination),
+            otel.kind = "producer",
+            otel.status_code = field::Empty,
+            messaging.system = "rabbitmq",
+            messaging.operation.type = "send",
+            messaging.destination.name = exchange,
+            messaging.rabbitmq.destination.routing_key = routing_key,
+        );
+        let headers = trace_utils::amqp_headers(&span.context());
+        let result = self
+            .send(exchange, routing_key, payload, headers)
+            .instrument(span.clone())
+            .await;
+        if result.is_err() {
+            span.record("otel.status_code", "error");
+        }
+        MetricsService::record_publish(result.is_ok());
+        result
+    }
+
+    async fn ping(&self) -> HandleResult<()> {
+        let connection = self.pool.get().await?;
+        if !connection.status().connected() {
+            return Err(AppError::Queue("RabbitMQ connection is closed".to_string()));
+        }
+        handle_ok(())
+    }
+
+    async fn close(&self) {
+        self.pool.close();
+        info!("RabbitMQ connection pool closed");
+    }
+}
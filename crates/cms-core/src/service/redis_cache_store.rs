@@ -0,0 +1,108 @@
+use redis::{Client, Commands, Connection, JsonCommands};
+use serde_json::Value as JsonValue;
+use std::time::Duration;
+
+use crate::domain::{HandleResult, handle_ok};
+
+use super::{CacheStore, MetricsService};
+
+/// 检查 Redis 是否可用时的连接超时
+const PING_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Redis 缓存，JSON 使用 RedisJSON 模块
+#[derive(Debug, Clone)]
+pub struct RedisCacheStore {
+    client: Client,
+}
+
+impl RedisCacheStore {
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    fn get_connection(&self) -> HandleResult<Connection> {
+        let con = self.client.get_connection()?;
+        handle_ok(con)
+    }
+}
+
+impl CacheStore for RedisCacheStore {
+    fn set(&self, key: &str, value: &str, ex: Option<u64>) -> HandleResult<()> {
+        MetricsService::observe_redis("set", || {
+            let mut con = self.get_connection()?;
+            match ex {
+                Some(ex) => con.set_ex::<_, _, ()>(key, value, ex)?,
+                None => con.set::<_, _, ()>(key, value)?,
+            }
+            handle_ok(())
+        })
+    }
+
+    fn set_nx(&self, key: &str, value: &str, ex: Option<u64>) -> HandleResult<bool> {
+        MetricsService::observe_redis("set_nx", || {
+            let mut con = self.get_connection()?;
+            let mut cmd = redis::cmd("SET");
+            cmd.arg(key).arg(value).arg("NX");
+            if let Some(ex) = ex {
+                cmd.arg("EX").arg(ex);
+            }
+            let result: Option<String> = cmd.query(&mut con)?;
+            handle_ok(result.is_some())
+        })
+    }
+
+    fn get(&self, key: &str) -> HandleResult<Option<String>> {
+        MetricsService::observe_redis("get", || {
+            let mut con = self.get_connection()?;
+            let value: Option<String> = con.get(key)?;
+            handle_ok(value)
+        })
+    }
+
+    fn del(&self, key: &str) -> HandleResult<()> {
+        MetricsService::observe_redis("del", || {
+            let mut con = self.get_connection()?;
+            con.del::<_, ()>(key)?;
+            handle_ok(())
+        })
+    }
+
+    fn incr(&self, key: &str, delta: i64) -> HandleResult<i64> {
+        MetricsService::observe_redis("incr", || {
+            let mut con = self.get_connection()?;
+            let value: i64 = con.incr(key, delta)?;
+            handle_ok(value)
+        })
+    }
+
+    fn set_json(&self, key: &str, value: &JsonValue) -> HandleResult<()> {
+        MetricsService::observe_redis("set_json", || {
+            let mut con = self.get_connection()?;
+            con.json_set::<_, _, _, ()>(key, "$", value)?;
+            handle_ok(())
+        })
+    }
+
+    fn get_json(&self, key: &str) -> HandleResult<Option<JsonValue>> {
+        MetricsService::observe_redis("get_json", || {
+            let mut con = self.get_connection()?;
+            // 路径 `$` 的查询结果为数组
+            let json: Option<String> = con.json_get(key, "$")?;
+            let value = json
+                .and_then(|json| serde_json::from_str::<JsonValue>(&json).ok())
+                .and_then(|value| match value {
+                    JsonValue::Array(mut list) if !list.is_empty() => Some(list.remove(0)),
+                    _ => None,
+                });
+            handle_ok(value)
+        })
+    }
+
+    fn ping(&self) -> HandleResult<()> {
+        MetricsService::observe_redis("ping", || {
+            let mut con = self.client.get_connection_with_timeout(PING_TIMEOUT)?;
+            redis::cmd("PING").query::<String>(&mut con)?;
+            handle_ok(())
+        })
+    }
+}
@@ -0,0 +1,1853 @@
+use chrono::NaiveDateTime;
+use sea_orm::prelude::Expr;
+use sea_orm::sea_query::SimpleExpr;
+use sea_orm::*;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value as JsonValue, json};
+use std::collections::HashMap;
+use std::future::Future;
+use std::str::FromStr;
+
+use crate::config::AppState;
+use crate::domain::{
+    HandleResult,
+    dto::{
+        AuditQueryDTO, AuditStoreDTO, BulkUpdateDTO, EditorCurrentDTO, FieldBoolUpdateDTO,
+        FieldSetDTO, FieldValueUniqueDTO, ListFilterDTO, ListSortDTO, ModelLogicDeleteDTO,
+        ModelViewDTO, ResourceQueryDTO, ResourceStoreDTO, TenantCurrentDTO,
+    },
+    handle_ok,
+    vo::{AuditVO, BulkResultVO, PaginateResultVO},
+};
+use crate::enums::{
+    AuditActionEnum, EditorTypeEnum, ErrorEnum, FilterOperatorEnum, PlatformEnum, ViewModeEnum,
+};
+use crate::error::AppError;
+use crate::service::{AuditService, CacheService, TenantService};
+use crate::utils::{column_utils, cursor_utils, time_utils};
+
+/// 通用资源 CRUD
+///
+/// 约定实体包含 `id`、`editor_type`、`is_deleted`、`deleted_at`、`created_at`、`updated_at`
+/// 等字段，`version_no`、`is_enabled` 字段存在时自动处理，`tenant_id` 字段存在时按当前租户
+/// 隔离。创建、更新、删除和恢复操作会写入变更历史。
+pub trait ResourceService {
+    /// 实体
+    type Entity: EntityTrait<Model = Self::Model, ActiveModel = Self::ActiveModel, Column = Self::Column>;
+
+    /// 实体 Model
+    type Model: ModelTrait<Entity = Self::Entity>
+        + FromQueryResult
+        + IntoActiveModel<Self::ActiveModel>
+        + Serialize
+        + DeserializeOwned
+        + Sync;
+
+    /// 实体 ActiveModel
+    type ActiveModel: ActiveModelTrait<Entity = Self::Entity>
+        + ActiveModelBehavior
+        + From<Self::Model>
+        + TryIntoModel<Self::Model>
+        + Send
+        + Sync;
+
+    /// 实体列
+    type Column: ColumnTrait + FromStr;
+
+    /// 创建/更新 DTO
+    type StoreDTO: ResourceStoreDTO + Sync;
+
+    /// 分页查询 DTO
+    type QueryDTO: ResourceQueryDTO<Self::LoadEnum> + Sync;
+
+    /// 关联加载枚举
+    type LoadEnum: Clone + PartialEq + Sync;
+
+    /// 主 VO
+    type MasterVO: Serialize + Send + Default;
+
+    /// 查询选项 VO
+    type QueryOptionVO: Send;
+
+    /// 表单选项 VO
+    type FormOptionVO: Send;
+
+    // ------------------------------------------------------------------------
+    // 必须实现的方法
+    // ------------------------------------------------------------------------
+
+    /// 主键列
+    fn id_column() -> Self::Column;
+
+    /// 根据 DTO 填充待保存的 Model，`origin` 为更新前的记录
+    fn fill_store_model(
+        platform: &PlatformEnum,
+        dto: &Self::StoreDTO,
+        model: &mut Self::ActiveModel,
+        origin: Option<&Self::Model>,
+        state: &AppState,
+    ) -> impl Future<Output = HandleResult<()>> + Send;
+
+    /// 构建列表查询器
+    fn query_builder(
+        platform: &PlatformEnum,
+        dto: &Self::QueryDTO,
+    ) -> HandleResult<Select<Self::Entity>>;
+
+    /// Model 转换为主 VO
+    fn master_vo(
+        view_enum: &ViewModeEnum,
+        editor: &EditorCurrentDTO,
+        model: &Self::Model,
+    ) -> Self::MasterVO;
+
+    /// 查询选项
+    fn query_options(
+        platform: &PlatformEnum,
+        state: &AppState,
+    ) -> impl Future<Output = HandleResult<Self::QueryOptionVO>> + Send;
+
+    /// 表单选项
+    fn form_options(
+        platform: &PlatformEnum,
+        state: &AppState,
+    ) -> impl Future<Output = HandleResult<Self::FormOptionVO>> + Send;
+
+    // ------------------------------------------------------------------------
+    // 可覆盖的钩子
+    // ------------------------------------------------------------------------
+
+    /// 变更历史中的实体类型，默认为表名
+    fn audit_entity_type() -> String {
+        Self::Entity::default().table_name().to_string()
+    }
+
+    /// 变更历史中额外忽略的字段，如密码等敏感字段
+    fn audit_skip_columns() -> &'static [&'static str] {
+        &[]
+    }
+
+    /// 根据字段名查询列
+    fn column(name: &str) -> Option<Self::Column> {
+        Self::Column::from_str(name).ok()
+    }
+
+    /// 需要唯一性校验的列及重复时的错误
+    fn unique_columns() -> Vec<(Self::Column, AppError)> {
+        vec![]
+    }
+
+    /// 唯一性校验接口允许的字段名
+    fn unique_column(field_name: &str) -> Option<Self::Column> {
+        let field_name = field_name.to_lowercase();
+        Self::unique_columns()
+            .into_iter()
+            .map(|(column, _)| column)
+            .find(|column| column.as_str() == field_name)
+    }
+
+    /// 唯一性校验的查询范围
+    fn unique_scope(
+        query: Select<Self::Entity>,
+        _column: &Self::Column,
+        _extends: &HashMap<String, String>,
+    ) -> Select<Self::Entity> {
+        query
+    }
+
+    /// 保存时唯一性校验的扩展参数
+    fn store_unique_extends(_model: &Self::ActiveModel) -> HashMap<String, String> {
+        HashMap::new()
+    }
+
+    /// 游标分页的排序列和方向，列值不能为空，相同值时按主键排序
+    fn cursor_sort() -> (Self::Column, Order) {
+        (Self::id_column(), Order::Desc)
+    }
+
+    /// 列表允许排序的列
+    fn sort_columns() -> Vec<Self::Column> {
+        vec![Self::id_column()]
+    }
+
+    /// 列表允许筛选的列及操作符
+    fn filter_columns() -> Vec<(Self::Column, Vec<FilterOperatorEnum>)> {
+        vec![]
+    }
+
+    /// 允许修改的布尔字段
+    fn bool_column(field_name: &str) -> Option<Self::Column> {
+        match field_name.to_lowercase().as_str() {
+            "is_enabled" | "enabled" => Self::column("is_enabled"),
+            _ => None,
+        }
+    }
+
+    /// 批量移动允许修改的列
+    fn move_column(_field_name: &str) -> Option<Self::Column> {
+        None
+    }
+
+    /// 批量移动前检查目标记录，返回需要修改的列和值，如同时修改类型所属的 App
+    fn move_check(
+        column: Self::Column,
+        value: i64,
+        _state: &AppState,
+    ) -> impl Future<Output = HandleResult<Vec<(Self::Column, Value)>>> + Send {
+        async move {
+            let value =
+                column_utils::integer_to_column_value(column.def().get_column_type(), value)
+                    .ok_or_else(|| Into::<AppError>::into(ErrorEnum::UpdateFieldInvalid))?;
+            handle_ok(vec![(column, value)])
+        }
+    }
+
+    /// 是否可以移动记录，如不能移动到自身或自身的下级
+    fn can_move(
+        _model: &Self::Model,
+        _column: Self::Column,
+        _value: i64,
+        _state: &AppState,
+    ) -> impl Future<Output = HandleResult<bool>> + Send {
+        async { handle_ok(true) }
+    }
+
+    /// 管理端默认加载的关联数据
+    fn manager_load_models(_view_enum: &ViewModeEnum) -> Vec<Self::LoadEnum> {
+        vec![]
+    }
+
+    /// `include` 参数允许加载的关联数据
+    fn include_options() -> Vec<(&'static str, Self::LoadEnum)> {
+        vec![]
+    }
+
+    /// 字段裁剪时始终查询的列，如加载关联数据的外键和权限判断用到的列
+    fn relation_columns() -> Vec<Self::Column> {
+        vec![]
+    }
+
+    /// 导入时当前行按名称引用的本次导入中的记录键，如父级路径
+    fn import_reference(
+        _dto: &Self::StoreDTO,
+        _record: &HashMap<String, String>,
+    ) -> Option<String> {
+        None
+    }
+
+    /// 导入时当前行的记录键，供后续行引用，本次导入中重复时该行失败
+    fn import_key(_dto: &Self::StoreDTO, _record: &HashMap<String, String>) -> Option<String> {
+        None
+    }
+
+    /// 导入时解析按名称引用的关联数据，`reference_id` 为 `import_reference` 对应记录的ID，
+    /// 试运行时为 0
+    fn import_resolve(
+        _dto: &mut Self::StoreDTO,
+        _record: &HashMap<String, String>,
+        _reference_id: Option<i64>,
+        _state: &AppState,
+    ) -> impl Future<Output = HandleResult<()>> + Send {
+        async { handle_ok(()) }
+    }
+
+    /// 用户端是否可见
+    fn is_open_visible(model: &Self::Model) -> bool {
+        match Self::column("is_enabled") {
+            Some(column) => model.get(column) == Value::Bool(Some(true)),
+            None => true,
+        }
+    }
+
+    /// 是否可以更新记录
+    fn can_update(_editor: &EditorCurrentDTO, _model: &Self::Model) -> bool {
+        true
+    }
+
+    /// 是否可以删除记录
+    fn can_delete(editor: &EditorCurrentDTO, _model: &Self::Model) -> bool {
+        editor.editor_type == EditorTypeEnum::Admin
+    }
+
+    /// 是否可以从回收站恢复记录
+    fn can_restore(editor: &EditorCurrentDTO, _model: &Self::Model) -> bool {
+        editor.editor_type == EditorTypeEnum::Admin
+    }
+
+    /// 是否可以彻底删除记录
+    fn can_purge(editor: &EditorCurrentDTO, _model: &Self::Model) -> bool {
+        editor.editor_type == EditorTypeEnum::Admin
+    }
+
+    /// 恢复前的检查，如父级记录是否已删除
+    fn restore_check(
+        _model: &Self::Model,
+        _state: &AppState,
+    ) -> impl Future<Output = HandleResult<()>> + Send {
+        async { handle_ok(()) }
+    }
+
+    /// 加载关联数据
+    fn load_relations(
+        _list: &mut [Self::MasterVO],
+        _load_models: &[Self::LoadEnum],
+        _state: &AppState,
+    ) -> impl Future<Output = HandleResult<()>> + Send {
+        async { handle_ok(()) }
+    }
+
+    /// 保存后的处理（同一事务内），如更新父级计数，回滚历史版本时 `dto` 为空
+    fn after_store(
+        _dto: Option<&Self::StoreDTO>,
+        _model: &Self::Model,
+        _origin: Option<&Self::Model>,
+        _txn: &DatabaseTransaction,
+    ) -> impl Future<Output = HandleResult<()>> + Send {
+        async { handle_ok(()) }
+    }
+
+    /// 删除后的处理（同一事务内），如更新父级计数
+    fn after_delete(
+        _model: &Self::Model,
+        _txn: &DatabaseTransaction,
+    ) -> impl Future<Output = HandleResult<()>> + Send {
+        async { handle_ok(()) }
+    }
+
+    /// 恢复后的处理（同一事务内），默认与删除后相同，重新计算父级计数
+    fn after_restore(
+        model: &Self::Model,
+        txn: &DatabaseTransaction,
+    ) -> impl Future<Output = HandleResult<()>> + Send {
+        Self::after_delete(model, txn)
+    }
+
+    /// 批量删除、恢复、移动后的处理（同一事务内），只调用一次，默认逐条调用删除后的处理
+    fn after_bulk(
+        models: &[Self::Model],
+        origins: &[Self::Model],
+        txn: &DatabaseTransaction,
+    ) -> impl Future<Output = HandleResult<()>> + Send {
+        async move {
+            for model in origins.iter().chain(models) {
+                Self::after_delete(model, txn).await?;
+            }
+            handle_ok(())
+        }
+    }
+
+    /// 彻底删除后的处理（同一事务内），如删除附属记录
+    fn after_purge(
+        _model: &Self::Model,
+        _txn: &DatabaseTransaction,
+    ) -> impl Future<Output = HandleResult<()>> + Send {
+        async { handle_ok(()) }
+    }
+
+    // ------------------------------------------------------------------------
+    // 通用实现
+    // ------------------------------------------------------------------------
+
+    /// 构建查询器，存在 `tenant_id` 字段时限制为当前租户
+    fn scope_active_query() -> Select<Self::Entity> {
+        let query = TenantService::scope_query(Self::Entity::find(), Self::column("tenant_id"));
+        match Self::column("is_deleted") {
+            Some(column) => query.filter(column.eq(false)),
+            None => query,
+        }
+    }
+
+    /// 构建回收站查询器
+    fn scope_trash_query() -> Select<Self::Entity> {
+        let query = TenantService::scope_query(Self::Entity::find(), Self::column("tenant_id"));
+        match Self::column("is_deleted") {
+            Some(column) => query.filter(column.eq(true)),
+            None => query.filter(Expr::value(false)),
+        }
+    }
+
+    /// 根据ID查询
+    fn fetch_by_id(
+        id: i64,
+        state: &AppState,
+    ) -> impl Future<Output = HandleResult<Self::Model>> + Send {
+        async move {
+            if id < 1 {
+                return Err(ErrorEnum::ParamIdInvalid.into());
+            }
+
+            let db = &state.db;
+            let model = Self::scope_active_query()
+                .filter(Self::id_column().eq(id))
+                .one(db)
+                .await?
+                .ok_or_else(|| Into::<AppError>::into(ErrorEnum::RecordNotFound))?;
+
+            handle_ok(model)
+        }
+    }
+
+    /// 根据ID查询回收站中的记录
+    fn fetch_trash_by_id(
+        id: i64,
+        state: &AppState,
+    ) -> impl Future<Output = HandleResult<Self::Model>> + Send {
+        async move {
+            if id < 1 {
+                return Err(ErrorEnum::ParamIdInvalid.into());
+            }
+
+            let db = &state.db;
+            let model = Self::scope_trash_query()
+                .filter(Self::id_column().eq(id))
+                .one(db)
+                .await?
+                .ok_or_else(|| Into::<AppError>::into(ErrorEnum::RecordNotFound))?;
+
+            handle_ok(model)
+        }
+    }
+
+    /// 根据ID查询，包括回收站中的记录
+    fn fetch_with_trash_by_id(
+        id: i64,
+        state: &AppState,
+    ) -> impl Future<Output = HandleResult<Self::Model>> + Send {
+        async move {
+            if id < 1 {
+                return Err(ErrorEnum::ParamIdInvalid.into());
+            }
+
+            let db = &state.db;
+            let model = TenantService::scope_query(Self::Entity::find(), Self::column("tenant_id"))
+                .filter(Self::id_column().eq(id))
+                .one(db)
+                .await?
+                .ok_or_else(|| Into::<AppError>::into(ErrorEnum::RecordNotFound))?;
+
+            handle_ok(model)
+        }
+    }
+
+    /// 检查字段值是否唯一（查询）
+    fn is_column_exist<C: ConnectionTrait>(
+        id: i64,
+        column: Self::Column,
+        value: Value,
+        extends: &HashMap<String, String>,
+        db: &C,
+    ) -> impl Future<Output = HandleResult<bool>> + Send {
+        async move {
+            let id_column = Self::id_column();
+            let mut query = Self::scope_active_query()
+                .select_only()
+                .column(id_column)
+                .filter(column.eq(value));
+            query = Self::unique_scope(query, &column, extends);
+            if id > 0 {
+                query = query.filter(id_column.ne(id));
+            }
+            let count = query.count(db).await?;
+
+            handle_ok(count > 0)
+        }
+    }
+
+    /// 检查字段值是否唯一
+    fn field_unique(
+        dto: &FieldValueUniqueDTO,
+        state: &AppState,
+    ) -> impl Future<Output = HandleResult<bool>> + Send {
+        async move {
+            let column = Self::unique_column(&dto.field_name)
+                .ok_or_else(|| Into::<AppError>::into(ErrorEnum::FieldInvalid))?;
+
+            let id = dto.skip_id.active_int_id().unwrap_or(0);
+            let value = Value::from(dto.field_value.to_owned());
+            let extends = dto.extends.clone().unwrap_or_default();
+            let exist = Self::is_column_exist(id, column, value, &extends, &state.db).await?;
+            handle_ok(!exist)
+        }
+    }
+
+    /// 创建/更新
+    fn store(
+        platform: &PlatformEnum,
+        dto: &Self::StoreDTO,
+        state: &AppState,
+    ) -> impl Future<Output = HandleResult<Self::Model>> + Send {
+        async move {
+            let id = dto.id();
+            let is_create = id < 1;
+            let editor = dto.editor();
+
+            let origin = if is_create {
+                None
+            } else {
+                let model = Self::fetch_by_id(id, state).await?;
+                if !Self::can_update(editor, &model) {
+                    return Err(ErrorEnum::NoPermissionUpdate.into());
+                }
+                Some(model)
+            };
+            let mut model: Self::ActiveModel = match origin {
+                Some(ref model) => model.clone().into(),
+                None => <Self::ActiveModel as ActiveModelTrait>::default(),
+            };
+
+            // 检查版本号
+            if let (Some(column), Some(version_no), Some(model)) = (
+                Self::column("version_no"),
+                dto.version_no(),
+                origin.as_ref(),
+            ) {
+                let current_version_no = version_no_value(model.get(column));
+                if version_no != current_version_no {
+                    return Err(AppError::Conflict(current_version_no));
+                }
+            }
+
+            Self::fill_store_model(platform, dto, &mut model, origin.as_ref(), state).await?;
+
+            let action = if is_create {
+                AuditActionEnum::Create
+            } else {
+                AuditActionEnum::Update
+            };
+            Self::persist(action, Some(dto), model, origin.as_ref(), editor, state).await
+        }
+    }
+
+    /// 保存 Model：递增版本号、唯一性校验、写入时间和编辑用户，并在同一事务内执行
+    /// `after_store` 和写入变更历史
+    fn persist(
+        action: AuditActionEnum,
+        dto: Option<&Self::StoreDTO>,
+        model: Self::ActiveModel,
+        origin: Option<&Self::Model>,
+        editor: &EditorCurrentDTO,
+        state: &AppState,
+    ) -> impl Future<Output = HandleResult<Self::Model>> + Send {
+        async move {
+            let txn = state.db.begin().await?;
+            let model = Self::persist_in(action, dto, model, origin, editor, &txn).await?;
+            txn.commit().await?;
+            CacheService::touch_cache_version(state.cache.as_ref());
+
+            handle_ok(model)
+        }
+    }
+
+    /// 在指定事务内保存 Model，见 `persist`
+    fn persist_in(
+        action: AuditActionEnum,
+        dto: Option<&Self::StoreDTO>,
+        mut model: Self::ActiveModel,
+        origin: Option<&Self::Model>,
+        editor: &EditorCurrentDTO,
+        txn: &DatabaseTransaction,
+    ) -> impl Future<Output = HandleResult<Self::Model>> + Send {
+        async move {
+            let id = origin.map_or(0, primary_id::<Self>);
+            if let Some(column) = Self::column("version_no") {
+                let current_version_no =
+                    origin.map_or(0, |model| version_no_value(model.get(column)));
+                model.set(column, Value::from(current_version_no + 1));
+            }
+
+            if origin.is_none() {
+                set_tenant_column::<Self>(&mut model);
+            }
+            Self::check_unique(id, &model, txn).await?;
+
+            let time = time_utils::current_time();
+            if let Some(column) = Self::column("updated_at") {
+                model.set(column, Value::from(time));
+            }
+            if origin.is_none()
+                && let Some(column) = Self::column("created_at")
+            {
+                model.set(column, Value::from(time));
+            }
+            set_editor_columns::<Self>(&mut model, editor);
+
+            let model = Self::save_model(model, origin, txn).await?;
+            Self::after_store(dto, &model, origin, txn).await?;
+            Self::write_audit(action, &model, origin, editor, txn).await?;
+
+            handle_ok(model)
+        }
+    }
+
+    /// 唯一性校验，返回第一个重复列对应的错误，在记录所属的租户内校验
+    fn check_unique<C: ConnectionTrait>(
+        id: i64,
+        model: &Self::ActiveModel,
+        db: &C,
+    ) -> impl Future<Output = HandleResult<()>> + Send {
+        async move {
+            match Self::duplicate_error(id, model, db).await? {
+                Some(err) => Err(err),
+                None => handle_ok(()),
+            }
+        }
+    }
+
+    /// 查询第一个重复列对应的错误，只校验已修改的唯一列
+    fn duplicate_error<C: ConnectionTrait>(
+        id: i64,
+        model: &Self::ActiveModel,
+        db: &C,
+    ) -> impl Future<Output = HandleResult<Option<AppError>>> + Send {
+        async move {
+            let extends = Self::store_unique_extends(model);
+            let tenant = Self::column("tenant_id")
+                .and_then(|column| model.get(column).into_value())
+                .and_then(|value| match value {
+                    Value::BigInt(Some(tenant_id)) => Some(TenantCurrentDTO::new(tenant_id)),
+                    _ => None,
+                });
+            for (column, err) in Self::unique_columns() {
+                if let ActiveValue::Set(value) = model.get(column) {
+                    let exist = Self::is_column_exist(id, column, value, &extends, db);
+                    let exist = match tenant {
+                        Some(tenant) => TenantService::scope(tenant, exist).await?,
+                        None => exist.await?,
+                    };
+                    if exist {
+                        return handle_ok(Some(err));
+                    }
+                }
+            }
+
+            handle_ok(None)
+        }
+    }
+
+    /// 保存 ActiveModel，更新时以 `version_no` 作为条件原子更新，记录已被修改时返回版本冲突
+    fn save_model<C: ConnectionTrait>(
+        model: Self::ActiveModel,
+        origin: Option<&Self::Model>,
+        db: &C,
+    ) -> impl Future<Output = HandleResult<Self::Model>> + Send {
+        async move {
+            let (origin, column) = match (origin, Self::column("version_no")) {
+                (Some(origin), Some(column)) => (origin, column),
+                _ => {
+                    let model = model.save(db).await?;
+                    return handle_ok(model.try_into_model()?);
+                }
+            };
+
+            let condition = match origin.get(column) {
+                Value::Int(Some(version_no)) => column.eq(version_no),
+                _ => column.is_null(),
+            };
+            match Self::Entity::update(model).filter(condition).exec(db).await {
+                Ok(model) => handle_ok(model),
+                Err(DbErr::RecordNotUpdated) => {
+                    let current = Self::Entity::find()
+                        .filter(Self::id_column().eq(primary_id::<Self>(origin)))
+                        .one(db)
+                        .await?
+                        .ok_or_else(|| Into::<AppError>::into(ErrorEnum::RecordNotFound))?;
+                    Err(AppError::Conflict(version_no_value(current.get(column))))
+                }
+                Err(err) => Err(err.into()),
+            }
+        }
+    }
+
+    /// 对比两个版本的字段，忽略 `AUDIT_SKIP_COLUMNS` 和 `audit_skip_columns` 中的字段
+    fn audit_changes(origin: Option<&Self::Model>, model: &Self::Model) -> JsonValue {
+        let origin = origin.and_then(|model| serde_json::to_value(model).ok());
+        let current = serde_json::to_value(model).unwrap_or_default();
+        json_changes(origin.as_ref(), &current, Self::audit_skip_columns())
+    }
+
+    /// `audit_skip_columns` 中的字段是否有变化
+    fn audit_skip_changed(origin: Option<&Self::Model>, model: &Self::Model) -> bool {
+        let origin = origin.and_then(|model| serde_json::to_value(model).ok());
+        let current = serde_json::to_value(model).unwrap_or_default();
+        Self::audit_skip_columns().iter().any(|name| {
+            let old_value = origin.as_ref().and_then(|origin| origin.get(name));
+            old_value != current.get(name)
+        })
+    }
+
+    /// 写入变更历史，更新操作没有字段变化时不写入
+    ///
+    /// 只有 `audit_skip_columns` 中的字段变化时（如修改密码）仍写入，但不记录字段。
+    fn write_audit<C: ConnectionTrait>(
+        action: AuditActionEnum,
+        model: &Self::Model,
+        origin: Option<&Self::Model>,
+        editor: &EditorCurrentDTO,
+        db: &C,
+    ) -> impl Future<Output = HandleResult<()>> + Send {
+        async move {
+            let changes = Self::audit_changes(origin, model);
+            if action == AuditActionEnum::Update
+                && changes.as_object().is_some_and(Map::is_empty)
+                && !Self::audit_skip_changed(origin, model)
+            {
+                return handle_ok(());
+            }
+
+            let dto = AuditStoreDTO {
+                entity_type: Self::audit_entity_type(),
+                entity_id: primary_id::<Self>(model),
+                action,
+                editor: editor.clone(),
+                version_no: Self::column("version_no")
+                    .map_or(0, |column| version_no_value(model.get(column))),
+                changes,
+            };
+            AuditService::store(&dto, db).await?;
+
+            handle_ok(())
+        }
+    }
+
+    /// 修改布尔值字段
+    fn update_bool_field(
+        dto: &FieldBoolUpdateDTO,
+        state: &AppState,
+    ) -> impl Future<Output = HandleResult<bool>> + Send {
+        async move {
+            let column = Self::bool_column(&dto.field_name)
+                .ok_or_else(|| Into::<AppError>::into(ErrorEnum::UpdateFieldInvalid))?;
+            let bool_value = dto
+                .field_value
+                .ok_or_else(|| Into::<AppError>::into(ErrorEnum::UpdateFieldInvalid))?;
+
+            let model = Self::fetch_by_id(dto.id, state).await?;
+            let editor = &dto.editor;
+            if !Self::can_update(editor, &model) {
+                return Err(ErrorEnum::NoPermissionUpdate.into());
+            }
+            if let (Some(column), Some(version_no)) = (Self::column("version_no"), dto.version_no) {
+                let current_version_no = version_no_value(model.get(column));
+                if version_no != current_version_no {
+                    return Err(AppError::Conflict(current_version_no));
+                }
+            }
+
+            let mut active: Self::ActiveModel = model.clone().into();
+            active.set(column, Value::from(bool_value));
+            Self::persist(
+                AuditActionEnum::Update,
+                None,
+                active,
+                Some(&model),
+                editor,
+                state,
+            )
+            .await?;
+
+            handle_ok(true)
+        }
+    }
+
+    /// 根据 `include` 和 `fields` 参数确定加载的关联数据，均未指定时使用管理端默认值
+    fn resolve_load_models(
+        view_enum: &ViewModeEnum,
+        includes: Option<&[String]>,
+        fields: &FieldSetDTO,
+    ) -> HandleResult<Vec<Self::LoadEnum>> {
+        if includes.is_none() && fields.is_empty() {
+            return handle_ok(Self::manager_load_models(view_enum));
+        }
+
+        let options = Self::include_options();
+        let find = |name: &str| {
+            options
+                .iter()
+                .find(|(option, _)| *option == name)
+                .map(|(_, load)| load.clone())
+        };
+        let mut list: Vec<Self::LoadEnum> = Vec::new();
+        for name in includes.unwrap_or_default() {
+            let load =
+                find(name).ok_or_else(|| Into::<AppError>::into(ErrorEnum::IncludeInvalid))?;
+            if !list.contains(&load) {
+                list.push(load);
+            }
+        }
+        // fields 中引用的关联数据自动加载
+        for name in fields.relations() {
+            let load =
+                find(name).ok_or_else(|| Into::<AppError>::into(ErrorEnum::IncludeInvalid))?;
+            if !list.contains(&load) {
+                list.push(load);
+            }
+        }
+        // 返回字段须为主 VO 的字段或关联数据
+        let vo = serde_json::to_value(Self::MasterVO::default()).unwrap_or_default();
+        for name in fields.roots() {
+            if vo.get(name).is_none() && find(name).is_none() {
+                return Err(ErrorEnum::FieldInvalid.into());
+            }
+        }
+
+        handle_ok(list)
+    }
+
+    /// 只查询返回字段相关的列，其余列使用占位值，字段按 `_time`→`_at`、`_url`→`_path` 对应到列
+    fn select_fields(query: Select<Self::Entity>, fields: &FieldSetDTO) -> Select<Self::Entity> {
+        if fields.is_empty() {
+            return query;
+        }
+
+        let mut names: Vec<String> = [
+            "id",
+            "editor_type",
+            "editor_id",
+            "version_no",
+            "is_enabled",
+            "is_deleted",
+        ]
+        .iter()
+        .map(|name| String::from(*name))
+        .collect();
+        names.push(Self::cursor_sort().0.as_str().to_string());
+        names.extend(
+            Self::relation_columns()
+                .iter()
+                .map(|column| column.as_str().to_string()),
+        );
+        for field in fields.roots() {
+            names.push(field.to_string());
+            names.push(field.replace("_time", "_at"));
+            names.push(field.replace("_url", "_path"));
+        }
+
+        let mut query = query.select_only();
+        for column in Self::Column::iter() {
+            let def = column.def();
+            let placeholder = if names.iter().any(|name| name == column.as_str()) {
+                None
+            } else {
+                column_utils::column_placeholder(def.get_column_type(), def.is_null())
+            };
+            query = match placeholder {
+                Some(value) => query.column_as(Expr::value(value), column.as_str()),
+                None => query.column(column),
+            };
+        }
+        query
+    }
+
+    /// 查看
+    fn view(
+        platform: &PlatformEnum,
+        dto: &ModelViewDTO<Self::LoadEnum>,
+        state: &AppState,
+    ) -> impl Future<Output = HandleResult<Self::MasterVO>> + Send {
+        async move {
+            let id = dto
+                .id
+                .active_int_id()
+                .ok_or_else(|| Into::<AppError>::into(ErrorEnum::ParamIdInvalid))?;
+            let mut query = Self::scope_active_query().filter(Self::id_column().eq(id));
+            if let Some(ref fields) = dto.fields {
+                query = Self::select_fields(query, fields);
+            }
+            let model = query
+                .one(state.db_read())
+                .await?
+                .ok_or_else(|| Into::<AppError>::into(ErrorEnum::RecordNotFound))?;
+
+            let view_enum = ViewModeEnum::platform_to_detail_mode(platform);
+            if view_enum == ViewModeEnum::OpenDetail && !Self::is_open_visible(&model) {
+                return Err(ErrorEnum::RecordNotFound.into());
+            }
+
+            let mut list = vec![Self::master_vo(&view_enum, &dto.editor, &model)];
+            if let Some(ref load_models) = dto.load_models {
+                Self::load_relations(&mut list, load_models, state).await?;
+            }
+
+            let vo = list.pop().ok_or(AppError::Internal)?;
+            handle_ok(vo)
+        }
+    }
+
+    /// 分页查询
+    fn paginage(
+        platform: &PlatformEnum,
+        dto: &Self::QueryDTO,
+        state: &AppState,
+    ) -> impl Future<Output = HandleResult<PaginateResultVO<Self::MasterVO>>> + Send {
+        async move {
+            let db = state.db_read();
+            let page = dto.page();
+            let page_size = dto.page_size();
+
+            let editor = dto.editor();
+            let view_enum = ViewModeEnum::platform_to_list_mode(platform);
+
+            // 游标只记录默认排序列的值，不能与指定的排序一起使用
+            if dto.cursor().is_some() && !dto.sorts().is_empty() {
+                return Err(ErrorEnum::CursorWithSort.into());
+            }
+
+            let query = Self::list_query(platform, dto)?;
+            let (models, total, next_cursor) = match dto.cursor() {
+                Some(cursor) => {
+                    let (models, next_cursor) =
+                        Self::fetch_cursor_page(query, cursor, page_size, db).await?;
+                    (models, None, next_cursor)
+                }
+                None => {
+                    let paginator = query.paginate(db, page_size);
+                    let total = if dto.with_total() {
+                        Some(paginator.num_items().await?)
+                    } else {
+                        None
+                    };
+                    let models = paginator.fetch_page(page - 1).await?;
+                    (models, total, None)
+                }
+            };
+            let mut list: Vec<Self::MasterVO> = models
+                .iter()
+                .map(|model| Self::master_vo(&view_enum, editor, model))
+                .collect();
+
+            if let Some(load_models) = dto.load_models() {
+                Self::load_relations(&mut list, load_models, state).await?;
+            }
+
+            let vo = PaginateResultVO {
+                page_size,
+                current_page: page,
+                total,
+                next_cursor,
+                list,
+            };
+
+            handle_ok(vo)
+        }
+    }
+
+    /// 列表查询：查询条件、排序筛选及返回字段，分页和导出共用
+    fn list_query(
+        platform: &PlatformEnum,
+        dto: &Self::QueryDTO,
+    ) -> HandleResult<Select<Self::Entity>> {
+        let query = Self::query_builder(platform, dto)?;
+        let mut query = Self::apply_list_query(query, dto.sorts(), dto.filters())?;
+        if let Some(fields) = dto.fields() {
+            query = Self::select_fields(query, fields);
+        }
+        handle_ok(query)
+    }
+
+    /// 应用列表排序和筛选参数，字段或操作符不在白名单内时返回错误
+    fn apply_list_query(
+        mut query: Select<Self::Entity>,
+        sorts: &[ListSortDTO],
+        filters: &[ListFilterDTO],
+    ) -> HandleResult<Select<Self::Entity>> {
+        if !filters.is_empty() {
+            let invalid = || Into::<AppError>::into(ErrorEnum::FilterInvalid);
+            let columns = Self::filter_columns();
+            let mut condition = Condition::all();
+            for filter in filters {
+                let (column, operators) = columns
+                    .iter()
+                    .find(|(column, _)| column.as_str() == filter.field)
+                    .ok_or_else(invalid)?;
+                let operator = filter
+                    .operator
+                    .as_ref()
+                    .filter(|operator| operators.contains(operator))
+                    .ok_or_else(invalid)?;
+                let expr = filter_expr(*column, operator, &filter.value).ok_or_else(invalid)?;
+                condition = condition.add(expr);
+            }
+            query = query.filter(condition);
+        }
+
+        if !sorts.is_empty() {
+            let columns = Self::sort_columns();
+            QueryTrait::query(&mut query).clear_order_by();
+            for sort in sorts {
+                let column = columns
+                    .iter()
+                    .find(|column| column.as_str() == sort.field)
+                    .ok_or_else(|| Into::<AppError>::into(ErrorEnum::SortInvalid))?;
+                let order = if sort.desc { Order::Desc } else { Order::Asc };
+                query = query.order_by(*column, order);
+            }
+            query = query.order_by_desc(Self::id_column());
+        }
+
+        handle_ok(query)
+    }
+
+    /// 游标分页：按 (排序列, 主键) 排序，返回当前页记录和下一页游标
+    fn fetch_cursor_page(
+        mut query: Select<Self::Entity>,
+        cursor: &str,
+        page_size: u64,
+        db: &DatabaseConnection,
+    ) -> impl Future<Output = HandleResult<(Vec<Self::Model>, Option<String>)>> + Send {
+        async move {
+            let (column, order) = Self::cursor_sort();
+            let id_column = Self::id_column();
+
+            if !cursor.is_empty() {
+                let invalid = || Into::<AppError>::into(ErrorEnum::CursorInvalid);
+                let (key, id) = cursor_utils::decode_cursor(cursor).ok_or_else(invalid)?;
+                let column_type = column.def().get_column_type().clone();
+                let value =
+                    cursor_utils::json_to_column_value(&column_type, &key).ok_or_else(invalid)?;
+                let condition = match order {
+                    Order::Asc => Condition::any()
+                        .add(column.gt(value.clone()))
+                        .add(Condition::all().add(column.eq(value)).add(id_column.gt(id))),
+                    _ => Condition::any()
+                        .add(column.lt(value.clone()))
+                        .add(Condition::all().add(column.eq(value)).add(id_column.lt(id))),
+                };
+                query = query.filter(condition);
+            }
+
+            QueryTrait::query(&mut query).clear_order_by();
+            let mut models = query
+                .order_by(column, order.clone())
+                .order_by(id_column, order)
+                .limit(page_size + 1)
+                .all(db)
+                .await?;
+
+            let mut next_cursor = None;
+            if models.len() as u64 > page_size {
+                models.truncate(page_size as usize);
+                if let Some(model) = models.last() {
+                    let json = serde_json::to_value(model).unwrap_or_default();
+                    let key = json.get(column.as_str()).cloned().unwrap_or_default();
+                    next_cursor =
+                        Some(cursor_utils::encode_cursor(&key, primary_id::<Self>(model)));
+                }
+            }
+
+            handle_ok((models, next_cursor))
+        }
+    }
+
+    /// 软删除记录
+    fn logic_delete(
+        dto: &ModelLogicDeleteDTO,
+        state: &AppState,
+    ) -> impl Future<Output = HandleResult<()>> + Send {
+        async move {
+            let id = match dto.id.active_int_id() {
+                Some(id) => id,
+                None => return handle_ok(()),
+            };
+
+            let model = match Self::fetch_by_id(id, state).await {
+                Ok(model) => model,
+                Err(_) => return handle_ok(()),
+            };
+            let editor = &dto.editor;
+            if !Self::can_delete(editor, &model) {
+                return Err(ErrorEnum::NoPermissionDelete.into());
+            }
+
+            let origin = model;
+            let mut model: Self::ActiveModel = origin.clone().into();
+            if let Some(column) = Self::column("is_deleted") {
+                model.set(column, Value::from(true));
+            }
+            let time = time_utils::current_time();
+            if let Some(column) = Self::column("deleted_at") {
+                model.set(column, Value::from(time));
+            }
+            if let Some(column) = Self::column("version_no") {
+                let version_no = version_no_value(origin.get(column));
+                model.set(column, Value::from(version_no + 1));
+            }
+            if let Some(column) = Self::column("updated_at") {
+                model.set(column, Value::from(time));
+            }
+            set_editor_columns::<Self>(&mut model, editor);
+
+            let txn = state.db.begin().await?;
+            let model = Self::save_model(model, Some(&origin), &txn).await?;
+            Self::after_delete(&model, &txn).await?;
+            Self::write_audit(AuditActionEnum::Delete, &model, Some(&origin), editor, &txn).await?;
+            txn.commit().await?;
+            CacheService::touch_cache_version(state.cache.as_ref());
+
+            handle_ok(())
+        }
+    }
+
+    /// 回收站分页查询
+    fn trash_paginage(
+        dto: &Self::QueryDTO,
+        state: &AppState,
+    ) -> impl Future<Output = HandleResult<PaginateResultVO<Self::MasterVO>>> + Send {
+        async move {
+            let db = state.db_read();
+            let page = dto.page();
+            let page_size = dto.page_size();
+            let editor = dto.editor();
+
+            let mut query = Self::scope_trash_query();
+            if let Some(column) = Self::column("deleted_at") {
+                query = query.order_by_desc(column);
+            }
+            query = query.order_by_desc(Self::id_column());
+
+            let paginator = query.paginate(db, page_size);
+            let total = paginator.num_items().await?;
+            let models = paginator.fetch_page(page - 1).await?;
+            let list: Vec<Self::MasterVO> = models
+                .iter()
+                .map(|model| Self::master_vo(&ViewModeEnum::ManagerList, editor, model))
+                .collect();
+
+            let vo = PaginateResultVO {
+                page_size,
+                current_page: page,
+                total: Some(total),
+                next_cursor: None,
+                list,
+            };
+
+            handle_ok(vo)
+        }
+    }
+
+    /// 从回收站恢复记录
+    fn restore(
+        dto: &ModelLogicDeleteDTO,
+        state: &AppState,
+    ) -> impl Future<Output = HandleResult<Self::Model>> + Send {
+        async move {
+            let id = dto
+                .id
+                .active_int_id()
+                .ok_or_else(|| Into::<AppError>::into(ErrorEnum::ParamIdInvalid))?;
+            let model = Self::fetch_trash_by_id(id, state).await?;
+            let editor = &dto.editor;
+            if !Self::can_restore(editor, &model) {
+                return Err(ErrorEnum::NoPermissionUpdate.into());
+            }
+            Self::restore_check(&model, state).await?;
+
+            let origin = model;
+            let mut model: Self::ActiveModel = origin.clone().into();
+            if let Some(column) = Self::column("is_deleted") {
+                model.set(column, Value::from(false));
+            }
+            if let Some(column) = Self::column("deleted_at") {
+                model.set(column, Value::ChronoDateTime(None));
+            }
+            if let Some(column) = Self::column("version_no") {
+                let version_no = model.get(column).into_value().map_or(0, version_no_value);
+                model.set(column, Value::from(version_no + 1));
+            }
+            if let Some(column) = Self::column("updated_at") {
+                model.set(column, Value::from(time_utils::current_time()));
+            }
+            set_editor_columns::<Self>(&mut model, editor);
+
+            let txn = state.db.begin().await?;
+            // 恢复后不能与现有记录重复，在事务内按记录所属的租户校验
+            for (column, _) in Self::unique_columns() {
+                model.reset(column);
+            }
+            Self::check_unique(id, &model, &txn).await?;
+            let model = Self::save_model(model, Some(&origin), &txn).await?;
+            Self::after_restore(&model, &txn).await?;
+            Self::write_audit(
+                AuditActionEnum::Restore,
+                &model,
+                Some(&origin),
+                editor,
+                &txn,
+            )
+            .await?;
+            txn.commit().await?;
+            CacheService::touch_cache_version(state.cache.as_ref());
+
+            handle_ok(model)
+        }
+    }
+
+    /// 批量修改布尔值字段
+    fn bulk_update_bool_field(
+        dto: &BulkUpdateDTO<bool>,
+        state: &AppState,
+    ) -> impl Future<Output = HandleResult<BulkResultVO>> + Send {
+        async move {
+            let column = Self::bool_column(&dto.field_name)
+                .ok_or_else(|| Into::<AppError>::into(ErrorEnum::UpdateFieldInvalid))?;
+            let values: HashMap<i64, Value> = dto
+                .items
+                .iter()
+                .map(|(id, value)| (*id, Value::from(*value)))
+                .collect();
+
+            let editor = &dto.editor;
+            let (models, mut result) = Self::bulk_fetch(&dto.ids(), false, state).await?;
+            let mut targets = Vec::new();
+            for model in models {
+                if Self::can_update(editor, &model) {
+                    targets.push(model);
+                } else {
+                    result.push_failure(
+                        primary_id::<Self>(&model),
+                        ErrorEnum::NoPermissionUpdate.into(),
+                    );
+                }
+            }
+
+            let mut result = Self::bulk_save(
+                AuditActionEnum::Update,
+                targets,
+                result,
+                editor,
+                false,
+                |origin, model| {
+                    if let Some(value) = values.get(&primary_id::<Self>(origin)) {
+                        model.set(column, value.clone());
+                    }
+                },
+                state,
+            )
+            .await?;
+            result.sort_by_ids(&dto.ids());
+            handle_ok(result)
+        }
+    }
+
+    /// 批量排序
+    fn bulk_sort(
+        dto: &BulkUpdateDTO<i64>,
+        state: &AppState,
+    ) -> impl Future<Output = HandleResult<BulkResultVO>> + Send {
+        async move {
+            let invalid = || Into::<AppError>::into(ErrorEnum::UpdateFieldInvalid);
+            let column = Self::column("sort").ok_or_else(invalid)?;
+            let mut values: HashMap<i64, Value> = HashMap::new();
+            for (id, sort) in &dto.items {
+                let value =
+                    column_utils::integer_to_column_value(column.def().get_column_type(), *sort)
+                        .ok_or_else(invalid)?;
+                values.insert(*id, value);
+            }
+
+            let editor = &dto.editor;
+            let (models, mut result) = Self::bulk_fetch(&dto.ids(), false, state).await?;
+            let mut targets = Vec::new();
+            for model in models {
+                if Self::can_update(editor, &model) {
+                    targets.push(model);
+                } else {
+                    result.push_failure(
+                        primary_id::<Self>(&model),
+                        ErrorEnum::NoPermissionUpdate.into(),
+                    );
+                }
+            }
+
+            let mut result = Self::bulk_save(
+                AuditActionEnum::Update,
+                targets,
+                result,
+                editor,
+                false,
+                |origin, model| {
+                    if let Some(value) = values.get(&primary_id::<Self>(origin)) {
+                        model.set(column, value.clone());
+                    }
+                },
+                state,
+            )
+            .await?;
+            result.sort_by_ids(&dto.ids());
+            handle_ok(result)
+        }
+    }
+
+    /// 批量移动，如修改所属类型或父级，目标记录不存在时整体失败
+    fn bulk_move(
+        dto: &BulkUpdateDTO<i64>,
+        state: &AppState,
+    ) -> impl Future<Output = HandleResult<BulkResultVO>> + Send {
+        async move {
+            let column = Self::move_column(&dto.field_name)
+                .ok_or_else(|| Into::<AppError>::into(ErrorEnum::UpdateFieldInvalid))?;
+            let mut changes: HashMap<i64, Vec<(Self::Column, Value)>> = HashMap::new();
+            for (_, value) in &dto.items {
+                if !changes.contains_key(value) {
+                    let list = Self::move_check(column, *value, state).await?;
+                    changes.insert(*value, list);
+                }
+            }
+            let values: HashMap<i64, i64> = dto.items.iter().cloned().collect();
+
+            let editor = &dto.editor;
+            let (models, mut result) = Self::bulk_fetch(&dto.ids(), false, state).await?;
+            let mut targets = Vec::new();
+            for model in models {
+                let id = primary_id::<Self>(&model);
+                let value = values.get(&id).copied().unwrap_or_default();
+                if !Self::can_update(editor, &model) {
+                    result.push_failure(id, ErrorEnum::NoPermissionUpdate.into());
+                } else if !Self::can_move(&model, column, value, state).await? {
+                    result
+                        .push_failure(id, AppError::BadRequest(String::from("不能移动到目标位置")));
+                } else {
+                    targets.push(model);
+                }
+            }
+
+            let mut result = Self::bulk_save(
+                AuditActionEnum::Update,
+                targets,
+                result,
+                editor,
+                true,
+                |origin, model| {
+                    let value = values.get(&primary_id::<Self>(origin));
+                    for (column, value) in value
+                        .and_then(|value| changes.get(value))
+                        .into_iter()
+                        .flatten()
+                    {
+                        model.set(*column, value.clone());
+                    }
+                    // 唯一性校验的范围可能改变，需要重新校验
+                    for (column, _) in Self::unique_columns() {
+                        model.reset(column);
+                    }
+                },
+                state,
+            )
+            .await?;
+            result.sort_by_ids(&dto.ids());
+            handle_ok(result)
+        }
+    }
+
+    /// 批量删除（放入回收站）
+    fn bulk_delete(
+        dto: &BulkUpdateDTO<()>,
+        state: &AppState,
+    ) -> impl Future<Output = HandleResult<BulkResultVO>> + Send {
+        async move {
+            let editor = &dto.editor;
+            let (models, mut result) = Self::bulk_fetch(&dto.ids(), false, state).await?;
+            let mut targets = Vec::new();
+            for model in models {
+                if Self::can_delete(editor, &model) {
+                    targets.push(model);
+                } else {
+                    result.push_failure(
+                        primary_id::<Self>(&model),
+                        ErrorEnum::NoPermissionDelete.into(),
+                    );
+                }
+            }
+
+            let time = time_utils::current_time();
+            let mut result = Self::bulk_save(
+                AuditActionEnum::Delete,
+                targets,
+                result,
+                editor,
+                true,
+                |_, model| {
+                    if let Some(column) = Self::column("is_deleted") {
+                        model.set(column, Value::from(true));
+                    }
+                    if let Some(column) = Self::column("deleted_at") {
+                        model.set(column, Value::from(time));
+                    }
+                },
+                state,
+            )
+            .await?;
+            result.sort_by_ids(&dto.ids());
+            handle_ok(result)
+        }
+    }
+
+    /// 批量从回收站恢复
+    fn bulk_restore(
+        dto: &BulkUpdateDTO<()>,
+        state: &AppState,
+    ) -> impl Future<Output = HandleResult<BulkResultVO>> + Send {
+        async move {
+            let editor = &dto.editor;
+            let (models, mut result) = Self::bulk_fetch(&dto.ids(), true, state).await?;
+            let mut targets = Vec::new();
+            for model in models {
+                let id = primary_id::<Self>(&model);
+                if !Self::can_restore(editor, &model) {
+                    result.push_failure(id, ErrorEnum::NoPermissionUpdate.into());
+                    continue;
+                }
+                if let Err(err) = Self::restore_check(&model, state).await {
+                    result.push_failure(id, err);
+                    continue;
+                }
+                targets.push(model);
+            }
+
+            let mut result = Self::bulk_save(
+                AuditActionEnum::Restore,
+                targets,
+                result,
+                editor,
+                true,
+                |_, model| {
+                    if let Some(column) = Self::column("is_deleted") {
+                        model.set(column, Value::from(false));
+                    }
+                    if let Some(column) = Self::column("deleted_at") {
+                        model.set(column, Value::ChronoDateTime(None));
+                    }
+                    // 恢复后不能与现有记录重复
+                    for (column, _) in Self::unique_columns() {
+                        model.reset(column);
+                    }
+                },
+                state,
+            )
+            .await?;
+            result.sort_by_ids(&dto.ids());
+            handle_ok(result)
+        }
+    }
+
+    /// 批量查询记录，不存在的记录写入失败结果
+    fn bulk_fetch(
+        ids: &[i64],
+        trash: bool,
+        state: &AppState,
+    ) -> impl Future<Output = HandleResult<(Vec<Self::Model>, BulkResultVO)>> + Send {
+        async move {
+            let query = if trash {
+                Self::scope_trash_query()
+            } else {
+                Self::scope_active_query()
+            };
+            let models = query
+                .filter(Self::id_column().is_in(ids.to_vec()))
+                .all(&state.db)
+                .await?;
+
+            let mut result = BulkResultVO::default();
+            for id in ids {
+                if !models.iter().any(|model| primary_id::<Self>(model) == *id) {
+                    result.push_failure(*id, ErrorEnum::RecordNotFound.into());
+                }
+            }
+            handle_ok((models, result))
+        }
+    }
+
+    /// 批量保存，所有记录在同一事务内修改，唯一性校验失败和版本冲突的记录写入失败结果，
+    /// 其余错误整体回滚
+    fn bulk_save<F>(
+        action: AuditActionEnum,
+        targets: Vec<Self::Model>,
+        mut result: BulkResultVO,
+        editor: &EditorCurrentDTO,
+        after_bulk: bool,
+        change: F,
+        state: &AppState,
+    ) -> impl Future<Output = HandleResult<BulkResultVO>> + Send
+    where
+        F: Fn(&Self::Model, &mut Self::ActiveModel) + Send + Sync,
+    {
+        async move {
+            let time = time_utils::current_time();
+            let mut models = Vec::new();
+            let mut origins = Vec::new();
+
+            let txn = state.db.begin().await?;
+            for origin in targets {
+                let id = primary_id::<Self>(&origin);
+                let mut model: Self::ActiveModel = origin.clone().into();
+                change(&origin, &mut model);
+                if let Some(column) = Self::column("version_no") {
+                    let version_no = version_no_value(origin.get(column));
+                    model.set(column, Value::from(version_no + 1));
+                }
+                if let Some(column) = Self::column("updated_at") {
+                    model.set(column, Value::from(time));
+                }
+                set_editor_columns::<Self>(&mut model, editor);
+
+                // 在事务内校验，同一批次中先保存的记录也参与校验
+                if let Some(err) = Self::duplicate_error(id, &model, &txn).await? {
+                    result.push_failure(id, err);
+                    continue;
+                }
+                match Self::save_model(model, Some(&origin), &txn).await {
+                    Ok(model) => {
+                        Self::write_audit(action.clone(), &model, Some(&origin), editor, &txn)
+                            .await?;
+                        result.push_success(id);
+                        models.push(model);
+                        origins.push(origin);
+                    }
+                    Err(err @ AppError::Conflict(_)) => result.push_failure(id, err),
+                    Err(err) => return Err(err),
+                }
+            }
+            if after_bulk && !models.is_empty() {
+                Self::after_bulk(&models, &origins, &txn).await?;
+            }
+            txn.commit().await?;
+            CacheService::touch_cache_version(state.cache.as_ref());
+
+            handle_ok(result)
+        }
+    }
+
+    /// 彻底删除回收站中的记录
+    fn purge(
+        dto: &ModelLogicDeleteDTO,
+        state: &AppState,
+    ) -> impl Future<Output = HandleResult<()>> + Send {
+        async move {
+            let id = dto
+                .id
+                .active_int_id()
+                .ok_or_else(|| Into::<AppError>::into(ErrorEnum::ParamIdInvalid))?;
+            let model = Self::fetch_trash_by_id(id, state).await?;
+            let editor = &dto.editor;
+            if !Self::can_purge(editor, &model) {
+                return Err(ErrorEnum::NoPermissionDelete.into());
+            }
+
+            let txn = state.db.begin().await?;
+            Self::Entity::delete_many()
+                .filter(Self::id_column().eq(id))
+                .exec(&txn)
+                .await?;
+            Self::after_purge(&model, &txn).await?;
+            Self::write_audit(AuditActionEnum::Purge, &model, Some(&model), editor, &txn).await?;
+            txn.commit().await?;
+            CacheService::touch_cache_version(state.cache.as_ref());
+
+            handle_ok(())
+        }
+    }
+
+    /// 彻底删除指定时间之前进入回收站的记录，返回删除数量，变更历史的编辑用户为系统
+    fn purge_expired(
+        older_than: NaiveDateTime,
+        state: &AppState,
+    ) -> impl Future<Output = HandleResult<u64>> + Send {
+        async move {
+            let db = &state.db;
+            let column = match Self::column("deleted_at") {
+                Some(column) => column,
+                None => return handle_ok(0),
+            };
+            let models = Self::scope_trash_query()
+                .filter(column.lt(older_than))
+                .all(db)
+                .await?;
+            if models.is_empty() {
+                return handle_ok(0);
+            }
+
+            let ids: Vec<i64> = models.iter().map(primary_id::<Self>).collect();
+            let txn = db.begin().await?;
+            let result = Self::Entity::delete_many()
+                .filter(Self::id_column().is_in(ids))
+                .exec(&txn)
+                .await?;
+            let editor = EditorCurrentDTO::empty();
+            for model in models.iter() {
+                Self::after_purge(model, &txn).await?;
+                Self::write_audit(AuditActionEnum::Purge, model, Some(model), &editor, &txn)
+                    .await?;
+            }
+            txn.commit().await?;
+            CacheService::touch_cache_version(state.cache.as_ref());
+
+            handle_ok(result.rows_affected)
+        }
+    }
+
+    /// 分页查询记录的变更历史
+    ///
+    /// 变更历史不区分租户，先按当前租户查询记录（包括回收站中的记录），不存在时返回 404。
+    fn history(
+        id: i64,
+        dto: &AuditQueryDTO,
+        state: &AppState,
+    ) -> impl Future<Output = HandleResult<PaginateResultVO<AuditVO>>> + Send {
+        async move {
+            Self::fetch_with_trash_by_id(id, state).await?;
+
+            let dto = AuditQueryDTO {
+                entity_type: Self::audit_entity_type(),
+                entity_id: id,
+                ..dto.clone()
+            };
+            AuditService::paginage(&dto, state).await
+        }
+    }
+
+    /// 查看记录的某条变更历史，记录的查询同 `history`
+    fn history_view(
+        id: i64,
+        audit_id: i64,
+        state: &AppState,
+    ) -> impl Future<Output = HandleResult<AuditVO>> + Send {
+        async move {
+            Self::fetch_with_trash_by_id(id, state).await?;
+
+            let entity_type = Self::audit_entity_type();
+            let model = AuditService::fetch_by_id(&entity_type, id, audit_id, state).await?;
+            handle_ok(model.into())
+        }
+    }
+
+    /// 回滚到某条变更历史之后的版本
+    fn revert(
+        id: i64,
+        audit_id: i64,
+        editor: &EditorCurrentDTO,
+        state: &AppState,
+    ) -> impl Future<Output = HandleResult<Self::Model>> + Send {
+        async move {
+            let origin = Self::fetch_by_id(id, state).await?;
+            if !Self::can_update(editor, &origin) {
+                return Err(ErrorEnum::NoPermissionUpdate.into());
+            }
+
+            // 从当前版本开始，按时间倒序撤销之后的每一次变更
+            let entity_type = Self::audit_entity_type();
+            let audit = AuditService::fetch_by_id(&entity_type, id, audit_id, state).await?;
+            let audits = AuditService::list_after(&entity_type, id, audit.id, state).await?;
+            let mut target = serde_json::to_value(&origin).map_err(|_| AppError::Internal)?;
+            for audit in audits.iter() {
+                revert_changes(&mut target, &audit.changes);
+            }
+            let target: Self::Model = serde_json::from_value(target)
+                .map_err(|_| AppError::BadRequest(String::from("历史版本数据无效")))?;
+
+            let mut model: Self::ActiveModel = origin.clone().into();
+            for column in Self::Column::iter() {
+                if REVERT_SKIP_COLUMNS.contains(&column.as_str()) {
+                    continue;
+                }
+                let value = target.get(column);
+                if value != origin.get(column) {
+                    model.set(column, value);
+                }
+            }
+
+            Self::persist(
+                AuditActionEnum::Revert,
+                None,
+                model,
+                Some(&origin),
+                editor,
+                state,
+            )
+            .await
+        }
+    }
+}
+
+/// 变更历史中忽略的字段
+const AUDIT_SKIP_COLUMNS: [&str; 5] = [
+    "created_at",
+    "updated_at",
+    "version_no",
+    "editor_type",
+    "editor_id",
+];
+
+/// 回滚时不恢复的字段
+const REVERT_SKIP_COLUMNS: [&str; 8] = [
+    "id",
+    "is_deleted",
+    "deleted_at",
+    "created_at",
+    "updated_at",
+    "version_no",
+    "editor_type",
+    "editor_id",
+];
+
+/// 对比两个版本的字段，返回 {"字段": {"old": 旧值, "new": 新值}}
+fn json_changes(origin: Option<&JsonValue>, current: &JsonValue, skip: &[&str]) -> JsonValue {
+    let mut changes = Map::new();
+    if let Some(fields) = current.as_object() {
+        for (name, new_value) in fields {
+            if AUDIT_SKIP_COLUMNS.contains(&name.as_str()) || skip.contains(&name.as_str()) {
+                continue;
+            }
+            let old_value = origin
+                .and_then(|origin| origin.get(name))
+                .cloned()
+                .unwrap_or_default();
+            if old_value != *new_value {
+                changes.insert(
+                    name.to_owned(),
+                    json!({ "old": old_value, "new": new_value }),
+                );
+            }
+        }
+    }
+    JsonValue::Object(changes)
+}
+
+/// 将一次变更中的字段还原为旧值
+fn revert_changes(target: &mut JsonValue, changes: &JsonValue) {
+    let (Some(target), Some(changes)) = (target.as_object_mut(), changes.as_object()) else {
+        return;
+    };
+    for (name, change) in changes {
+        if REVERT_SKIP_COLUMNS.contains(&name.as_str()) {
+            continue;
+        }
+        if let Some(old_value) = change.get("old") {
+            target.insert(name.to_owned(), old_value.clone());
+        }
+    }
+}
+
+/// 读取主键
+pub(super) fn primary_id<S: ResourceService + ?Sized>(model: &S::Model) -> i64 {
+    match model.get(S::id_column()) {
+        Value::BigInt(Some(id)) => id,
+        Value::Int(Some(id)) => id as i64,
+        _ => 0,
+    }
+}
+
+/// 根据筛选操作符构建查询条件，值无法转换时返回空
+fn filter_expr<C: ColumnTrait>(
+    column: C,
+    operator: &FilterOperatorEnum,
+    value: &str,
+) -> Option<SimpleExpr> {
+    let column_type = column.def().get_column_type().clone();
+    let parse = |value: &str| column_utils::string_to_column_value(&column_type, value);
+    let expr = match operator {
+        FilterOperatorEnum::Eq => column.eq(parse(value)?),
+        FilterOperatorEnum::Ne => column.ne(parse(value)?),
+        FilterOperatorEnum::Gt => column.gt(parse(value)?),
+        FilterOperatorEnum::Gte => column.gte(parse(value)?),
+        FilterOperatorEnum::Lt => column.lt(parse(value)?),
+        FilterOperatorEnum::Lte => column.lte(parse(value)?),
+        FilterOperatorEnum::Like => column.contains(value),
+        FilterOperatorEnum::In => {
+            let values = value
+                .split(',')
+                .map(parse)
+                .collect::<Option<Vec<Value>>>()?;
+            column.is_in(values)
+        }
+        FilterOperatorEnum::Null => {
+            if column_utils::string_to_bool(value)? {
+                column.is_null()
+            } else {
+                column.is_not_null()
+            }
+        }
+    };
+    Some(expr)
+}
+
+/// 读取版本号
+fn version_no_value(value: Value) -> i32 {
+    match value {
+        Value::Int(Some(num)) => num,
+        _ => 0,
+    }
+}
+
+/// 新建的记录属于当前租户
+fn set_tenant_column<S: ResourceService + ?Sized>(model: &mut S::ActiveModel) {
+    if let Some(column) = S::column("tenant_id") {
+        model.set(column, Value::from(TenantService::current_tenant_id()));
+    }
+}
+
+/// 设置编辑用户字段
+fn set_editor_columns<S: ResourceService + ?Sized>(
+    model: &mut S::ActiveModel,
+    editor: &EditorCurrentDTO,
+) {
+    if let Some(column) = S::column("editor_type") {
+        model.set(column, Value::from(editor.editor_type.string_value()));
+    }
+    if let Some(column) = S::column("editor_id") {
+        model.set(column, Value::from(editor.editor_id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_changes_for_create() {
+        let current = json!({ "id": 1, "name": "app", "title": null, "updated_at": "now" });
+        let changes = json_changes(None, &current, &[]);
+        assert_eq!(
+            changes,
+            json!({
+                "id": { "old": null, "new": 1 },
+                "name": { "old": null, "new": "app" },
+            })
+        );
+    }
+
+    #[test]
+    fn test_json_changes_for_update() {
+        let origin = json!({ "id": 1, "name": "app", "version_no": 1 });
+        let current = json!({ "id": 1, "name": "mate", "version_no": 2 });
+        let changes = json_changes(Some(&origin), &current, &[]);
+        assert_eq!(changes, json!({ "name": { "old": "app", "new": "mate" } }));
+
+        let changes = json_changes(Some(&origin), &origin, &[]);
+        assert_eq!(changes, json!({}));
+
+        let changes = json_changes(Some(&origin), &current, &["name"]);
+        assert_eq!(changes, json!({}));
+    }
+
+    #[test]
+    fn test_revert_changes() {
+        let mut target = json!({ "id": 2, "name": "mate", "is_deleted": true });
+        let changes = json!({
+            "id": { "old": null, "new": 2 },
+            "name": { "old": "app", "new": "mate" },
+            "is_deleted": { "old": false, "new": true },
+        });
+        revert_changes(&mut target, &changes);
+        assert_eq!(
+            target,
+            json!({ "id": 2, "name": "app", "is_deleted": true })
+        );
+    }
+
+    #[test]
+    fn test_version_no_value() {
+        assert_eq!(version_no_value(Value::Int(Some(3))), 3);
+        assert_eq!(version_no_value(Value::Int(None)), 0);
+        assert_eq!(version_no_value(Value::BigInt(Some(3))), 0);
+    }
+}
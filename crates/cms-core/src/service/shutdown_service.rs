@@ -0,0 +1,68 @@
+use salvo::conn::Acceptor;
+use salvo::prelude::*;
+use tracing::{error, info};
+
+use crate::config::AppState;
+
+use super::TelemetryService;
+
+pub struct ShutdownService {}
+
+impl ShutdownService {
+    /// 启动服务，收到 SIGTERM 或 SIGINT 后停止接受新连接
+    ///
+    /// 处理中的请求最多等待 `web.shutdown_timeout`，之后关闭连接池并导出剩余的 span。
+    pub async fn serve<A>(acceptor: A, service: impl Into<Service> + Send, state: &AppState)
+    where
+        A: Acceptor + Send,
+    {
+        let timeout = state.config.current().web.shutdown_timeout();
+        let server = Server::new(acceptor);
+        let handle = server.handle();
+        tokio::spawn(async move {
+            Self::signal().await;
+            info!(
+                "Stopping server, waiting up to {}s for in-flight requests",
+                timeout.as_secs()
+            );
+            handle.stop_graceful(timeout);
+        });
+
+        server.serve(service).await;
+        state.close().await;
+        info!("Server stopped");
+        TelemetryService::shutdown();
+    }
+
+    /// 等待 SIGTERM 或 SIGINT
+    pub async fn signal() {
+        let interrupt = async {
+            if let Err(err) = tokio::signal::ctrl_c().await {
+                error!("Failed to listen for SIGINT: {}", err);
+                std::future::pending::<()>().await;
+            }
+        };
+
+        #[cfg(unix)]
+        let terminate = async {
+            use tokio::signal::unix::{SignalKind, signal};
+
+            match signal(SignalKind::terminate()) {
+                Ok(mut terminate) => {
+                    terminate.recv().await;
+                }
+                Err(err) => {
+                    error!("Failed to listen for SIGTERM: {}", err);
+                    std::future::pending::<()>().await;
+                }
+            }
+        };
+        #[cfg(not(unix))]
+        let terminate = std::future::pending::<()>();
+
+        tokio::select! {
+            _ = interrupt => info!("Received SIGINT"),
+            _ = terminate => info!("Received SIGTERM"),
+        }
+    }
+}
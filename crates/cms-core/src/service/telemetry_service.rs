@@ -0,0 +1,124 @@
+use opentelemetry::trace::{Span as _, SpanKind, Status, Tracer, TracerProvider as _};
+use opentelemetry::{KeyValue, global};
+use opentelemetry_otlp::{ExporterBuildError, SpanExporter, WithExportConfig};
+use opentelemetry_sdk::Resource;
+use opentelemetry_sdk::trace::{SdkTracer, SdkTracerProvider};
+use sea_orm::{DbBackend, metric};
+use std::sync::OnceLock;
+use std::time::SystemTime;
+use tracing::{Span, error, info};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+use crate::config::WebConfig;
+
+/// SeaORM 查询 span 的 tracer 名称
+const DATABASE_TRACER: &str = "sea-orm";
+
+/// 导出链路追踪的 TracerProvider，关闭时导出剩余的 span
+static PROVIDER: OnceLock<SdkTracerProvider> = OnceLock::new();
+
+pub struct TelemetryService {}
+
+impl TelemetryService {
+    /// 创建通过 OTLP/HTTP 导出链路追踪的 tracer
+    ///
+    /// 未配置 `web.otlp_endpoint` 时返回 None，不导出链路追踪。
+    pub fn init(
+        config: &WebConfig,
+        service_name: &str,
+    ) -> Result<Option<SdkTracer>, ExporterBuildError> {
+        let Some(endpoint) = config.otlp_traces_endpoint() else {
+            return Ok(None);
+        };
+        let exporter = SpanExporter::builder()
+            .with_http()
+            .with_endpoint(endpoint)
+            .build()?;
+        let resource = Resource::builder()
+            .with_service_name(service_name.to_string())
+            .build();
+        let provider = SdkTracerProvider::builder()
+            .with_batch_exporter(exporter)
+            .with_resource(resource)
+            .build();
+        let tracer = provider.tracer(service_name.to_string());
+        global::set_tracer_provider(provider.clone());
+        let _ = PROVIDER.set(provider);
+        Ok(Some(tracer))
+    }
+
+    /// 是否导出链路追踪
+    pub fn is_enabled() -> bool {
+        PROVIDER.get().is_some()
+    }
+
+    /// 导出剩余的 span，退出前调用
+    pub fn shutdown() {
+        let Some(provider) = PROVIDER.get() else {
+            return;
+        };
+        match provider.shutdown() {
+            Ok(()) => info!("Tracer provider shut down"),
+            Err(err) => error!("Failed to shut down tracer provider: {}", err),
+        }
+    }
+
+    /// 记录 SeaORM 查询的 span，作为数据库连接的 metric callback 使用
+    ///
+    /// 查询完成后才调用，按耗时推算开始时间。父 span 为当前的 tracing span，如请求的 span。
+    /// SQL 使用占位符，不包含参数值。
+    pub fn record_query(info: &metric::Info<'_>) {
+        if !Self::is_enabled() {
+            return;
+        }
+        let end = SystemTime::now();
+        let sql = info.statement.sql.trim();
+        let operation = sql
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .to_uppercase();
+        let system = match info.statement.db_backend {
+            DbBackend::Postgres => "postgresql",
+            DbBackend::MySql => "mysql",
+            DbBackend::Sqlite => "sqlite",
+        };
+
+        let tracer = global::tracer(DATABASE_TRACER);
+        let mut span = tracer
+            .span_builder(operation.clone())
+            .with_kind(SpanKind::Client)
+            .with_start_time(end.checked_sub(info.elapsed).unwrap_or(end))
+            .with_attributes([
+                KeyValue::new("db.system.name", system),
+                KeyValue::new("db.operation.name", operation),
+                KeyValue::new("db.query.text", sql.to_string()),
+            ])
+            .start_with_context(&tracer, &Span::current().context());
+        if info.failed {
+            span.set_status(Status::error("Query failed"));
+        }
+        span.end_with_timestamp(end);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_init_without_endpoint() {
+        let tracer = TelemetryService::init(&WebConfig::default(), "cms-test").unwrap();
+        assert!(tracer.is_none());
+        assert!(!TelemetryService::is_enabled());
+
+        // 未导出时不记录查询
+        let statement = sea_orm::Statement::from_string(DbBackend::Sqlite, "SELECT 1");
+        TelemetryService::record_query(&metric::Info {
+            elapsed: std::time::Duration::from_millis(1),
+            statement: &statement,
+            failed: false,
+        });
+        TelemetryService::shutdown();
+    }
+}
@@ -0,0 +1,304 @@
+use sea_orm::*;
+use std::future::Future;
+use tokio::task::JoinHandle;
+use tracing::error;
+
+use crate::config::AppState;
+use crate::domain::dto::{DEFAULT_TENANT_ID, JwtClaimsDTO, TenantCurrentDTO};
+use crate::domain::entity::tenant::{Column as TenantColumn, Entity as TenantEntity};
+use crate::domain::{HandleResult, handle_ok};
+use crate::error::AppError;
+
+use super::CacheStore;
+
+tokio::task_local! {
+    /// 当前请求的租户
+    static CURRENT_TENANT: TenantCurrentDTO;
+}
+
+/// 请求头 Tenant-Id，超级管理员指定查看的租户，值为 all 时跨租户查看
+pub const HEADER_TENANT_ID: &str = "Tenant-Id";
+
+/// 域名对应租户的缓存时间（秒）
+const HOST_CACHE_SECONDS: u64 = 300;
+
+pub struct TenantService {}
+
+impl TenantService {
+    /// 当前租户，在请求之外（如命令行和测试）为空
+    pub fn current() -> Option<TenantCurrentDTO> {
+        CURRENT_TENANT.try_with(|tenant| *tenant).ok()
+    }
+
+    /// 在指定租户内执行
+    pub async fn scope<F: Future>(tenant: TenantCurrentDTO, future: F) -> F::Output {
+        CURRENT_TENANT.scope(tenant, future).await
+    }
+
+    /// 启动后台任务，任务内沿用当前租户
+    pub fn spawn<F>(future: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        match Self::current() {
+            Some(tenant) => tokio::spawn(CURRENT_TENANT.scope(tenant, future)),
+            None => tokio::spawn(future),
+        }
+    }
+
+    /// 查询限制为当前租户，实体没有租户列或跨租户查看时不限制
+    ///
+    /// 没有当前租户时限制为默认租户，需要查询所有租户时在跨租户的 `scope` 内执行。
+    pub fn scope_query<E: EntityTrait>(query: Select<E>, column: Option<E::Column>) -> Select<E> {
+        let tenant_id = Self::current()
+            .unwrap_or_else(|| TenantCurrentDTO::new(DEFAULT_TENANT_ID))
+            .scope_id();
+        match (column, tenant_id) {
+            (Some(column), Some(tenant_id)) => query.filter(column.eq(tenant_id)),
+            _ => query,
+        }
+    }
+
+    /// 新建记录所属的租户
+    pub fn current_tenant_id() -> i64 {
+        Self::current().map_or(DEFAULT_TENANT_ID, |tenant| tenant.tenant_id)
+    }
+
+    /// 缓存键加上租户，避免不同租户共用缓存
+    pub fn cache_key(key: &str) -> String {
+        match Self::current() {
+            Some(tenant) if tenant.is_cross_tenant => format!("{}:tenant:all", key),
+            Some(tenant) => format!("{}:tenant:{}", key, tenant.tenant_id),
+            None => key.to_owned(),
+        }
+    }
+
+    /// 超级管理员：默认租户的管理端用户，可以查看所有租户
+    pub fn is_super_admin(claims: &JwtClaimsDTO) -> bool {
+        claims.tenant_id == Some(DEFAULT_TENANT_ID)
+            && claims.user_type.eq_ignore_ascii_case("manager")
+    }
+
+    /// 解析请求的租户
+    ///
+    /// Token 中有租户时使用 Token 的租户，超级管理员可以通过 `Tenant-Id` 请求头指定租户或跨租户
+    /// 查看；没有 Token 时根据域名匹配租户，未匹配时为默认租户。
+    pub async fn resolve(
+        claims: Option<&JwtClaimsDTO>,
+        header: Option<&str>,
+        host: Option<&str>,
+        state: &AppState,
+    ) -> HandleResult<TenantCurrentDTO> {
+        let claims = claims.and_then(|claims| claims.tenant_id.map(|id| (claims, id)));
+        if let Some((claims, tenant_id)) = claims {
+            let tenant = match header.map(str::trim) {
+                Some(header) if Self::is_super_admin(claims) => {
+                    Self::parse_header(tenant_id, header)?
+                }
+                _ => TenantCurrentDTO::new(tenant_id),
+            };
+            return handle_ok(tenant);
+        }
+
+        let tenant_id = match host {
+            Some(host) => Self::resolve_host(host, state).await?,
+            None => DEFAULT_TENANT_ID,
+        };
+        handle_ok(TenantCurrentDTO::new(tenant_id))
+    }
+
+    /// 解析超级管理员的 `Tenant-Id` 请求头
+    fn parse_header(tenant_id: i64, header: &str) -> HandleResult<TenantCurrentDTO> {
+        if header.eq_ignore_ascii_case("all") {
+            return handle_ok(TenantCurrentDTO::cross_tenant(tenant_id));
+        }
+        let tenant_id = header
+            .parse::<i64>()
+            .ok()
+            .filter(|id| *id >= DEFAULT_TENANT_ID)
+            .ok_or_else(|| AppError::BadRequest(String::from("租户ID无效")))?;
+        handle_ok(TenantCurrentDTO::new(tenant_id))
+    }
+
+    /// 根据域名查询租户ID，未匹配时为默认租户，租户已停用时返回错误
+    pub async fn resolve_host(host: &str, state: &AppState) -> HandleResult<i64> {
+        let host = normalize_host(host);
+        if host.is_empty() {
+            return handle_ok(DEFAULT_TENANT_ID);
+        }
+
+        let cache_key = format!("tenant:host:{}", host);
+        if let Some(tenant_id) = Self::cached_tenant_id(state.cache.as_ref(), &cache_key) {
+            return handle_ok(tenant_id);
+        }
+
+        let model = TenantEntity::find()
+            .filter(TenantColumn::Host.eq(host.as_str()))
+            .one(state.db_read())
+            .await?;
+        let tenant_id = match model {
+            Some(model) if !model.is_enabled => {
+                return Err(AppError::NotFound(String::from("租户已停用")));
+            }
+            Some(model) => model.id,
+            None => DEFAULT_TENANT_ID,
+        };
+
+        let value = tenant_id.to_string();
+        if let Err(err) = state
+            .cache
+            .set(&cache_key, &value, Some(HOST_CACHE_SECONDS))
+        {
+            error!("Failed to cache tenant host: {}", err);
+        }
+        handle_ok(tenant_id)
+    }
+
+    fn cached_tenant_id(cache: &dyn CacheStore, key: &str) -> Option<i64> {
+        cache.get(key).ok().flatten()?.parse::<i64>().ok()
+    }
+}
+
+/// 去掉端口并转为小写
+fn normalize_host(host: &str) -> String {
+    let host = host.trim();
+    let host = match host.rsplit_once(':') {
+        Some((name, port)) if port.chars().all(|c| c.is_ascii_digit()) => name,
+        _ => host,
+    };
+    host.to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entity::tenant::ActiveModel as TenantActiveModel;
+    use crate::domain::entity::{audit, tenant};
+    use crate::fixture::config::FakerAppState;
+    use crate::utils::time_utils;
+
+    async fn insert_tenant(host: &str, is_enabled: bool, state: &AppState) -> tenant::Model {
+        let now = time_utils::current_time();
+        TenantActiveModel {
+            name: Set(host.replace('.', "_")),
+            title: Set(host.to_owned()),
+            host: Set(host.to_owned()),
+            is_enabled: Set(is_enabled),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        }
+        .insert(&state.db)
+        .await
+        .unwrap()
+    }
+
+    fn claims(user_type: &str, tenant_id: Option<i64>) -> JwtClaimsDTO {
+        JwtClaimsDTO {
+            user_type: user_type.to_owned(),
+            tenant_id,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_normalize_host() {
+        assert_eq!(normalize_host("Site.Example.com:8080"), "site.example.com");
+        assert_eq!(normalize_host(" example.com "), "example.com");
+        assert_eq!(normalize_host(""), "");
+    }
+
+    #[tokio::test]
+    async fn test_scope() {
+        assert_eq!(TenantService::current(), None);
+        assert_eq!(TenantService::current_tenant_id(), DEFAULT_TENANT_ID);
+        assert_eq!(TenantService::cache_key("options"), "options");
+
+        let tenant = TenantCurrentDTO::new(3);
+        let current = TenantService::scope(tenant, async {
+            assert_eq!(TenantService::cache_key("options"), "options:tenant:3");
+            let handle = TenantService::spawn(async { TenantService::current() });
+            assert_eq!(handle.await.unwrap(), Some(tenant));
+            TenantService::current_tenant_id()
+        })
+        .await;
+        assert_eq!(current, 3);
+
+        let tenant = TenantCurrentDTO::cross_tenant(0);
+        let key = TenantService::scope(tenant, async { TenantService::cache_key("options") }).await;
+        assert_eq!(key, "options:tenant:all");
+    }
+
+    #[tokio::test]
+    async fn test_scope_query() {
+        let sql = |query: Select<audit::Entity>| query.build(DbBackend::Postgres).to_string();
+        let column = Some(audit::Column::EntityId);
+
+        // 没有当前租户时限制为默认租户
+        let query = TenantService::scope_query(audit::Entity::find(), column);
+        assert!(sql(query).ends_with(r#"WHERE "core_audits"."entity_id" = 0"#));
+
+        let query = TenantService::scope(TenantCurrentDTO::new(3), async {
+            TenantService::scope_query(audit::Entity::find(), column)
+        })
+        .await;
+        assert!(sql(query).ends_with(r#"WHERE "core_audits"."entity_id" = 3"#));
+
+        let query = TenantService::scope(TenantCurrentDTO::cross_tenant(0), async {
+            TenantService::scope_query(audit::Entity::find(), column)
+        })
+        .await;
+        assert!(!sql(query).contains("WHERE"));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_host() {
+        let state = FakerAppState::init().await;
+        let tenant = insert_tenant("site.example.com", true, &state).await;
+        insert_tenant("closed.example.com", false, &state).await;
+
+        let tenant_id = TenantService::resolve_host("Site.Example.com:80", &state).await;
+        assert_eq!(tenant_id.unwrap(), tenant.id);
+        let cached = state.cache.get("tenant:host:site.example.com").unwrap();
+        assert_eq!(cached, Some(tenant.id.to_string()));
+
+        let tenant_id = TenantService::resolve_host("other.example.com", &state).await;
+        assert_eq!(tenant_id.unwrap(), DEFAULT_TENANT_ID);
+
+        let res = TenantService::resolve_host("closed.example.com", &state).await;
+        assert!(res.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_resolve() {
+        let state = FakerAppState::init().await;
+        let tenant = insert_tenant("site.example.com", true, &state).await;
+        let host = Some("site.example.com");
+
+        let res = TenantService::resolve(None, Some("all"), host, &state).await;
+        assert_eq!(res.unwrap(), TenantCurrentDTO::new(tenant.id));
+
+        // Token 中的租户优先于域名
+        let member = claims("open", Some(5));
+        let res = TenantService::resolve(Some(&member), Some("all"), host, &state).await;
+        assert_eq!(res.unwrap(), TenantCurrentDTO::new(5));
+
+        let legacy = claims("manager", None);
+        let res = TenantService::resolve(Some(&legacy), None, host, &state).await;
+        assert_eq!(res.unwrap(), TenantCurrentDTO::new(tenant.id));
+
+        let admin = claims("manager", Some(DEFAULT_TENANT_ID));
+        let res = TenantService::resolve(Some(&admin), None, host, &state).await;
+        assert_eq!(res.unwrap(), TenantCurrentDTO::new(DEFAULT_TENANT_ID));
+        let res = TenantService::resolve(Some(&admin), Some("all"), host, &state).await;
+        assert_eq!(
+            res.unwrap(),
+            TenantCurrentDTO::cross_tenant(DEFAULT_TENANT_ID)
+        );
+        let res = TenantService::resolve(Some(&admin), Some("7"), host, &state).await;
+        assert_eq!(res.unwrap(), TenantCurrentDTO::new(7));
+        let res = TenantService::resolve(Some(&admin), Some("x"), host, &state).await;
+        assert!(res.is_err());
+    }
+}
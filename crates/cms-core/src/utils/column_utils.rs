@@ -0,0 +1,173 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use sea_orm::{ColumnType, Value};
+use uuid::Uuid;
+
+/// 根据列类型将查询参数字符串转换为查询值，日期时间支持 `yyyy-MM-dd` 格式
+pub fn string_to_column_value(column_type: &ColumnType, value: &str) -> Option<Value> {
+    let value = value.trim();
+    match column_type {
+        ColumnType::TinyInteger
+        | ColumnType::SmallInteger
+        | ColumnType::Integer
+        | ColumnType::BigInteger
+        | ColumnType::TinyUnsigned
+        | ColumnType::SmallUnsigned
+        | ColumnType::Unsigned
+        | ColumnType::BigUnsigned => value.parse::<i64>().ok().map(Value::from),
+        ColumnType::Boolean => string_to_bool(value).map(Value::from),
+        ColumnType::Char(_) | ColumnType::String(_) | ColumnType::Text => Some(Value::from(value)),
+        ColumnType::DateTime | ColumnType::Timestamp => string_to_datetime(value).map(Value::from),
+        ColumnType::Uuid => Uuid::parse_str(value).ok().map(Value::from),
+        _ => None,
+    }
+}
+
+/// 根据列类型将整数转换为对应宽度的值，超出范围或非整数列返回空
+pub fn integer_to_column_value(column_type: &ColumnType, value: i64) -> Option<Value> {
+    match column_type {
+        ColumnType::TinyInteger => i8::try_from(value).ok().map(Value::from),
+        ColumnType::SmallInteger => i16::try_from(value).ok().map(Value::from),
+        ColumnType::Integer => i32::try_from(value).ok().map(Value::from),
+        ColumnType::BigInteger => Some(Value::from(value)),
+        ColumnType::TinyUnsigned => u8::try_from(value).ok().map(Value::from),
+        ColumnType::SmallUnsigned => u16::try_from(value).ok().map(Value::from),
+        ColumnType::Unsigned => u32::try_from(value).ok().map(Value::from),
+        ColumnType::BigUnsigned => u64::try_from(value).ok().map(Value::from),
+        _ => None,
+    }
+}
+
+/// 字段裁剪时未查询列的占位值，可为空的列为 NULL，否则为类型默认值，不支持的类型返回空
+pub fn column_placeholder(column_type: &ColumnType, nullable: bool) -> Option<Value> {
+    let value = match column_type {
+        ColumnType::SmallInteger => Value::SmallInt(Some(0)),
+        ColumnType::Integer => Value::Int(Some(0)),
+        ColumnType::BigInteger => Value::BigInt(Some(0)),
+        ColumnType::Boolean => Value::Bool(Some(false)),
+        ColumnType::Char(_) | ColumnType::String(_) | ColumnType::Text => {
+            Value::String(Some(Box::default()))
+        }
+        ColumnType::DateTime | ColumnType::Timestamp => Value::ChronoDateTime(Some(Box::default())),
+        ColumnType::Uuid => Value::Uuid(Some(Box::default())),
+        _ => return None,
+    };
+    if nullable {
+        return Some(value.as_null());
+    }
+    Some(value)
+}
+
+/// 字符串转换为布尔值，支持 `true`/`false`/`1`/`0`
+pub fn string_to_bool(value: &str) -> Option<bool> {
+    match value.trim().to_lowercase().as_str() {
+        "true" | "1" => Some(true),
+        "false" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+/// 字符串转换为日期时间，日期格式转换为当天零点
+fn string_to_datetime(value: &str) -> Option<NaiveDateTime> {
+    let formats = ["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S"];
+    for fmt in &formats {
+        if let Ok(datetime) = NaiveDateTime::parse_from_str(value, fmt) {
+            return Some(datetime);
+        }
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::sea_query::StringLen;
+
+    #[test]
+    fn test_string_to_column_value_number() {
+        assert_eq!(
+            string_to_column_value(&ColumnType::BigInteger, " 12 "),
+            Some(Value::from(12_i64))
+        );
+        assert_eq!(string_to_column_value(&ColumnType::Integer, "abc"), None);
+    }
+
+    #[test]
+    fn test_string_to_column_value_bool() {
+        assert_eq!(
+            string_to_column_value(&ColumnType::Boolean, "true"),
+            Some(Value::from(true))
+        );
+        assert_eq!(
+            string_to_column_value(&ColumnType::Boolean, "0"),
+            Some(Value::from(false))
+        );
+        assert_eq!(string_to_column_value(&ColumnType::Boolean, "yes"), None);
+    }
+
+    #[test]
+    fn test_string_to_column_value_string() {
+        assert_eq!(
+            string_to_column_value(&ColumnType::String(StringLen::None), "app"),
+            Some(Value::from("app"))
+        );
+    }
+
+    #[test]
+    fn test_string_to_column_value_datetime() {
+        let date = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        assert_eq!(
+            string_to_column_value(&ColumnType::DateTime, "2025-01-01"),
+            Some(Value::from(date.and_hms_opt(0, 0, 0).unwrap()))
+        );
+        assert_eq!(
+            string_to_column_value(&ColumnType::DateTime, "2025-01-01 08:30:00"),
+            Some(Value::from(date.and_hms_opt(8, 30, 0).unwrap()))
+        );
+        assert_eq!(
+            string_to_column_value(&ColumnType::DateTime, "2025-13-01"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_integer_to_column_value() {
+        assert_eq!(
+            integer_to_column_value(&ColumnType::SmallInteger, 3),
+            Some(Value::SmallInt(Some(3)))
+        );
+        assert_eq!(
+            integer_to_column_value(&ColumnType::BigInteger, 3),
+            Some(Value::BigInt(Some(3)))
+        );
+        assert_eq!(
+            integer_to_column_value(&ColumnType::SmallInteger, 40000),
+            None
+        );
+        assert_eq!(integer_to_column_value(&ColumnType::Text, 1), None);
+    }
+
+    #[test]
+    fn test_column_placeholder() {
+        assert_eq!(
+            column_placeholder(&ColumnType::BigInteger, false),
+            Some(Value::BigInt(Some(0)))
+        );
+        assert_eq!(
+            column_placeholder(&ColumnType::Text, true),
+            Some(Value::String(None))
+        );
+        assert_eq!(
+            column_placeholder(&ColumnType::DateTime, true),
+            Some(Value::ChronoDateTime(None))
+        );
+        assert_eq!(column_placeholder(&ColumnType::TinyInteger, false), None);
+        assert_eq!(column_placeholder(&ColumnType::Json, true), None);
+    }
+
+    #[test]
+    fn test_string_to_column_value_unsupported() {
+        assert_eq!(string_to_column_value(&ColumnType::Json, "{}"), None);
+    }
+}
@@ -0,0 +1,101 @@
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::NaiveDateTime;
+use sea_orm::{ColumnType, Value};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::str::FromStr;
+use uuid::Uuid;
+
+/// 游标内容：排序字段值和主键
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+struct CursorPayload {
+    key: JsonValue,
+    id: i64,
+}
+
+/// 编码游标
+pub fn encode_cursor(key: &JsonValue, id: i64) -> String {
+    let payload = CursorPayload {
+        key: key.clone(),
+        id,
+    };
+    let json = serde_json::to_vec(&payload).unwrap_or_default();
+    URL_SAFE_NO_PAD.encode(json)
+}
+
+/// 解码游标，返回排序字段值和主键
+pub fn decode_cursor(cursor: &str) -> Option<(JsonValue, i64)> {
+    let bytes = URL_SAFE_NO_PAD.decode(cursor.trim()).ok()?;
+    let payload: CursorPayload = serde_json::from_slice(&bytes).ok()?;
+    Some((payload.key, payload.id))
+}
+
+/// 根据列类型将游标中的排序字段值转换为查询值
+pub fn json_to_column_value(column_type: &ColumnType, key: &JsonValue) -> Option<Value> {
+    match column_type {
+        ColumnType::TinyInteger
+        | ColumnType::SmallInteger
+        | ColumnType::Integer
+        | ColumnType::BigInteger
+        | ColumnType::TinyUnsigned
+        | ColumnType::SmallUnsigned
+        | ColumnType::Unsigned
+        | ColumnType::BigUnsigned => key.as_i64().map(Value::from),
+        ColumnType::Boolean => key.as_bool().map(Value::from),
+        ColumnType::Char(_) | ColumnType::String(_) | ColumnType::Text => {
+            key.as_str().map(Value::from)
+        }
+        ColumnType::DateTime | ColumnType::Timestamp => key
+            .as_str()
+            .and_then(|str| NaiveDateTime::from_str(str).ok())
+            .map(Value::from),
+        ColumnType::Uuid => key
+            .as_str()
+            .and_then(|str| Uuid::parse_str(str).ok())
+            .map(Value::from),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::sea_query::StringLen;
+    use serde_json::json;
+
+    #[test]
+    fn test_cursor_round_trip() {
+        let key = json!("2025-01-01T08:30:00");
+        let cursor = encode_cursor(&key, 42);
+        assert_eq!(decode_cursor(&cursor), Some((key, 42)));
+    }
+
+    #[test]
+    fn test_decode_cursor_invalid() {
+        assert_eq!(decode_cursor(""), None);
+        assert_eq!(decode_cursor("not a cursor"), None);
+        assert_eq!(decode_cursor(&URL_SAFE_NO_PAD.encode(r#"{"key":1}"#)), None);
+    }
+
+    #[test]
+    fn test_json_to_column_value() {
+        assert_eq!(
+            json_to_column_value(&ColumnType::BigInteger, &json!(5)),
+            Some(Value::from(5_i64))
+        );
+        assert_eq!(
+            json_to_column_value(&ColumnType::String(StringLen::None), &json!("app")),
+            Some(Value::from("app"))
+        );
+        let time = NaiveDateTime::from_str("2025-01-01T08:30:00").unwrap();
+        assert_eq!(
+            json_to_column_value(&ColumnType::DateTime, &json!("2025-01-01T08:30:00")),
+            Some(Value::from(time))
+        );
+        assert_eq!(
+            json_to_column_value(&ColumnType::Integer, &json!("a")),
+            None
+        );
+        assert_eq!(json_to_column_value(&ColumnType::Json, &json!({})), None);
+    }
+}
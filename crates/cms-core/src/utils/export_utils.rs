@@ -0,0 +1,136 @@
+use salvo::oapi::{Components, RefOr, Schema, ToSchema, schema::SchemaType};
+use serde_json::Value as JsonValue;
+
+use crate::domain::dto::FieldSetDTO;
+
+/// UTF-8 BOM，Excel 打开 CSV 时据此识别编码
+pub const CSV_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// 结构体在 OpenAPI 文档中的字段及类型，引用其他组件的字段类型为空
+pub fn schema_properties<T: ToSchema>() -> Vec<(String, Option<SchemaType>)> {
+    let mut components = Components::new();
+    let schema = match T::to_schema(&mut components) {
+        RefOr::Ref(reference) => {
+            // 组件名称中可能包含 `/`，如 `Mate/Item/ItemStoreForm`
+            let location = reference.ref_location.as_str();
+            let name = location
+                .strip_prefix("#/components/schemas/")
+                .unwrap_or(location);
+            components.schemas.get(name).cloned()
+        }
+        schema => Some(schema),
+    };
+    match schema {
+        Some(RefOr::Type(Schema::Object(object))) => object
+            .properties
+            .into_iter()
+            .map(|(name, schema)| {
+                let schema_type = match schema {
+                    RefOr::Type(Schema::Object(object)) => Some(object.schema_type),
+                    _ => None,
+                };
+                (name, schema_type)
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// 导出的列：VO 在 OpenAPI 文档中的字段，指定返回字段时只保留其中的字段
+pub fn schema_columns<T: ToSchema>(fields: Option<&FieldSetDTO>) -> Vec<String> {
+    let columns = schema_properties::<T>().into_iter().map(|(name, _)| name);
+
+    match fields.filter(|fields| !fields.is_empty()) {
+        Some(fields) => columns
+            .filter(|column| {
+                fields.fields.iter().any(|field| {
+                    field == column
+                        || field
+                            .split_once('.')
+                            .is_some_and(|(root, _)| root == column)
+                })
+            })
+            .collect(),
+        None => columns.collect(),
+    }
+}
+
+/// 一行 CSV，包含换行符
+pub fn csv_record<I, S>(values: I) -> Vec<u8>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<[u8]>,
+{
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    if writer.write_record(values).is_err() {
+        return Vec::new();
+    }
+    writer.into_inner().unwrap_or_default()
+}
+
+/// 按导出的列生成一行 CSV，关联数据等嵌套字段输出为 JSON
+pub fn csv_row(columns: &[String], json: &JsonValue) -> Vec<u8> {
+    let values = columns.iter().map(|column| match json.get(column) {
+        None | Some(JsonValue::Null) => String::new(),
+        Some(JsonValue::String(value)) => value.to_owned(),
+        Some(value) => value.to_string(),
+    });
+    csv_record(values)
+}
+
+/// 一行 JSON Lines，包含换行符
+pub fn ndjson_row(json: &JsonValue) -> Vec<u8> {
+    let mut row = serde_json::to_vec(json).unwrap_or_default();
+    row.push(b'\n');
+    row
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+    use serde_json::json;
+
+    #[derive(Serialize, ToSchema)]
+    #[salvo(schema(name = "Core::Test::ExportVO"))]
+    struct ExportVO {
+        id: i64,
+        title: String,
+    }
+
+    #[derive(Serialize, ToSchema)]
+    #[salvo(schema(name = "Core/Test/ImportForm"))]
+    struct ImportForm {
+        name: String,
+        sort: Option<i16>,
+    }
+
+    #[test]
+    fn test_schema_columns() {
+        assert_eq!(schema_columns::<ExportVO>(None), vec!["id", "title"]);
+        assert_eq!(schema_columns::<ImportForm>(None), vec!["name", "sort"]);
+
+        let fields = FieldSetDTO::parse("title,kind.title");
+        assert_eq!(schema_columns::<ExportVO>(Some(&fields)), vec!["title"]);
+    }
+
+    #[test]
+    fn test_csv_row() {
+        let columns = vec!["id".to_string(), "title".to_string(), "kind".to_string()];
+        assert_eq!(csv_record(&columns), b"id,title,kind\n".to_vec());
+
+        let json = json!({"id": 1, "title": "a,\"b\"", "kind": {"id": 2}});
+        assert_eq!(
+            String::from_utf8(csv_row(&columns, &json)).unwrap(),
+            "1,\"a,\"\"b\"\"\",\"{\"\"id\"\":2}\"\n"
+        );
+
+        let json = json!({"id": 1, "title": null});
+        assert_eq!(csv_row(&columns, &json), b"1,,\n".to_vec());
+    }
+
+    #[test]
+    fn test_ndjson_row() {
+        assert_eq!(ndjson_row(&json!({"id": 1})), b"{\"id\":1}\n".to_vec());
+    }
+}
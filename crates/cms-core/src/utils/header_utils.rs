@@ -0,0 +1,147 @@
+use salvo::{Depot, Request};
+use serde::Serialize;
+
+/// 请求头 If-Match
+pub const HEADER_IF_MATCH: &str = "If-Match";
+
+/// 请求头 If-None-Match
+pub const HEADER_IF_NONE_MATCH: &str = "If-None-Match";
+
+/// depot 中接口指定的 ETag
+pub const DEPOT_ETAG_KEY: &str = "etag";
+
+/// depot 中路由指定的 Cache-Control
+pub const DEPOT_CACHE_CONTROL_KEY: &str = "cache_control";
+
+/// depot 中的请求 ID
+pub const DEPOT_REQUEST_ID_KEY: &str = "request_id";
+
+/// 未指定时 GET 响应的 Cache-Control，客户端每次使用缓存前需重新验证
+pub const DEFAULT_CACHE_CONTROL: &str = "private, no-cache";
+
+/// 表单和查询选项 GET 响应的 Cache-Control，选项很少变化，客户端可以缓存一分钟
+pub const OPTIONS_CACHE_CONTROL: &str = "private, max-age=60";
+
+/// 表单和查询选项 GET 响应的 Vary，选项按租户和登录用户区分
+pub const OPTIONS_VARY: &str = "Tenant-Id, Authorization";
+
+/// 根据版本号生成 ETag
+pub fn version_etag(version_no: i32) -> String {
+    format!("\"{}\"", version_no)
+}
+
+/// 从 ETag 中解析版本号，支持 `"3"`、`W/"3"` 和 `3`
+pub fn parse_etag_version(etag: &str) -> Option<i32> {
+    let etag = etag.trim();
+    let etag = etag.strip_prefix("W/").unwrap_or(etag);
+    etag.trim_matches('"').parse::<i32>().ok()
+}
+
+/// 从 If-Match 请求头中读取客户端提交的版本号
+pub fn if_match_version(req: &Request) -> Option<i32> {
+    req.header::<String>(HEADER_IF_MATCH)
+        .and_then(|value| parse_etag_version(&value))
+}
+
+/// 根据响应内容生成弱 ETag
+pub fn content_etag(body: &str) -> String {
+    format!("W/\"{:x}\"", md5::compute(body))
+}
+
+/// 根据 VO 的 `version_no` 或 `updated_time` 生成 ETag，都不存在时返回空
+pub fn vo_etag<T: Serialize>(vo: &T) -> Option<String> {
+    let json = serde_json::to_value(vo).ok()?;
+    if let Some(version_no) = json.get("version_no").and_then(|value| value.as_i64()) {
+        return Some(version_etag(version_no as i32));
+    }
+    json.get("updated_time")
+        .and_then(|value| value.as_str())
+        .map(content_etag)
+}
+
+/// 根据选项数据的缓存版本生成 ETag，`scope` 区分租户，不同租户的选项不能互相命中
+pub fn cache_version_etag(scope: &str, version: i64) -> String {
+    format!("W/\"{}-{}\"", scope, version)
+}
+
+/// 指定当前响应的 ETag，未指定时根据响应内容生成
+pub fn set_etag(depot: &mut Depot, etag: String) {
+    depot.insert(DEPOT_ETAG_KEY, etag);
+}
+
+/// If-None-Match 是否与 ETag 匹配，按弱比较处理
+pub fn etag_matches(if_none_match: &str, etag: &str) -> bool {
+    let etag = etag.strip_prefix("W/").unwrap_or(etag);
+    if_none_match
+        .split(',')
+        .map(str::trim)
+        .any(|item| item == "*" || item.strip_prefix("W/").unwrap_or(item) == etag)
+}
+
+/// 当前请求的 ID，未经过请求 ID 中间件时为空
+pub fn request_id(depot: &Depot) -> Option<String> {
+    depot.get::<String>(DEPOT_REQUEST_ID_KEY).ok().cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_etag() {
+        assert_eq!(version_etag(3), "\"3\"");
+    }
+
+    #[test]
+    fn test_parse_etag_version() {
+        assert_eq!(parse_etag_version("\"3\""), Some(3));
+        assert_eq!(parse_etag_version("W/\"12\""), Some(12));
+        assert_eq!(parse_etag_version(" 5 "), Some(5));
+    }
+
+    #[test]
+    fn test_vo_etag() {
+        let vo = serde_json::json!({"id": 1, "version_no": 4});
+        assert_eq!(vo_etag(&vo), Some("\"4\"".to_string()));
+
+        let vo = serde_json::json!({"id": 1, "updated_time": "2024-01-01 00:00:00"});
+        assert!(vo_etag(&vo).unwrap().starts_with("W/\""));
+
+        let vo = serde_json::json!({"id": 1});
+        assert_eq!(vo_etag(&vo), None);
+    }
+
+    #[test]
+    fn test_etag_matches() {
+        assert!(etag_matches("\"3\"", "\"3\""));
+        assert!(etag_matches("W/\"a\", \"b\"", "\"b\""));
+        assert!(etag_matches("\"a\"", "W/\"a\""));
+        assert!(etag_matches("*", "\"3\""));
+        assert!(!etag_matches("\"4\"", "\"3\""));
+    }
+
+    #[test]
+    fn test_content_etag() {
+        assert_eq!(content_etag("a"), content_etag("a"));
+        assert_ne!(content_etag("a"), content_etag("b"));
+    }
+
+    #[test]
+    fn test_cache_version_etag() {
+        assert_eq!(
+            cache_version_etag("options:tenant:1", 3),
+            "W/\"options:tenant:1-3\""
+        );
+        assert_ne!(
+            cache_version_etag("options:tenant:1", 3),
+            cache_version_etag("options:tenant:2", 3)
+        );
+    }
+
+    #[test]
+    fn test_parse_etag_version_invalid() {
+        assert_eq!(parse_etag_version("*"), None);
+        assert_eq!(parse_etag_version("\"abc\""), None);
+        assert_eq!(parse_etag_version(""), None);
+    }
+}
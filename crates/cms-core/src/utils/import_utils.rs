@@ -0,0 +1,139 @@
+use salvo::oapi::schema::{BasicType, SchemaType};
+use serde_json::{Map, Value as JsonValue};
+use std::collections::HashMap;
+
+use crate::domain::{HandleResult, handle_ok};
+use crate::enums::ErrorEnum;
+use crate::error::AppError;
+
+use super::export_utils::CSV_BOM;
+
+/// 解析 CSV 内容，返回每行的行号和以表头（小写）为键的单元格，空单元格不返回
+pub fn csv_records(bytes: &[u8]) -> HandleResult<Vec<(u64, HashMap<String, String>)>> {
+    let bytes = bytes.strip_prefix(CSV_BOM).unwrap_or(bytes);
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(bytes);
+    let invalid = |_| Into::<AppError>::into(ErrorEnum::ImportFileInvalid);
+
+    let headers: Vec<String> = reader
+        .headers()
+        .map_err(invalid)?
+        .iter()
+        .map(|header| header.to_lowercase())
+        .collect();
+    if headers.iter().all(|header| header.is_empty()) {
+        return Err(ErrorEnum::ImportFileInvalid.into());
+    }
+
+    let mut list = Vec::new();
+    for record in reader.records() {
+        let record = record.map_err(invalid)?;
+        let line = record.position().map_or(0, |position| position.line());
+        let map: HashMap<String, String> = headers
+            .iter()
+            .zip(record.iter())
+            .filter(|(header, value)| !header.is_empty() && !value.is_empty())
+            .map(|(header, value)| (header.to_owned(), value.to_owned()))
+            .collect();
+        if !map.is_empty() {
+            list.push((line, map));
+        }
+    }
+
+    handle_ok(list)
+}
+
+/// 按表单字段的类型把一行 CSV 转换为 JSON，无法识别类型的字段按 JSON 解析，失败时作为字符串
+pub fn record_to_json(
+    properties: &[(String, Option<SchemaType>)],
+    record: &HashMap<String, String>,
+) -> JsonValue {
+    let mut map = Map::new();
+    for (name, schema_type) in properties {
+        let Some(value) = record.get(name) else {
+            continue;
+        };
+        let json = match schema_type.as_ref().and_then(basic_type) {
+            Some(BasicType::String) => None,
+            Some(BasicType::Integer) => value.parse::<i64>().ok().map(JsonValue::from),
+            Some(BasicType::Number) => value.parse::<f64>().ok().map(JsonValue::from),
+            Some(BasicType::Boolean) => parse_bool(value).map(JsonValue::from),
+            _ => serde_json::from_str(value).ok(),
+        };
+        map.insert(
+            name.to_owned(),
+            json.unwrap_or_else(|| JsonValue::from(value.as_str())),
+        );
+    }
+    JsonValue::Object(map)
+}
+
+/// 字段的基础类型，可为空字段取非空类型
+fn basic_type(schema_type: &SchemaType) -> Option<BasicType> {
+    match schema_type {
+        SchemaType::Basic(basic_type) => Some(basic_type.clone()),
+        SchemaType::Array(list) => list
+            .iter()
+            .find(|basic_type| **basic_type != BasicType::Null)
+            .cloned(),
+        SchemaType::AnyValue => None,
+    }
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.to_lowercase().as_str() {
+        "1" | "true" | "yes" | "y" => Some(true),
+        "0" | "false" | "no" | "n" => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_csv_records() {
+        let bytes = b"\xEF\xBB\xBFName, Title ,sort\nitem_a,A,1\n\nitem_b, B ,\n";
+        let list = csv_records(bytes).unwrap();
+        assert_eq!(list.len(), 2);
+        assert_eq!(list[0].0, 2);
+        assert_eq!(list[0].1.get("name"), Some(&"item_a".to_string()));
+        assert_eq!(list[1].1.get("title"), Some(&"B".to_string()));
+        assert_eq!(list[1].1.get("sort"), None);
+
+        assert!(csv_records(b"").is_err());
+    }
+
+    #[test]
+    fn test_record_to_json() {
+        let properties = vec![
+            (
+                "name".to_string(),
+                Some(SchemaType::basic(BasicType::String)),
+            ),
+            (
+                "sort".to_string(),
+                Some(SchemaType::from_iter([BasicType::Integer, BasicType::Null])),
+            ),
+            (
+                "is_enabled".to_string(),
+                Some(SchemaType::basic(BasicType::Boolean)),
+            ),
+            ("gender".to_string(), None),
+        ];
+        let record = HashMap::from([
+            ("name".to_string(), "10086".to_string()),
+            ("sort".to_string(), "3".to_string()),
+            ("is_enabled".to_string(), "yes".to_string()),
+            ("gender".to_string(), "1".to_string()),
+            ("other".to_string(), "x".to_string()),
+        ]);
+        assert_eq!(
+            record_to_json(&properties, &record),
+            json!({"name": "10086", "sort": 3, "is_enabled": true, "gender": 1})
+        );
+    }
+}
@@ -1,9 +1,16 @@
+pub mod column_utils;
+pub mod cursor_utils;
 pub mod deserializer_utils;
 pub mod editor_utils;
 pub mod encrypt_utils;
+pub mod export_utils;
+pub mod header_utils;
+pub mod import_utils;
 pub mod parameter_utils;
 pub mod random_utils;
+pub mod route_utils;
 pub mod time_utils;
+pub mod trace_utils;
 pub mod validate_utils;
 
 pub use editor_utils::get_current as get_current_editor;
@@ -0,0 +1,76 @@
+use salvo::prelude::*;
+
+/// 未匹配任何路由的请求
+pub const UNMATCHED_ROUTE: &str = "unmatched";
+
+/// 路由：路径中的参数值替换为参数名，如 `/core/users/{id}`
+///
+/// 用作日志和指标的标签，避免按 ID 区分路由。需要在 Service 的中间件中、调用后续处理前使用：
+/// 未匹配路由时 Salvo 已设置 404 或 405 状态码，返回 `unmatched`，避免任意路径成为标签。
+pub fn route_pattern(req: &Request, res: &Response) -> String {
+    if res.status_code.is_some() {
+        return UNMATCHED_ROUTE.to_string();
+    }
+    let params = req.params();
+    req.uri()
+        .path()
+        .split('/')
+        .map(|segment| {
+            params
+                .iter()
+                .find(|(_, value)| !segment.is_empty() && value.as_str() == segment)
+                .map(|(name, _)| format!("{{{}}}", name))
+                .unwrap_or_else(|| segment.to_string())
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use salvo::test::{ResponseExt, TestClient};
+    use uuid::Uuid;
+
+    use super::*;
+
+    #[handler]
+    async fn route_name(req: &mut Request, depot: &mut Depot) -> String {
+        let route = depot.get::<String>("route").cloned().unwrap_or_default();
+        format!("{} {}", route, req.params().len())
+    }
+
+    #[handler]
+    async fn capture(req: &mut Request, depot: &mut Depot, res: &mut Response) {
+        depot.insert("route", route_pattern(req, res));
+    }
+
+    fn service() -> Service {
+        let router = Router::with_path("users/{id}/logs/{kind}").get(route_name);
+        Service::new(router).hoop(capture)
+    }
+
+    #[tokio::test]
+    async fn test_route_pattern() {
+        let id = Uuid::new_v4().to_string();
+        let url = format!("http://127.0.0.1:5800/users/{}/logs/login", id);
+        let mut response = TestClient::get(url).send(&service()).await;
+        assert_eq!(
+            response.take_string().await.unwrap(),
+            "/users/{id}/logs/{kind} 2"
+        );
+
+        let response = TestClient::get("http://127.0.0.1:5800/users")
+            .send(&service())
+            .await;
+        assert_eq!(response.status_code.unwrap(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_route_pattern_unmatched() {
+        let req = Request::new();
+        let mut res = Response::new();
+        assert_eq!(route_pattern(&req, &res), "/");
+        res.status_code(StatusCode::NOT_FOUND);
+        assert_eq!(route_pattern(&req, &res), UNMATCHED_ROUTE);
+    }
+}
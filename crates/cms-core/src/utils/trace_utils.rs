@@ -0,0 +1,123 @@
+use lapin::types::{AMQPValue, FieldTable, LongString};
+use opentelemetry::Context;
+use opentelemetry::propagation::{Extractor, Injector, TextMapPropagator};
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use salvo::http::HeaderMap;
+
+/// 读取 HTTP 请求头中的 W3C `traceparent`，没有时返回空的上下文
+pub fn http_context(headers: &HeaderMap) -> Context {
+    TraceContextPropagator::new().extract(&HttpExtractor(headers))
+}
+
+/// 生成写入 W3C `traceparent` 的 AMQP 消息头
+pub fn amqp_headers(cx: &Context) -> FieldTable {
+    let mut headers = FieldTable::default();
+    TraceContextPropagator::new().inject_context(cx, &mut AmqpInjector(&mut headers));
+    headers
+}
+
+/// 读取 AMQP 消息头中的 W3C `traceparent`，没有时返回空的上下文
+pub fn amqp_context(headers: Option<&FieldTable>) -> Context {
+    match headers {
+        Some(headers) => TraceContextPropagator::new().extract(&AmqpExtractor(headers)),
+        None => Context::new(),
+    }
+}
+
+struct HttpExtractor<'a>(&'a HeaderMap);
+
+impl Extractor for HttpExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|key| key.as_str()).collect()
+    }
+}
+
+struct AmqpInjector<'a>(&'a mut FieldTable);
+
+impl Injector for AmqpInjector<'_> {
+    fn set(&mut self, key: &str, value: String) {
+        self.0
+            .insert(key.into(), AMQPValue::LongString(LongString::from(value)));
+    }
+}
+
+struct AmqpExtractor<'a>(&'a FieldTable);
+
+impl Extractor for AmqpExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        match self.0.inner().get(key)? {
+            AMQPValue::LongString(value) => std::str::from_utf8(value.as_bytes()).ok(),
+            AMQPValue::ShortString(value) => Some(value.as_str()),
+            _ => None,
+        }
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.inner().keys().map(|key| key.as_str()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use opentelemetry::trace::{
+        SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState,
+    };
+
+    use super::*;
+
+    const TRACEPARENT: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+    #[test]
+    fn test_http_context() {
+        let mut headers = HeaderMap::new();
+        assert!(!http_context(&headers).has_active_span());
+
+        headers.insert("traceparent", TRACEPARENT.parse().unwrap());
+        let cx = http_context(&headers);
+        let span = cx.span();
+        let span_context = span.span_context();
+        assert!(span_context.is_remote());
+        assert_eq!(
+            span_context.trace_id().to_string(),
+            "4bf92f3577b34da6a3ce929d0e0e4736"
+        );
+        assert_eq!(span_context.span_id().to_string(), "00f067aa0ba902b7");
+    }
+
+    #[test]
+    fn test_amqp_headers() {
+        let span_context = SpanContext::new(
+            TraceId::from_hex("4bf92f3577b34da6a3ce929d0e0e4736").unwrap(),
+            SpanId::from_hex("00f067aa0ba902b7").unwrap(),
+            TraceFlags::SAMPLED,
+            true,
+            TraceState::default(),
+        );
+        let cx = Context::new().with_remote_span_context(span_context.clone());
+        let headers = amqp_headers(&cx);
+        assert_eq!(
+            headers.inner().get("traceparent"),
+            Some(&AMQPValue::LongString(TRACEPARENT.into()))
+        );
+
+        let extracted = amqp_context(Some(&headers));
+        assert_eq!(extracted.span().span_context(), &span_context);
+
+        // 没有消息头或没有 traceparent
+        assert!(!amqp_context(None).has_active_span());
+        assert!(!amqp_context(Some(&FieldTable::default())).has_active_span());
+
+        // 其他客户端可能使用短字符串
+        let mut headers = FieldTable::default();
+        headers.insert(
+            "traceparent".into(),
+            AMQPValue::ShortString(TRACEPARENT.into()),
+        );
+        let extracted = amqp_context(Some(&headers));
+        assert_eq!(extracted.span().span_context(), &span_context);
+    }
+}
@@ -1,7 +1,9 @@
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 
-use cms_core::domain::dto::EditorCurrentDTO;
+use cms_core::domain::dto::{
+    EditorCurrentDTO, FieldSetDTO, ListFilterDTO, ListSortDTO, ResourceQueryDTO,
+};
 
 use crate::{domain::query::AppPaginateQuery, enums::AppLoadEnum};
 
@@ -17,6 +19,21 @@ pub struct AppQueryDTO {
     /// 每页数量
     pub page_size: u64,
 
+    /// 分页游标
+    pub cursor: Option<String>,
+
+    /// 是否统计总条数
+    pub with_total: Option<bool>,
+
+    /// 排序项
+    pub sorts: Vec<ListSortDTO>,
+
+    /// 筛选项
+    pub filters: Vec<ListFilterDTO>,
+
+    /// 返回字段
+    pub fields: FieldSetDTO,
+
     /// 关键字
     pub keyword: Option<String>,
 
@@ -41,6 +58,8 @@ impl AppQueryDTO {
         Self {
             page: model.page,
             page_size: model.page_size,
+            cursor: model.cursor.clone(),
+            with_total: model.with_total,
             keyword: model.keyword.clone(),
             title: model.title.clone(),
             is_enabled: model.is_enabled.clone(),
@@ -62,3 +81,41 @@ impl From<&AppPaginateQuery> for AppQueryDTO {
         Self::from_inner(model)
     }
 }
+
+impl ResourceQueryDTO<AppLoadEnum> for AppQueryDTO {
+    fn page(&self) -> u64 {
+        self.page
+    }
+
+    fn page_size(&self) -> u64 {
+        self.page_size
+    }
+
+    fn cursor(&self) -> Option<&str> {
+        self.cursor.as_deref()
+    }
+
+    fn with_total(&self) -> bool {
+        self.with_total.unwrap_or(true)
+    }
+
+    fn sorts(&self) -> &[ListSortDTO] {
+        &self.sorts
+    }
+
+    fn filters(&self) -> &[ListFilterDTO] {
+        &self.filters
+    }
+
+    fn fields(&self) -> Option<&FieldSetDTO> {
+        Some(&self.fields)
+    }
+
+    fn editor(&self) -> &EditorCurrentDTO {
+        &self.editor
+    }
+
+    fn load_models(&self) -> Option<&Vec<AppLoadEnum>> {
+        self.load_models.as_ref()
+    }
+}
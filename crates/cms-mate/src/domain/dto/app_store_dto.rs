@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use cms_core::domain::dto::EditorCurrentDTO;
+use cms_core::domain::dto::{EditorCurrentDTO, ResourceStoreDTO};
 
 use crate::domain::form::AppStoreForm;
 
@@ -61,3 +61,21 @@ impl From<&AppStoreForm> for AppStoreDTO {
         Self::from_inner(model)
     }
 }
+
+impl ResourceStoreDTO for AppStoreDTO {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn version_no(&self) -> Option<i32> {
+        self.version_no
+    }
+
+    fn editor(&self) -> &EditorCurrentDTO {
+        &self.editor
+    }
+
+    fn set_editor(&mut self, editor: EditorCurrentDTO) {
+        self.editor = editor;
+    }
+}
@@ -1,7 +1,9 @@
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 
-use cms_core::domain::dto::EditorCurrentDTO;
+use cms_core::domain::dto::{
+    EditorCurrentDTO, FieldSetDTO, ListFilterDTO, ListSortDTO, ResourceQueryDTO,
+};
 
 use crate::{domain::query::ItemPaginateQuery, enums::ItemLoadEnum};
 
@@ -17,6 +19,21 @@ pub struct ItemQueryDTO {
     /// 每页数量
     pub page_size: u64,
 
+    /// 分页游标
+    pub cursor: Option<String>,
+
+    /// 是否统计总条数
+    pub with_total: Option<bool>,
+
+    /// 排序项
+    pub sorts: Vec<ListSortDTO>,
+
+    /// 筛选项
+    pub filters: Vec<ListFilterDTO>,
+
+    /// 返回字段
+    pub fields: FieldSetDTO,
+
     /// App ID
     pub app_id: Option<i64>,
 
@@ -50,6 +67,8 @@ impl ItemQueryDTO {
         Self {
             page: model.page,
             page_size: model.page_size,
+            cursor: model.cursor.clone(),
+            with_total: model.with_total,
             app_id: model.app_id.clone(),
             kind_id: model.kind_id.clone(),
             parent_id: model.parent_id.clone(),
@@ -74,3 +93,41 @@ impl From<&ItemPaginateQuery> for ItemQueryDTO {
         Self::from_inner(model)
     }
 }
+
+impl ResourceQueryDTO<ItemLoadEnum> for ItemQueryDTO {
+    fn page(&self) -> u64 {
+        self.page
+    }
+
+    fn page_size(&self) -> u64 {
+        self.page_size
+    }
+
+    fn cursor(&self) -> Option<&str> {
+        self.cursor.as_deref()
+    }
+
+    fn with_total(&self) -> bool {
+        self.with_total.unwrap_or(true)
+    }
+
+    fn sorts(&self) -> &[ListSortDTO] {
+        &self.sorts
+    }
+
+    fn filters(&self) -> &[ListFilterDTO] {
+        &self.filters
+    }
+
+    fn fields(&self) -> Option<&FieldSetDTO> {
+        Some(&self.fields)
+    }
+
+    fn editor(&self) -> &EditorCurrentDTO {
+        &self.editor
+    }
+
+    fn load_models(&self) -> Option<&Vec<ItemLoadEnum>> {
+        self.load_models.as_ref()
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use cms_core::domain::dto::EditorCurrentDTO;
+use cms_core::domain::dto::{EditorCurrentDTO, ResourceStoreDTO};
 
 use crate::domain::form::ItemStoreForm;
 
@@ -85,3 +85,21 @@ impl From<&ItemStoreForm> for ItemStoreDTO {
         Self::by_store_form(model)
     }
 }
+
+impl ResourceStoreDTO for ItemStoreDTO {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn version_no(&self) -> Option<i32> {
+        self.version_no
+    }
+
+    fn editor(&self) -> &EditorCurrentDTO {
+        &self.editor
+    }
+
+    fn set_editor(&mut self, editor: EditorCurrentDTO) {
+        self.editor = editor;
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use cms_core::domain::dto::EditorCurrentDTO;
+use cms_core::domain::dto::{EditorCurrentDTO, ResourceStoreDTO};
 
 use crate::domain::form::KindStoreForm;
 
@@ -73,3 +73,21 @@ impl From<&KindStoreForm> for KindStoreDTO {
         Self::by_store_form(model)
     }
 }
+
+impl ResourceStoreDTO for KindStoreDTO {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn version_no(&self) -> Option<i32> {
+        self.version_no
+    }
+
+    fn editor(&self) -> &EditorCurrentDTO {
+        &self.editor
+    }
+
+    fn set_editor(&mut self, editor: EditorCurrentDTO) {
+        self.editor = editor;
+    }
+}
@@ -12,8 +12,9 @@ use cms_core::{domain::model::SelectOptionModel, enums::SelectValueEnum, utils::
 pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i64,
+    pub tenant_id: i64,
     pub editor_type: String,
-    pub editor_id: i64,
+    pub editor_id: Uuid,
     pub app_id: i64,
     pub kind_id: i64,
     pub name: String,
@@ -11,8 +11,9 @@ use cms_core::utils::time_utils;
 pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i64,
+    pub tenant_id: i64,
     pub editor_type: String,
-    pub editor_id: i64,
+    pub editor_id: Uuid,
     pub app_id: i64,
     pub kind_id: i64,
     pub item_id: i64,
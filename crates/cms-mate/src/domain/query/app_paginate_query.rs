@@ -31,6 +31,19 @@ pub struct AppPaginateQuery {
     ))]
     pub page_size: u64,
 
+    /// 分页游标，传入时使用游标分页，空字符串表示第一页
+    #[serde(default)]
+    #[salvo(parameter(required = false, nullable = false, value_type = String, example = ""))]
+    pub cursor: Option<String>,
+
+    /// 是否统计总条数，默认统计
+    #[serde(
+        default,
+        deserialize_with = "deserializer_utils::string_to_option_bool"
+    )]
+    #[salvo(parameter(required = false, nullable = false, value_type = bool, example = false))]
+    pub with_total: Option<bool>,
+
     /// 关键字
     #[serde(
         default,
@@ -3,6 +3,7 @@ use salvo::oapi::{
     Array, BasicType, Object, Ref, RefOr, Schema, SchemaType, ToSchema, schema::OneOf,
 };
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 use cms_core::{domain::vo::EditorLoadVO, enums::ViewModeEnum};
 
@@ -50,8 +51,8 @@ pub struct AppMasterVO {
 
     /// 编辑用户ID
     #[serde(skip_serializing)]
-    #[salvo(parameter(required = false, nullable = false, minimum = 0, example = 1))]
-    pub editor_id: i64,
+    #[salvo(schema(value_type = KnownFormat::Uuid, example = "00000000-0000-0000-0000-000000000000"))]
+    pub editor_id: Uuid,
 
     /// 名称
     #[salvo(parameter(
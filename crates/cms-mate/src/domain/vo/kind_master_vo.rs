@@ -1,6 +1,7 @@
 use redis_macros::{FromRedisValue, ToRedisArgs};
 use salvo::oapi::ToSchema;
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 use cms_core::{domain::vo::EditorLoadVO, enums::ViewModeEnum};
 
@@ -23,7 +24,8 @@ pub struct KindMasterVO {
 
     /// 编辑用户ID
     #[serde(skip_serializing)]
-    pub editor_id: i64,
+    #[salvo(schema(value_type = KnownFormat::Uuid, example = "00000000-0000-0000-0000-000000000000"))]
+    pub editor_id: Uuid,
 
     /// 模块ID
     pub app_id: i64,
@@ -1,6 +1,7 @@
 use cms_core::domain::vo::EditorLoadVO;
 use salvo::oapi::ToSchema;
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 use super::ItemLoadVO;
 use crate::domain::entity::morph::Model;
@@ -18,7 +19,8 @@ pub struct MorphInstanceVO {
 
     /// 编辑用户ID
     #[serde(skip_serializing)]
-    pub editor_id: i64,
+    #[salvo(schema(value_type = KnownFormat::Uuid, example = "00000000-0000-0000-0000-000000000000"))]
+    pub editor_id: Uuid,
 
     /// 模块ID
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -0,0 +1,64 @@
+use cms_core::config::AppState;
+use cms_core::domain::dto::EditorCurrentDTO;
+use cms_core::enums::{EditorTypeEnum, PlatformEnum};
+use cms_core::fixture::model::editors::{EDITOR_NAME_ADMIN, faker_uuid_by_name};
+use cms_core::service::ResourceService;
+
+use crate::domain::dto::{AppStoreDTO, ItemStoreDTO, KindStoreDTO};
+use crate::domain::entity::app::Model as AppModel;
+use crate::domain::entity::item::Model as ItemModel;
+use crate::domain::entity::kind::Model as KindModel;
+use crate::service::{AppService, ItemService, KindService};
+
+/// 测试用的管理员
+pub fn faker_editor() -> EditorCurrentDTO {
+    EditorCurrentDTO {
+        editor_id: faker_uuid_by_name(EDITOR_NAME_ADMIN),
+        editor_type: EditorTypeEnum::Admin,
+    }
+}
+
+/// 创建 App，标题与名称相同
+pub async fn faker_app(name: &str, state: &AppState) -> AppModel {
+    let dto = AppStoreDTO {
+        editor: faker_editor(),
+        name: Some(name.to_owned()),
+        title: Some(name.to_owned()),
+        is_enabled: Some(true),
+        ..Default::default()
+    };
+    AppService::store(&PlatformEnum::Manager, &dto, state)
+        .await
+        .unwrap()
+}
+
+/// 创建类型
+pub async fn faker_kind(app_id: i64, name: &str, state: &AppState) -> KindModel {
+    let dto = KindStoreDTO {
+        editor: faker_editor(),
+        app_id: Some(app_id),
+        name: Some(name.to_owned()),
+        title: Some(name.to_owned()),
+        is_enabled: Some(true),
+        ..Default::default()
+    };
+    KindService::store(&PlatformEnum::Manager, &dto, state)
+        .await
+        .unwrap()
+}
+
+/// 创建数据项，`parent_id` 为 0 时为顶级
+pub async fn faker_item(kind_id: i64, parent_id: i64, name: &str, state: &AppState) -> ItemModel {
+    let dto = ItemStoreDTO {
+        editor: faker_editor(),
+        kind_id: Some(kind_id),
+        parent_id: Some(parent_id),
+        name: Some(name.to_owned()),
+        title: Some(name.to_owned()),
+        is_enabled: Some(true),
+        ..Default::default()
+    };
+    ItemService::store(&PlatformEnum::Manager, &dto, state)
+        .await
+        .unwrap()
+}
@@ -1,209 +1,97 @@
 use salvo::oapi::extract::*;
 use salvo::prelude::*;
-use validator::Validate;
 
 use cms_core::{
     config::AppState,
     domain::{
         AppResult,
-        dto::{FieldBoolUpdateDTO, ModelLogicDeleteDTO, ModelViewDTO},
-        form::{FieldBoolUpdateForm, FieldValueUniqueForm},
+        query::ImportQuery,
         result_ok,
-        vo::PaginateResultVO,
+        vo::{AuditVO, ImportResultVO},
     },
     enums::PlatformEnum,
+    service::{ImportService, ResourceService},
     utils::get_current_editor,
 };
 
 use crate::{
-    domain::{
-        dto::{ItemQueryDTO, ItemStoreDTO},
-        form::ItemStoreForm,
-        query::ItemPaginateQuery,
-        vo::{ItemFormOptionVO, ItemMasterVO, ItemQueryOptionVO},
-    },
-    enums::ItemLoadEnum,
+    domain::{form::ItemStoreForm, query::ItemPaginateQuery},
     service::ItemService,
 };
 
-/// Paginate List
-///
-/// paginate query list
-#[endpoint(
-    operation_id = "mate_item_manager_paginate",
-    security(["bearer" = ["bearer"]]),
-    tags("Mate/Manager/Item")
-)]
-pub async fn manager_paginate(
-    depot: &mut Depot,
+cms_core::resource_manage_router! {
+    path: "items",
+    service: ItemService,
+    create_form: ItemStoreForm,
+    update_form: ItemStoreForm,
     query: ItemPaginateQuery,
-) -> AppResult<PaginateResultVO<ItemMasterVO>> {
-    let state = depot.obtain::<AppState>().unwrap();
-
-    let mut dto: ItemQueryDTO = query.into();
-    dto.load_models = Some(vec![ItemLoadEnum::Editor]);
-    dto.editor = get_current_editor(depot);
-
-    let vo = ItemService::paginage(&PlatformEnum::Manager, &dto, state).await?;
-    result_ok(vo)
+    operation_id: "mate_item",
+    tag: "Mate/Manager/Item",
 }
 
-/// Create Item
+/// History View
 ///
-/// Create item
+/// View item change history
 #[endpoint(
-    operation_id = "mate_item_manager_create",
+    operation_id = "mate_item_manager_history_view",
     security(["bearer" = ["bearer"]]),
     tags("Mate/Manager/Item")
 )]
-pub async fn manager_create(depot: &mut Depot, json: JsonBody<ItemStoreForm>) -> AppResult<bool> {
-    let form = json.into_inner();
-    form.validate()?;
-
-    let state = depot.obtain::<AppState>().unwrap();
-    let mut dto: ItemStoreDTO = form.into();
-    dto.editor = get_current_editor(depot);
-
-    ItemService::store(&PlatformEnum::Manager, &dto, state).await?;
-    result_ok(true)
-}
-
-/// Update Item
-///
-/// Update item
-#[endpoint(
-    operation_id = "mate_item_manager_update",
-    security(["bearer" = ["bearer"]]),
-    tags("Mate/Manager/Item")
-)]
-pub async fn manager_update(
+pub async fn manager_history_view(
     depot: &mut Depot,
     id: PathParam<i64>,
-    json: JsonBody<ItemStoreForm>,
-) -> AppResult<bool> {
-    let form = json.into_inner();
-    form.validate()?;
-
-    let state = depot.obtain::<AppState>().unwrap();
-    let mut dto: ItemStoreDTO = form.into();
-    dto.id = id.into_inner();
-    dto.editor = get_current_editor(depot);
-
-    ItemService::store(&PlatformEnum::Manager, &dto, state).await?;
-    result_ok(true)
-}
-
-/// Delete Item
-///
-/// Delete item by id
-#[endpoint(
-    operation_id = "mate_item_manager_delete",
-    security(["bearer" = ["bearer"]]),
-    tags("Mate/Manager/Item")
-)]
-pub async fn manager_delete(depot: &mut Depot, id: PathParam<i64>) -> AppResult<bool> {
-    let id = id.into_inner();
+    audit_id: PathParam<i64>,
+) -> AppResult<AuditVO> {
     let state = depot.obtain::<AppState>().unwrap();
 
-    let dto = ModelLogicDeleteDTO {
-        id,
-        editor: get_current_editor(depot),
-    };
-
-    ItemService::logic_delete(&dto, state).await?;
-    result_ok(true)
-}
-
-/// Form Options
-///
-/// Store form options
-#[endpoint(
-    operation_id = "mate_item_manager_form",
-    security(["bearer" = ["bearer"]]),
-    tags("Mate/Manager/Item")
-)]
-pub async fn manager_form(depot: &mut Depot) -> AppResult<ItemFormOptionVO> {
-    let state = depot.obtain::<AppState>().unwrap();
-    let vo = ItemService::form_options(&PlatformEnum::Manager, &state).await?;
+    let vo = ItemService::history_view(id.into_inner(), audit_id.into_inner(), state).await?;
     result_ok(vo)
 }
 
-/// Query Options
+/// Revert
 ///
-/// Paginate query options
+/// Revert item to the version of change history
 #[endpoint(
-    operation_id = "mate_item_manager_query",
+    operation_id = "mate_item_manager_revert",
     security(["bearer" = ["bearer"]]),
     tags("Mate/Manager/Item")
 )]
-pub async fn manager_query(depot: &mut Depot) -> AppResult<ItemQueryOptionVO> {
-    let state = depot.obtain::<AppState>().unwrap();
-    let vo = ItemService::query_options(&PlatformEnum::Manager, &state).await?;
-    result_ok(vo)
-}
-
-/// Field Unique
-///
-/// Check field unique
-#[endpoint(
-    operation_id = "mate_item_check_field_unique",
-    security(["bearer" = ["bearer"]]),
-    tags("Mate/Manager/Item")
-)]
-pub async fn check_field_unique(
-    depot: &mut Depot,
-    json: JsonBody<FieldValueUniqueForm>,
-) -> AppResult<bool> {
-    let form = json.into_inner();
-    form.validate()?;
-    let dto = form.into();
-    let state = depot.obtain::<AppState>().unwrap();
-    let value = ItemService::field_unique(&dto, state).await?;
-    result_ok(value)
-}
-
-/// Update Bool Field
-///
-/// Update Bool Field
-#[endpoint(
-    operation_id = "mate_item_update_bool_field",
-    security(["bearer" = ["bearer"]]),
-    tags("Mate/Manager/Item")
-)]
-pub async fn update_bool_field(
+pub async fn manager_revert(
     depot: &mut Depot,
     id: PathParam<i64>,
-    json: JsonBody<FieldBoolUpdateForm>,
+    audit_id: PathParam<i64>,
 ) -> AppResult<bool> {
-    let form = json.into_inner();
-    form.validate()?;
-
-    let mut dto: FieldBoolUpdateDTO = form.into();
-    dto.id = id.into_inner();
-    dto.editor = get_current_editor(depot);
-
     let state = depot.obtain::<AppState>().unwrap();
-    let value = ItemService::update_bool_field(&dto, state).await?;
-    result_ok(value)
+    let editor = get_current_editor(depot);
+
+    ItemService::revert(id.into_inner(), audit_id.into_inner(), &editor, state).await?;
+    result_ok(true)
 }
 
-/// View Item
+/// Import
 ///
-/// View item by id
+/// Import items from CSV, parent can be referenced by name path in `parent_path` column
 #[endpoint(
-    operation_id = "mate_item_manager_view",
+    operation_id = "mate_item_manager_import",
     security(["bearer" = ["bearer"]]),
     tags("Mate/Manager/Item")
 )]
-pub async fn manager_view(depot: &mut Depot, id: PathParam<i64>) -> AppResult<ItemMasterVO> {
-    let load_models: Vec<ItemLoadEnum> = vec![];
-    let dto = ModelViewDTO {
-        id: id.into_inner(),
-        load_models: Some(load_models),
-        editor: get_current_editor(depot),
-        ..Default::default()
-    };
+pub async fn manager_import(
+    depot: &mut Depot,
+    query: ImportQuery,
+    file: FormFile,
+) -> AppResult<ImportResultVO> {
     let state = depot.obtain::<AppState>().unwrap();
-    let model = ItemService::view(&PlatformEnum::Manager, &dto, state).await?;
-    result_ok(model)
+    let editor = get_current_editor(depot);
+    let bytes = ImportService::read_file(&file).await?;
+
+    let vo = ImportService::import::<ItemService, ItemStoreForm>(
+        &PlatformEnum::Manager,
+        &bytes,
+        query.dry_run.unwrap_or(false),
+        &editor,
+        state,
+    )
+    .await?;
+    result_ok(vo)
 }
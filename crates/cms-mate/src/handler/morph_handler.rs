@@ -1,4 +1,5 @@
 use salvo::prelude::*;
+use tracing::debug;
 
 use cms_core::domain::{AppResult, result_ok};
 
@@ -13,8 +14,6 @@ use crate::domain::query::MorphInstanceQuery;
     tags("Mate/Manager/Morph")
 )]
 pub async fn manager_list(_depot: &mut Depot, query: MorphInstanceQuery) -> AppResult<bool> {
-    print!("=======================================");
-    println!("{:#?}", query);
-    println!("=====================================");
+    debug!("List morphs by instance: {:?}", query);
     result_ok(true)
 }
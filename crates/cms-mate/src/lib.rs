@@ -2,3 +2,6 @@ pub mod domain;
 pub mod enums;
 pub mod handler;
 pub mod service;
+
+#[cfg(test)]
+mod fixture;
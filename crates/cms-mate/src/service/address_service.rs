@@ -54,7 +54,6 @@ impl AddressService {
         assert_eq!(user.name, stored_name);
         let Json(stored_addresses): Json<Vec<Address>> =
             con.json_get("user_wrapped", "$.addresses").await?;
-        println!("{:?}", stored_addresses);
         assert_eq!(user.addresses, stored_addresses);
 
         // You can even use these types as inputs
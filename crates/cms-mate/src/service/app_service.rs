@@ -1,23 +1,15 @@
 use sea_orm::*;
 use std::collections::HashMap;
+use uuid::Uuid;
 
 use cms_core::config::AppState;
-use cms_core::domain::{
-    HandleResult,
-    dto::{
-        EditorCurrentDTO, FieldBoolUpdateDTO, FieldValueUniqueDTO, ModelLogicDeleteDTO,
-        ModelViewDTO,
-    },
-    handle_ok,
-    model::SelectOptionModel,
-    vo::PaginateResultVO,
-};
+use cms_core::domain::{HandleResult, dto::EditorCurrentDTO, handle_ok, model::SelectOptionModel};
 use cms_core::enums::{
-    EditorTypeEnum, EnableEnum, ErrorEnum as CoreErrorEnum, PlatformEnum, ViewModeEnum,
+    EditorTypeEnum, EnableEnum, ErrorEnum as CoreErrorEnum, FilterOperatorEnum, PlatformEnum,
+    ViewModeEnum,
 };
 use cms_core::error::AppError;
-use cms_core::service::{EditorService, RedisService};
-use cms_core::utils::time_utils;
+use cms_core::service::{CacheService, EditorService, ResourceService, TenantService};
 
 use crate::domain::dto::{AppQueryDTO, AppStoreDTO};
 use crate::domain::entity::app::{
@@ -28,67 +20,35 @@ use crate::enums::AppLoadEnum;
 
 pub struct AppService {}
 
-impl AppService {
-    /// 创建/更新
-    pub async fn store(
+impl ResourceService for AppService {
+    type Entity = AppEntity;
+    type Model = AppModel;
+    type ActiveModel = AppActiveModel;
+    type Column = AppColumn;
+    type StoreDTO = AppStoreDTO;
+    type QueryDTO = AppQueryDTO;
+    type LoadEnum = AppLoadEnum;
+    type MasterVO = AppMasterVO;
+    type QueryOptionVO = AppQueryOptionVO;
+    type FormOptionVO = AppFormOptionVO;
+
+    fn id_column() -> AppColumn {
+        AppColumn::Id
+    }
+
+    /// 填充创建/更新字段
+    async fn fill_store_model(
         _platform: &PlatformEnum,
         dto: &AppStoreDTO,
-        state: &AppState,
-    ) -> HandleResult<AppModel> {
-        let id = dto.id;
-        let is_create = id <= 0;
-
-        let (current_version_no, mut model) = if is_create {
-            (
-                0,
-                AppActiveModel {
-                    ..Default::default()
-                },
-            )
-        } else {
-            let model = Self::fetch_by_id(id, state).await?;
-            let current_version_no = model.version_no.unwrap_or(0);
-            (current_version_no, model.into())
-        };
-
-        // 检查版本号
-        if let Some(version_no) = dto.version_no {
-            if !is_create && version_no != current_version_no {
-                return Err(Into::<AppError>::into(CoreErrorEnum::VersionNoInvalid));
-            }
-        }
-        model.version_no = Set(Some(current_version_no + 1));
-
-        let db = &state.db;
-        let filter_extends = HashMap::new();
-
+        model: &mut AppActiveModel,
+        _origin: Option<&AppModel>,
+        _state: &AppState,
+    ) -> HandleResult<()> {
         if let Some(name) = &dto.name {
-            if Self::is_column_exist(
-                id,
-                AppColumn::Name,
-                sea_orm::Value::from(name),
-                &filter_extends,
-                db,
-            )
-            .await?
-            {
-                return Err(Into::<AppError>::into(CoreErrorEnum::NameExists));
-            }
             model.name = Set(name.clone());
         }
 
         if let Some(title) = &dto.title {
-            if Self::is_column_exist(
-                id,
-                AppColumn::Title,
-                sea_orm::Value::from(title),
-                &filter_extends,
-                db,
-            )
-            .await?
-            {
-                return Err(Into::<AppError>::into(CoreErrorEnum::TitleExists));
-            }
             model.title = Set(title.clone());
         }
 
@@ -108,66 +68,61 @@ impl AppService {
             model.is_enabled = Set(is_enabled);
         }
 
-        let time = time_utils::current_time();
-        model.updated_at = Set(Some(time));
-
-        if is_create {
-            model.created_at = Set(Some(time));
-        }
+        handle_ok(())
+    }
 
-        let editor = dto.editor.clone();
-        model.editor_type = Set(editor.editor_type.string_value());
-        // model.editor_id = Set(editor.editor_id);
+    /// 构建列表查询器
+    fn query_builder(
+        platform: &PlatformEnum,
+        dto: &AppQueryDTO,
+    ) -> HandleResult<Select<AppEntity>> {
+        let mut query = Self::scope_active_query();
+        query = query.order_by_desc(AppColumn::Id);
 
-        let txn = db.begin().await?;
+        if let Some(ref keyword) = dto.keyword {
+            let condition = Condition::any()
+                .add(AppColumn::Name.contains(keyword))
+                .add(AppColumn::Title.contains(keyword));
+            query = query.filter(condition);
+        }
 
-        let model = model.save(&txn).await?;
-        let model = model.try_into_model()?;
+        if let Some(ref title) = dto.title {
+            query = query.filter(AppColumn::Title.contains(title));
+        }
 
-        // 提交事务
-        txn.commit().await?;
+        if *platform == PlatformEnum::Open {
+            query = query.filter(AppColumn::IsEnabled.eq(true));
+        } else if let Some(enabled) = dto.is_enabled {
+            query = query.filter(AppColumn::IsEnabled.eq(enabled));
+        }
 
-        handle_ok(model)
-    }
+        if let Some(ref time) = dto.created_start_time {
+            query = query.filter(AppColumn::CreatedAt.gte(*time));
+        }
 
-    /// 检查字段值是否唯一（查询）
-    async fn is_column_exist(
-        id: i64,
-        column: AppColumn,
-        value: sea_orm::Value,
-        _extends: &HashMap<String, String>,
-        db: &DatabaseConnection,
-    ) -> HandleResult<bool> {
-        let count = Self::scope_active_query()
-            .select_only()
-            .column(AppColumn::Id)
-            .filter(column.eq(value))
-            .filter(AppColumn::Id.ne(id))
-            .count(db)
-            .await?;
+        if let Some(ref time) = dto.created_end_time {
+            query = query.filter(AppColumn::CreatedAt.lte(*time));
+        }
 
-        handle_ok(count > 0)
+        handle_ok(query)
     }
 
-    /// 检查字段值是否唯一
-    pub async fn field_unique(dto: &FieldValueUniqueDTO, state: &AppState) -> HandleResult<bool> {
-        let _id = dto.skip_id;
-        let db = &state.db;
-
-        let column = match dto.field_name.to_lowercase().as_str() {
-            "name" => AppColumn::Name,
-            "title" => AppColumn::Title,
-            _ => return Err(Into::<AppError>::into(CoreErrorEnum::FieldInvalid)),
-        };
-
-        let value = sea_orm::Value::from(dto.field_value.clone());
-        let filter_extends = dto.extends.clone().unwrap_or_default();
-        let exist = Self::is_column_exist(0, column, value, &filter_extends, db).await?;
-        handle_ok(!exist)
+    /// Model 转换为 VO
+    fn master_vo(
+        view_enum: &ViewModeEnum,
+        editor: &EditorCurrentDTO,
+        model: &AppModel,
+    ) -> AppMasterVO {
+        let mut vo = AppMasterVO::mode_into(view_enum, model);
+        if *view_enum == ViewModeEnum::ManagerList || *view_enum == ViewModeEnum::ManagerDetail {
+            vo.can_update = Some(Self::can_update(editor, model));
+            vo.can_delete = Some(Self::can_delete(editor, model));
+        }
+        vo
     }
 
     /// 查询选项
-    pub async fn query_options(
+    async fn query_options(
         platform: &PlatformEnum,
         _state: &AppState,
     ) -> HandleResult<AppQueryOptionVO> {
@@ -182,7 +137,7 @@ impl AppService {
     }
 
     /// 表单选项
-    pub async fn form_options(
+    async fn form_options(
         platform: &PlatformEnum,
         _state: &AppState,
     ) -> HandleResult<AppFormOptionVO> {
@@ -196,240 +151,97 @@ impl AppService {
         handle_ok(vo)
     }
 
-    /// 修改布尔值字段
-    pub async fn update_bool_field(
-        dto: &FieldBoolUpdateDTO,
-        state: &AppState,
-    ) -> HandleResult<bool> {
-        let id = dto.id;
-        if id < 1 {
-            return Err(Into::<AppError>::into(CoreErrorEnum::ParamIdInvalid));
-        }
-        let db = &state.db;
-
-        let model = Self::fetch_by_id(id, state).await?;
-        let mut model: AppActiveModel = model.into();
-
-        let bool_value = dto.field_value;
-        match dto.field_name.to_lowercase().as_str() {
-            "is_enabled" | "enabled" => model.is_enabled = Set(bool_value),
-            _ => {
-                return Err(Into::<AppError>::into(CoreErrorEnum::UpdateFieldInvalid));
-            }
-        };
-
-        let now = time_utils::current_time();
-        model.updated_at = Set(Some(now));
-
-        let editor = dto.editor.clone();
-        model.editor_type = Set(editor.editor_type.string_value());
-        // model.editor_id = Set(editor.editor_id);
-
-        let _ = model.save(db).await?;
-
-        handle_ok(true)
+    /// 唯一性校验字段
+    fn unique_columns() -> Vec<(AppColumn, AppError)> {
+        vec![
+            (AppColumn::Name, CoreErrorEnum::NameExists.into()),
+            (AppColumn::Title, CoreErrorEnum::TitleExists.into()),
+        ]
     }
 
-    /// 查看
-    pub async fn view(
-        platform: &PlatformEnum,
-        dto: &ModelViewDTO<AppLoadEnum>,
-        state: &AppState,
-    ) -> HandleResult<AppMasterVO> {
-        let id = dto.id;
-        if id < 1 {
-            return Err(Into::<AppError>::into(CoreErrorEnum::ParamIdInvalid));
-        }
-
-        let model = Self::fetch_by_id(id, state).await?;
-
-        let view_enum = ViewModeEnum::platform_to_detail_mode(platform);
-        if view_enum == ViewModeEnum::OpenDetail && !model.is_enabled {
-            return Err(Into::<AppError>::into(CoreErrorEnum::RecordNotFound));
-        }
-
-        let mut vo: AppMasterVO = AppMasterVO::mode_into(&view_enum, &model);
-        if let Some(ref load_models) = dto.load_models {
-            for enums in load_models {
-                match enums {
-                    // AppLoadEnum::Editor => {
-                    //     vo.editor = EditorService::load_by_id(vo.editor_id.clone(), state).await?;
-                    // }
-                    _ => {}
-                }
-            }
-        }
-
-        let editor = dto.editor.clone();
-        if view_enum == ViewModeEnum::ManagerDetail {
-            vo.can_update = Some(true);
-            vo.can_delete = Some(Self::can_delete(&editor, &model));
-        }
-
-        handle_ok(vo)
-    }
-
-    /// 分页查询
-    pub async fn paginage(
-        platform: &PlatformEnum,
-        dto: &AppQueryDTO,
-        state: &AppState,
-    ) -> HandleResult<PaginateResultVO<AppMasterVO>> {
-        let db = &state.db;
-        let page = dto.page;
-        let page_size = dto.page_size;
-
-        let editor = dto.editor.clone();
-        let view_enum = ViewModeEnum::platform_to_list_mode(platform);
-
-        let mut cols = vec![
+    /// 列表允许排序的列
+    fn sort_columns() -> Vec<AppColumn> {
+        vec![
             AppColumn::Id,
-            AppColumn::EditorId,
-            AppColumn::EditorType,
             AppColumn::Name,
             AppColumn::Title,
-            AppColumn::Description,
-            AppColumn::Icon,
             AppColumn::Sort,
-            AppColumn::IsEnabled,
-        ];
-        if view_enum == ViewModeEnum::ManagerList {
-            cols.push(AppColumn::CreatedAt);
-            cols.push(AppColumn::UpdatedAt);
-        }
-
-        let mut query = Self::query_builder(platform, dto).await?;
-        query = query.select_only().columns(cols);
-        let paginator = query.paginate(db, page_size);
-        let total = paginator.num_items_and_pages().await?;
-        let models = paginator.fetch_page(page - 1).await?;
-        let len = models.len();
-        let mut list: Vec<AppMasterVO> = Vec::with_capacity(len);
-        let mut editor_ids: Vec<i64> = Vec::with_capacity(len);
-        for model in models.iter() {
-            editor_ids.push(model.editor_id);
-            let mut vo: AppMasterVO = AppMasterVO::mode_into(&view_enum, &model);
-            if view_enum == ViewModeEnum::ManagerList {
-                vo.can_update = Some(true);
-                vo.can_delete = Some(Self::can_delete(&editor, &model));
-            }
-            list.push(vo);
-        }
-
-        if let Some(ref load_models) = dto.load_models {
-            for enums in load_models {
-                match enums {
-                    // AppLoadEnum::Editor => {
-                    //     let map = EditorService::batch_load_by_ids(&editor_ids, state).await?;
-                    //     for vo in list.iter_mut() {
-                    //         vo.editor = map.get(&vo.editor_id).cloned();
-                    //     }
-                    // }
-                    _ => {}
-                }
-            }
-        }
-
-        let vo = PaginateResultVO {
-            page_size,
-            current_page: page,
-            total: total.number_of_items,
-            list,
-        };
-
-        handle_ok(vo)
+            AppColumn::CreatedAt,
+            AppColumn::UpdatedAt,
+        ]
     }
 
-    /// 构建列表查询器
-    async fn query_builder(
-        platform: &PlatformEnum,
-        dto: &AppQueryDTO,
-    ) -> HandleResult<Select<AppEntity>> {
-        let mut query = Self::scope_active_query();
-        query = query.order_by_desc(AppColumn::Id);
-
-        if let Some(ref keyword) = dto.keyword {
-            let condition = Condition::any()
-                .add(AppColumn::Name.contains(keyword))
-                .add(AppColumn::Title.contains(keyword));
-            query = query.filter(condition);
-        }
-
-        if let Some(ref title) = dto.title {
-            query = query.filter(AppColumn::Title.contains(title));
-        }
+    /// 列表允许筛选的列及操作符
+    fn filter_columns() -> Vec<(AppColumn, Vec<FilterOperatorEnum>)> {
+        vec![
+            (AppColumn::Id, FilterOperatorEnum::list()),
+            (AppColumn::Name, FilterOperatorEnum::text()),
+            (AppColumn::Title, FilterOperatorEnum::text()),
+            (AppColumn::IsEnabled, vec![FilterOperatorEnum::Eq]),
+            (AppColumn::Sort, FilterOperatorEnum::comparison()),
+            (AppColumn::CreatedAt, FilterOperatorEnum::range()),
+            (AppColumn::UpdatedAt, FilterOperatorEnum::range()),
+        ]
+    }
 
-        if *platform == PlatformEnum::Open {
-            query = query.filter(AppColumn::IsEnabled.eq(true));
-        } else if let Some(enabled) = dto.is_enabled {
-            query = query.filter(AppColumn::IsEnabled.eq(enabled));
-        }
-
-        if let Some(ref time) = dto.created_start_time {
-            query = query.filter(AppColumn::CreatedAt.gte(*time));
+    /// 管理端默认加载的关联数据
+    fn manager_load_models(view_enum: &ViewModeEnum) -> Vec<AppLoadEnum> {
+        match view_enum {
+            ViewModeEnum::ManagerList => vec![AppLoadEnum::Editor],
+            _ => vec![],
         }
+    }
 
-        if let Some(ref time) = dto.created_end_time {
-            query = query.filter(AppColumn::CreatedAt.lte(*time));
+    /// 加载关联数据
+    async fn load_relations(
+        list: &mut [AppMasterVO],
+        load_models: &[AppLoadEnum],
+        state: &AppState,
+    ) -> HandleResult<()> {
+        for enums in load_models {
+            if let AppLoadEnum::Editor = enums {
+                let editor_ids: Vec<Uuid> = list.iter().map(|vo| vo.editor_id).collect();
+                let map = EditorService::batch_load_by_uuids(&editor_ids, state).await?;
+                for vo in list.iter_mut() {
+                    vo.editor = map.get(&vo.editor_id.to_string()).cloned();
+                }
+            }
         }
 
-        handle_ok(query)
-    }
-
-    /// 构建查询器
-    fn scope_active_query() -> Select<AppEntity> {
-        AppEntity::find().filter(AppColumn::IsDeleted.eq(false))
+        handle_ok(())
     }
 
-    /// 根据ID查询
-    pub async fn fetch_by_id(id: i64, state: &AppState) -> HandleResult<AppModel> {
-        let db = &state.db;
-        let model = Self::scope_active_query()
-            .filter(AppColumn::Id.eq(id))
-            .one(db)
-            .await?
-            .ok_or_else(|| Into::<AppError>::into(CoreErrorEnum::ParamIdInvalid))?;
-
-        handle_ok(model)
+    /// 字段裁剪时删除权限判断用到的列
+    fn relation_columns() -> Vec<AppColumn> {
+        vec![AppColumn::KindCount]
     }
 
-    /// 软删除记录
-    pub async fn logic_delete(dto: &ModelLogicDeleteDTO, state: &AppState) -> HandleResult<()> {
-        if dto.id < 1 {
-            return handle_ok(());
-        }
-
-        let db = &state.db;
-        let result = Self::fetch_by_id(dto.id, state).await;
-        if result.is_err() {
-            return handle_ok(());
-        }
-        let model = result.unwrap();
-        let editor = dto.editor.clone();
-        if !Self::can_delete(&editor, &model) {
-            return Err(Into::<AppError>::into(CoreErrorEnum::NoPermissionDelete));
+    /// 是否可以删除记录
+    fn can_delete(editor: &EditorCurrentDTO, model: &AppModel) -> bool {
+        if let Some(num) = model.kind_count {
+            if num > 0 {
+                return false;
+            }
+        } else {
+            return false;
         }
-        let mut model: AppActiveModel = model.into();
-        model.editor_type = Set(editor.editor_type.string_value());
-        // model.editor_id = Set(editor.editor_id);
-        model.is_deleted = Set(Some(true));
-        let now = time_utils::current_time();
-        model.deleted_at = Set(Some(now));
-        let _ = model.save(db).await?;
 
-        handle_ok(())
+        editor.editor_type == EditorTypeEnum::Admin
     }
+}
 
+impl AppService {
     /// SelectOptionModel 列表
     pub async fn fetch_option_list(
         platform: &PlatformEnum,
         state: &AppState,
     ) -> HandleResult<Vec<SelectOptionModel>> {
-        let cache_key = "mate:app:option_list";
+        let cache_key = TenantService::cache_key("mate:app:option_list");
+        let cache_key = cache_key.as_str();
         let only_enabled = *platform == PlatformEnum::Open;
 
         let stored_list =
-            RedisService::get_json_list::<SelectOptionModel>(&state.redis, cache_key).await;
+            CacheService::get_json_list::<SelectOptionModel>(state.cache.as_ref(), cache_key).await;
         if !stored_list.is_empty() {
             if only_enabled {
                 let filted_list = stored_list
@@ -443,49 +255,32 @@ impl AppService {
             }
         }
 
-        let db = &state.db;
+        let db = state.db_read();
         let mut query = Self::scope_active_query();
         if *platform == PlatformEnum::Open {
             query = query.filter(AppColumn::IsEnabled.eq(true));
         }
         let models = query.all(db).await?;
         let list: Vec<SelectOptionModel> = models.into_iter().map(|model| model.into()).collect();
-        RedisService::set_json_list::<SelectOptionModel>(&state.redis, cache_key, &list).await;
+        CacheService::set_json_list::<SelectOptionModel>(state.cache.as_ref(), cache_key, &list)
+            .await;
 
         handle_ok(list)
     }
 
-    /// 查询关联的单个记录
-    pub async fn load_by_id(id: i64, state: &AppState) -> HandleResult<Option<AppLoadVO>> {
-        if id < 1 {
-            return handle_ok(None);
-        }
-
-        let db = &state.db;
-        let model = AppEntity::find()
-            .filter(AppColumn::Id.eq(id))
-            .one(db)
-            .await?;
-        if let Some(model) = model {
-            let vo: AppLoadVO = model.into();
-            handle_ok(Some(vo))
-        } else {
-            handle_ok(None)
-        }
-    }
-
     /// 批量查询关联的记录
     pub async fn batch_load_by_ids(
-        ids: &Vec<i64>,
+        ids: &[i64],
         state: &AppState,
     ) -> HandleResult<HashMap<i64, AppLoadVO>> {
-        let filted_ids: Vec<i64> = ids.into_iter().filter(|&&id| id > 0).cloned().collect();
+        let filted_ids: Vec<i64> = ids.iter().filter(|&&id| id > 0).cloned().collect();
         if filted_ids.is_empty() {
             return handle_ok(HashMap::new());
         }
 
-        let db = &state.db;
-        let models = AppEntity::find()
+        let db = state.db_read();
+        let query = TenantService::scope_query(AppEntity::find(), Some(AppColumn::TenantId));
+        let models = query
             .filter(AppColumn::Id.is_in(filted_ids))
             .all(db)
             .await?;
@@ -497,17 +292,365 @@ impl AppService {
 
         handle_ok(map)
     }
+}
 
-    /// 是否可以删除记录
-    pub fn can_delete(editor: &EditorCurrentDTO, model: &AppModel) -> bool {
-        if let Some(num) = model.kind_count {
-            if num > 0 {
-                return false;
+#[cfg(test)]
+mod tests {
+    use sea_orm::prelude::Expr;
+    use std::sync::Arc;
+
+    use cms_core::domain::dto::FieldSetDTO;
+    use cms_core::domain::dto::{
+        BulkUpdateDTO, ModelLogicDeleteDTO, ModelViewDTO, TenantCurrentDTO,
+    };
+    use cms_core::domain::response::AppResponse;
+    use cms_core::enums::{AuditActionEnum, ExportFormatEnum, PrimaryIdEnum};
+    use cms_core::fixture::config::FakerAppState;
+    use cms_core::service::{
+        AuditService, ExportService, MemoryCacheStore, MemoryPublisher, TenantService,
+    };
+    use cms_core::utils::{export_utils, time_utils};
+
+    use super::*;
+    use crate::fixture::{faker_app, faker_editor};
+
+    fn delete_dto(id: i64) -> ModelLogicDeleteDTO {
+        ModelLogicDeleteDTO {
+            id: PrimaryIdEnum::BigInt(id),
+            editor: faker_editor(),
+        }
+    }
+
+    fn update_dto(id: i64, title: &str, version_no: Option<i32>) -> AppStoreDTO {
+        AppStoreDTO {
+            id,
+            editor: faker_editor(),
+            title: Some(title.to_string()),
+            version_no,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_store_version_no() {
+        let state = FakerAppState::init().await;
+        let app = faker_app("shop", &state).await;
+        assert_eq!(app.version_no, Some(1));
+
+        let dto = update_dto(app.id, "Shop", Some(1));
+        let app = AppService::store(&PlatformEnum::Manager, &dto, &state)
+            .await
+            .unwrap();
+        assert_eq!(app.version_no, Some(2));
+        assert_eq!(app.title, "Shop");
+
+        // 使用过期的版本号更新时返回 409 和当前版本号
+        let dto = update_dto(app.id, "Stale", Some(1));
+        let err = AppService::store(&PlatformEnum::Manager, &dto, &state)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AppError::Conflict(2)));
+        let response: AppResponse<HashMap<String, String>> = err.into();
+        assert_eq!(response.code(), 409);
+
+        let app = AppService::fetch_by_id(app.id, &state).await.unwrap();
+        assert_eq!((app.title.as_str(), app.version_no), ("Shop", Some(2)));
+    }
+
+    #[tokio::test]
+    async fn test_delete_version_no() {
+        let state = FakerAppState::init().await;
+        let first = faker_app("first", &state).await;
+        let second = faker_app("second", &state).await;
+
+        AppService::logic_delete(&delete_dto(first.id), &state)
+            .await
+            .unwrap();
+        let dto = BulkUpdateDTO {
+            editor: faker_editor(),
+            field_name: String::new(),
+            items: vec![(second.id, ())],
+        };
+        AppService::bulk_delete(&dto, &state).await.unwrap();
+
+        // 删除同样递增版本号并更新修改时间
+        for origin in [first, second] {
+            let app = AppService::fetch_trash_by_id(origin.id, &state)
+                .await
+                .unwrap();
+            assert_eq!(app.version_no, Some(2));
+            assert!(app.updated_at >= origin.updated_at);
+            assert!(app.deleted_at.is_some());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_restore_unique() {
+        let state = FakerAppState::init().await;
+        let app = faker_app("shop", &state).await;
+        AppService::logic_delete(&delete_dto(app.id), &state)
+            .await
+            .unwrap();
+        faker_app("shop", &state).await;
+
+        // 已有同名记录时不能恢复
+        let err = AppService::restore(&delete_dto(app.id), &state)
+            .await
+            .unwrap_err();
+        let expected: AppError = CoreErrorEnum::NameExists.into();
+        assert_eq!(err.to_string(), expected.to_string());
+        assert!(AppService::fetch_trash_by_id(app.id, &state).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_purge_expired() {
+        let cache = Arc::new(MemoryCacheStore::new());
+        let state = FakerAppState::init_with(cache.clone(), Arc::new(MemoryPublisher::new())).await;
+        let expired = faker_app("expired", &state).await;
+        let recent = faker_app("recent", &state).await;
+        let active = faker_app("active", &state).await;
+        for id in [expired.id, recent.id] {
+            AppService::logic_delete(&delete_dto(id), &state)
+                .await
+                .unwrap();
+        }
+        let now = time_utils::current_time();
+        AppEntity::update_many()
+            .col_expr(
+                AppColumn::DeletedAt,
+                Expr::value(now - chrono::Duration::days(31)),
+            )
+            .filter(AppColumn::Id.eq(expired.id))
+            .exec(&state.db)
+            .await
+            .unwrap();
+
+        let version = CacheService::get_cache_version(cache.as_ref()).unwrap();
+        let older_than = now - chrono::Duration::days(30);
+        let count = AppService::purge_expired(older_than, &state).await.unwrap();
+        assert_eq!(count, 1);
+        assert!(CacheService::get_cache_version(cache.as_ref()).unwrap() > version);
+
+        // 只删除截止时间之前进入回收站的记录
+        assert!(
+            AppService::fetch_with_trash_by_id(expired.id, &state)
+                .await
+                .is_err()
+        );
+        assert!(
+            AppService::fetch_trash_by_id(recent.id, &state)
+                .await
+                .is_ok()
+        );
+        assert!(AppService::fetch_by_id(active.id, &state).await.is_ok());
+
+        let entity_type = AppService::audit_entity_type();
+        let audits = AuditService::list_after(&entity_type, expired.id, 0, &state)
+            .await
+            .unwrap();
+        assert_eq!(audits[0].action, AuditActionEnum::Purge.as_value());
+        assert_eq!(audits[0].editor_id, Uuid::nil());
+    }
+
+    #[tokio::test]
+    async fn test_paginage_cursor() {
+        let state = FakerAppState::init().await;
+        let mut ids = Vec::new();
+        for i in 0..7 {
+            ids.push(faker_app(&format!("app{}", i), &state).await.id);
+        }
+        ids.reverse();
+
+        let mut dto = AppQueryDTO {
+            editor: faker_editor(),
+            page: 1,
+            page_size: 3,
+            cursor: Some(String::new()),
+            ..Default::default()
+        };
+        let mut list = Vec::new();
+        loop {
+            let vo = AppService::paginage(&PlatformEnum::Manager, &dto, &state)
+                .await
+                .unwrap();
+            assert!(vo.list.len() <= 3);
+            list.extend(vo.list.iter().map(|vo| vo.id));
+
+            // 翻页过程中新增和删除记录，不影响后续页
+            if list.len() == 3 {
+                faker_app("new", &state).await;
+                AppService::logic_delete(&delete_dto(list[0]), &state)
+                    .await
+                    .unwrap();
+            }
+            match vo.next_cursor {
+                Some(cursor) => dto.cursor = Some(cursor),
+                None => break,
             }
-        } else {
-            return false;
         }
+        assert_eq!(list, ids);
+    }
 
-        editor.editor_type == EditorTypeEnum::Admin
+    #[tokio::test]
+    async fn test_audit_and_revert() {
+        let state = FakerAppState::init().await;
+        let app = faker_app("shop", &state).await;
+        let mut dto = update_dto(app.id, "Shop v2", Some(1));
+        dto.sort = Some(5);
+        AppService::store(&PlatformEnum::Manager, &dto, &state)
+            .await
+            .unwrap();
+        let dto = update_dto(app.id, "Shop v3", Some(2));
+        AppService::store(&PlatformEnum::Manager, &dto, &state)
+            .await
+            .unwrap();
+
+        // 没有变化的更新不写入变更历史
+        let dto = update_dto(app.id, "Shop v3", Some(3));
+        AppService::store(&PlatformEnum::Manager, &dto, &state)
+            .await
+            .unwrap();
+
+        // 每次更新写入一条变更历史，按时间倒序
+        let entity_type = AppService::audit_entity_type();
+        let audits = AuditService::list_after(&entity_type, app.id, 0, &state)
+            .await
+            .unwrap();
+        let actions: Vec<&str> = audits.iter().map(|audit| audit.action.as_str()).collect();
+        let expected = [
+            AuditActionEnum::Update,
+            AuditActionEnum::Update,
+            AuditActionEnum::Create,
+        ];
+        assert_eq!(actions, expected.map(|action| action.as_value()));
+        assert_eq!(audits[0].version_no, 3);
+        assert_eq!(audits[0].changes["title"]["old"], "Shop v2");
+        assert_eq!(audits[0].changes["title"]["new"], "Shop v3");
+
+        // 回滚到第一次更新之后的版本
+        let editor = faker_editor();
+        let model = AppService::revert(app.id, audits[1].id, &editor, &state)
+            .await
+            .unwrap();
+        assert_eq!((model.title.as_str(), model.sort), ("Shop v2", 5));
+        assert_eq!(model.version_no, Some(5));
+
+        // 回滚到创建时的版本
+        let model = AppService::revert(app.id, audits[2].id, &editor, &state)
+            .await
+            .unwrap();
+        assert_eq!((model.title.as_str(), model.sort), ("shop", app.sort));
+
+        let audits = AuditService::list_after(&entity_type, app.id, 0, &state)
+            .await
+            .unwrap();
+        assert_eq!(audits.len(), 5);
+        assert_eq!(audits[0].action, AuditActionEnum::Revert.as_value());
+    }
+
+    #[tokio::test]
+    async fn test_tenant_isolation() {
+        let state = FakerAppState::init().await;
+        let tenant_a = TenantCurrentDTO::new(1);
+        let tenant_b = TenantCurrentDTO::new(2);
+
+        // 名称在租户内唯一，不同租户可以重名
+        let app_a = TenantService::scope(tenant_a, faker_app("shop", &state)).await;
+        let app_b = TenantService::scope(tenant_b, faker_app("shop", &state)).await;
+        assert_eq!((app_a.tenant_id, app_b.tenant_id), (1, 2));
+        let dto = AppStoreDTO {
+            editor: faker_editor(),
+            name: Some("shop".to_string()),
+            title: Some("other".to_string()),
+            ..Default::default()
+        };
+        let res = TenantService::scope(
+            tenant_a,
+            AppService::store(&PlatformEnum::Manager, &dto, &state),
+        )
+        .await;
+        let expected: AppError = CoreErrorEnum::NameExists.into();
+        assert_eq!(res.unwrap_err().to_string(), expected.to_string());
+
+        // 不能查看和修改其他租户的记录
+        let not_found: AppError = CoreErrorEnum::RecordNotFound.into();
+        TenantService::scope(tenant_a, async {
+            let dto = AppQueryDTO {
+                editor: faker_editor(),
+                page: 1,
+                page_size: 10,
+                ..Default::default()
+            };
+            let vo = AppService::paginage(&PlatformEnum::Manager, &dto, &state)
+                .await
+                .unwrap();
+            let ids: Vec<i64> = vo.list.iter().map(|vo| vo.id).collect();
+            assert_eq!(ids, vec![app_a.id]);
+
+            let dto = ModelViewDTO {
+                id: PrimaryIdEnum::BigInt(app_b.id),
+                editor: faker_editor(),
+                ..Default::default()
+            };
+            let res = AppService::view(&PlatformEnum::Manager, &dto, &state).await;
+            assert_eq!(res.unwrap_err().to_string(), not_found.to_string());
+
+            let dto = update_dto(app_b.id, "changed", Some(1));
+            let res = AppService::store(&PlatformEnum::Manager, &dto, &state).await;
+            assert_eq!(res.unwrap_err().to_string(), not_found.to_string());
+        })
+        .await;
+
+        let app_b = TenantService::scope(tenant_b, AppService::fetch_by_id(app_b.id, &state))
+            .await
+            .unwrap();
+        assert_eq!((app_b.title.as_str(), app_b.version_no), ("shop", Some(1)));
+    }
+
+    #[tokio::test]
+    async fn test_export() {
+        let state = FakerAppState::init().await;
+        let first = faker_app("first", &state).await;
+        let second = faker_app("second", &state).await;
+        let dto = AppQueryDTO {
+            editor: faker_editor(),
+            fields: FieldSetDTO::parse("id,name"),
+            ..Default::default()
+        };
+
+        // CSV 以 BOM 开头，第一行为表头
+        let mut bytes: Vec<u8> = Vec::new();
+        let count = ExportService::write_rows::<AppService, _>(
+            &PlatformEnum::Manager,
+            &dto,
+            &ExportFormatEnum::Csv,
+            &mut bytes,
+            &state,
+        )
+        .await
+        .unwrap();
+        assert_eq!(count, 2);
+        let text = bytes.strip_prefix(export_utils::CSV_BOM).unwrap();
+        let text = String::from_utf8(text.to_vec()).unwrap();
+        let expected = format!("id,name\n{},second\n{},first\n", second.id, first.id);
+        assert_eq!(text, expected);
+
+        let mut bytes: Vec<u8> = Vec::new();
+        ExportService::write_rows::<AppService, _>(
+            &PlatformEnum::Manager,
+            &dto,
+            &ExportFormatEnum::Ndjson,
+            &mut bytes,
+            &state,
+        )
+        .await
+        .unwrap();
+        let text = String::from_utf8(bytes).unwrap();
+        let first_line = text.lines().next().unwrap();
+        let json: serde_json::Value = serde_json::from_str(first_line).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "id": second.id, "name": "second" })
+        );
     }
 }
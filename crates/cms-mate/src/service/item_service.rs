@@ -1,22 +1,21 @@
 use sea_orm::prelude::Expr;
 use sea_orm::*;
 use std::collections::HashMap;
+use uuid::Uuid;
 
 use cms_core::config::AppState;
 use cms_core::domain::{
     HandleResult,
-    dto::{
-        EditorCurrentDTO, FieldBoolUpdateDTO, FieldValueUniqueDTO, ModelLogicDeleteDTO,
-        ModelRelationCountDTO, ModelViewDTO,
-    },
+    dto::{EditorCurrentDTO, ModelRelationCountDTO},
     handle_ok,
     model::SelectOptionModel,
-    vo::PaginateResultVO,
 };
-use cms_core::enums::{EditorTypeEnum, EnableEnum, PlatformEnum, ViewModeEnum};
+use cms_core::enums::{
+    EditorTypeEnum, EnableEnum, ErrorEnum as CoreErrorEnum, FilterOperatorEnum, PlatformEnum,
+    ViewModeEnum,
+};
 use cms_core::error::AppError;
-use cms_core::service::EditorService;
-use cms_core::utils::time_utils;
+use cms_core::service::{EditorService, ResourceService, TenantService};
 
 use crate::domain::dto::{ItemQueryDTO, ItemStoreDTO};
 use crate::domain::entity::item::{
@@ -28,119 +27,78 @@ use crate::enums::ItemLoadEnum;
 
 use super::{AppService, KindService};
 
+/// 顶级记录的层级
+const ROOT_LEVEL: i32 = 1;
+
 pub struct ItemService {}
 
-impl ItemService {
-    /// 创建/更新
-    pub async fn store(
+impl ResourceService for ItemService {
+    type Entity = ItemEntity;
+    type Model = ItemModel;
+    type ActiveModel = ItemActiveModel;
+    type Column = ItemColumn;
+    type StoreDTO = ItemStoreDTO;
+    type QueryDTO = ItemQueryDTO;
+    type LoadEnum = ItemLoadEnum;
+    type MasterVO = ItemMasterVO;
+    type QueryOptionVO = ItemQueryOptionVO;
+    type FormOptionVO = ItemFormOptionVO;
+
+    fn id_column() -> ItemColumn {
+        ItemColumn::Id
+    }
+
+    /// 填充创建/更新字段
+    async fn fill_store_model(
         _platform: &PlatformEnum,
         dto: &ItemStoreDTO,
+        model: &mut ItemActiveModel,
+        origin: Option<&ItemModel>,
         state: &AppState,
-    ) -> HandleResult<ItemModel> {
-        let id: i64 = dto.id;
-        let is_create = id <= 0;
-
-        let (old_parent_id, old_kind_id, current_version_no, mut model) = if is_create {
-            (
-                0,
-                0,
-                0,
-                ItemActiveModel {
-                    ..Default::default()
-                },
-            )
-        } else {
-            let model = Self::fetch_by_id(id, state).await?;
-            let current_version_no = model.version_no.unwrap_or(0);
-            (
-                model.parent_id,
-                model.kind_id,
-                current_version_no,
-                model.into(),
-            )
-        };
-
-        // 检查版本号
-        if let Some(version_no) = dto.version_no {
-            if !is_create && version_no.ne(&current_version_no) {
-                let err = AppError::BadRequest(String::from("版本号错误"));
-                return Err(err);
-            }
-        }
-        model.version_no = Set(Some(current_version_no + 1));
-
-        let db = &state.db;
-
-        let mut new_kind_id: i64 = 0;
-        if let Some(opt_kind_id) = dto.kind_id {
-            new_kind_id = opt_kind_id;
-        }
+    ) -> HandleResult<()> {
+        let new_kind_id = dto.kind_id.unwrap_or(0);
         if new_kind_id < 1 {
             let err = AppError::BadRequest(String::from("参数 kind_id 必须大于0"));
             return Err(err);
         }
-        let kind = KindService::fetch_by_id(new_kind_id, state).await;
-        if kind.is_err() {
-            let err = AppError::BadRequest(String::from("类型不存在"));
-            return Err(err);
-        }
-        let kind = kind.unwrap();
-        if kind.is_enabled == false {
+        let kind = KindService::fetch_by_id(new_kind_id, state)
+            .await
+            .map_err(|_| AppError::BadRequest(String::from("类型不存在")))?;
+        if !kind.is_enabled {
             let err = AppError::BadRequest(String::from("类型 未启用"));
             return Err(err);
         }
         model.app_id = Set(kind.app_id);
         model.kind_id = Set(new_kind_id);
 
-        let mut new_parent_id: i64 = 0;
         if let Some(pid) = dto.parent_id {
-            new_parent_id = pid;
-            if new_parent_id > 0 {
-                let parent = Self::fetch_by_id(new_parent_id, state).await?;
-                if parent.is_enabled == false {
+            if pid > 0 {
+                if let Some(origin) = origin
+                    && !Self::can_move(origin, ItemColumn::ParentId, pid, state).await?
+                {
+                    let err = AppError::BadRequest(String::from("不能移动到自身或下级"));
+                    return Err(err);
+                }
+                let parent = Self::fetch_by_id(pid, state).await?;
+                if !parent.is_enabled {
                     let err = AppError::BadRequest(String::from("父级 未启用"));
                     return Err(err);
                 }
-                model.parent_id = Set(new_parent_id);
+                model.parent_id = Set(pid);
+                model.level = Set(parent.level + 1);
             } else {
-                new_parent_id = 0;
                 model.parent_id = Set(0);
+                model.level = Set(ROOT_LEVEL);
             }
+        } else if origin.is_none() {
+            model.level = Set(ROOT_LEVEL);
         }
 
-        let mut filter_extends = HashMap::<String, String>::new();
-        filter_extends.insert("kind_id".to_string(), new_kind_id.to_string());
-        filter_extends.insert("parent_id".to_string(), new_parent_id.to_string());
-
         if let Some(name) = &dto.name {
-            let is_exists = Self::is_column_exist(
-                id,
-                ItemColumn::Name,
-                sea_orm::Value::from(name),
-                &filter_extends,
-                db,
-            )
-            .await?;
-            if is_exists {
-                let err = AppError::BadRequest(String::from("名称已存在"));
-                return Err(err);
-            }
             model.name = Set(name.clone());
         }
 
         if let Some(title) = &dto.title {
-            let is_exists = Self::is_column_exist(
-                id,
-                ItemColumn::Title,
-                sea_orm::Value::from(title),
-                &filter_extends,
-                db,
-            )
-            .await?;
-            if is_exists {
-                let err = AppError::BadRequest(String::from("标题已存在"));
-                return Err(err);
-            }
             model.title = Set(title.clone());
         }
 
@@ -172,95 +130,74 @@ impl ItemService {
             model.is_enabled = Set(is_enabled);
         }
 
-        let time = time_utils::current_time();
-        model.updated_at = Set(Some(time));
+        handle_ok(())
+    }
 
-        if is_create {
-            model.created_at = Set(Some(time));
-        }
+    /// 构建列表查询器
+    fn query_builder(
+        platform: &PlatformEnum,
+        dto: &ItemQueryDTO,
+    ) -> HandleResult<Select<ItemEntity>> {
+        let mut query = Self::scope_active_query();
+        query = query.order_by_desc(ItemColumn::Id);
 
-        let editor = dto.editor.clone();
-        model.editor_type = Set(editor.editor_type.string_value());
-        // model.editor_id = Set(editor.editor_id);
+        if let Some(keyword) = &dto.keyword {
+            let condition = Condition::any()
+                .add(ItemColumn::Name.contains(keyword))
+                .add(ItemColumn::Title.contains(keyword));
+            query = query.filter(condition);
+        }
 
-        let kind_ids = vec![old_kind_id, new_kind_id];
-        let parent_ids = vec![old_parent_id, new_parent_id];
+        if let Some(app_id) = dto.app_id {
+            query = query.filter(ItemColumn::AppId.eq(app_id));
+        }
 
-        let txn = db.begin().await?;
-        let model = model.save(&txn).await?;
-        let model = model.try_into_model()?;
-        Self::batch_upload_count_in_parents(parent_ids, state).await?;
-        Self::batch_upload_count_in_kinds(kind_ids, state).await?;
-        txn.commit().await?;
+        if let Some(kind_id) = dto.kind_id {
+            query = query.filter(ItemColumn::KindId.eq(kind_id));
+        }
 
-        handle_ok(model)
-    }
+        if let Some(parent_id) = dto.parent_id {
+            let pid = if parent_id > 0 { parent_id } else { 0 };
+            query = query.filter(ItemColumn::ParentId.eq(pid));
+        }
 
-    /// 检查字段值是否唯一（查询）
-    async fn is_column_exist(
-        id: i64,
-        column: ItemColumn,
-        value: sea_orm::Value,
-        extends: &HashMap<String, String>,
-        db: &DatabaseConnection,
-    ) -> HandleResult<bool> {
-        let mut query = Self::scope_active_query()
-            .select_only()
-            .column(ItemColumn::Id)
-            .filter(column.eq(value));
+        if let Some(title) = &dto.title {
+            query = query.filter(ItemColumn::Title.contains(title));
+        }
 
-        if let Some(id_str) = extends.get("kind_id") {
-            let kind_id = id_str.parse::<i64>().unwrap_or(0);
-            if kind_id > 0 {
-                query = query.filter(ItemColumn::KindId.eq(kind_id));
-            }
+        if *platform == PlatformEnum::Open {
+            query = query.filter(ItemColumn::IsEnabled.eq(true));
+        } else if let Some(enabled) = dto.is_enabled {
+            query = query.filter(ItemColumn::IsEnabled.eq(enabled));
         }
 
-        if let Some(id_str) = extends.get("parent_id") {
-            let parent_id = id_str.parse::<i64>().unwrap_or(0);
-            if parent_id > 0 {
-                query = query.filter(ItemColumn::ParentId.eq(parent_id));
-            } else {
-                query = query.filter(ItemColumn::ParentId.eq(0));
-            }
+        if let Some(time) = dto.created_start_time {
+            query = query.filter(ItemColumn::CreatedAt.gte(time));
         }
 
-        if id > 0 {
-            query = query.filter(ItemColumn::Id.ne(id));
+        if let Some(time) = dto.created_end_time {
+            query = query.filter(ItemColumn::CreatedAt.lte(time));
         }
-        let count = query.count(db).await?;
 
-        handle_ok(count > 0)
+        handle_ok(query)
     }
 
-    /// 检查字段值是否唯一
-    pub async fn field_unique(dto: &FieldValueUniqueDTO, state: &AppState) -> HandleResult<bool> {
-        let _id = dto.skip_id;
-        let db = &state.db;
-
-        let field_name = dto.field_name.to_owned();
-        let column = match field_name.to_lowercase().as_str() {
-            "name" => ItemColumn::Name,
-            "title" => ItemColumn::Title,
-            _ => {
-                let err = AppError::BadRequest(String::from("无效的字段"));
-                return Err(err);
-            }
-        };
-
-        let field_value = dto.field_value.to_owned();
-        let value = sea_orm::Value::from(field_value);
-
-        let filter_extends = dto
-            .extends
-            .clone()
-            .unwrap_or(HashMap::<String, String>::new());
-        let exist = Self::is_column_exist(0, column, value, &filter_extends, db).await?;
-        handle_ok(exist != true)
+    /// Model 转换为 VO
+    fn master_vo(
+        view_enum: &ViewModeEnum,
+        editor: &EditorCurrentDTO,
+        model: &ItemModel,
+    ) -> ItemMasterVO {
+        let mut vo = ItemMasterVO::mode_into(view_enum, model);
+        if *view_enum == ViewModeEnum::ManagerList || *view_enum == ViewModeEnum::ManagerDetail {
+            vo.can_update = Some(Self::can_update(editor, model));
+            vo.can_delete = Some(Self::can_delete(editor, model));
+        }
+        vo
     }
 
     /// 查询选项
-    pub async fn query_options(
+    async fn query_options(
         platform: &PlatformEnum,
         state: &AppState,
     ) -> HandleResult<ItemQueryOptionVO> {
@@ -283,7 +220,7 @@ impl ItemService {
     }
 
     /// 表单选项
-    pub async fn form_options(
+    async fn form_options(
         platform: &PlatformEnum,
         state: &AppState,
     ) -> HandleResult<ItemFormOptionVO> {
@@ -305,289 +242,385 @@ impl ItemService {
         handle_ok(vo)
     }
 
-    /// 修改布尔值字段
-    pub async fn update_bool_field(
-        dto: &FieldBoolUpdateDTO,
-        state: &AppState,
-    ) -> HandleResult<bool> {
-        let id = dto.id;
-        if id < 1 {
-            let err = AppError::BadRequest(String::from("参数ID错误"));
-            return Err(err);
-        }
-        let db = &state.db;
-
-        let model = Self::fetch_by_id(id, state).await?;
-        let mut model: ItemActiveModel = model.into();
+    /// 唯一性校验字段
+    fn unique_columns() -> Vec<(ItemColumn, AppError)> {
+        vec![
+            (ItemColumn::Name, CoreErrorEnum::NameExists.into()),
+            (ItemColumn::Title, CoreErrorEnum::TitleExists.into()),
+        ]
+    }
 
-        let field_name = dto.field_name.to_owned();
-        let bool_value = dto.field_value;
-        match field_name.to_lowercase().as_str() {
-            "is_enabled" | "enabled" => {
-                model.is_enabled = Set(bool_value);
-            }
-            _ => {
-                let err = AppError::BadRequest(String::from("更新字段错误"));
-                return Err(err);
+    /// 名称和标题在同一类型的同一父级下唯一
+    fn unique_scope(
+        mut query: Select<ItemEntity>,
+        _column: &ItemColumn,
+        extends: &HashMap<String, String>,
+    ) -> Select<ItemEntity> {
+        if let Some(id_str) = extends.get("kind_id") {
+            let kind_id = id_str.parse::<i64>().unwrap_or(0);
+            if kind_id > 0 {
+                query = query.filter(ItemColumn::KindId.eq(kind_id));
             }
-        };
-
-        let now = time_utils::current_time();
-        model.updated_at = Set(Some(now));
+        }
 
-        let editor = dto.editor.clone();
-        model.editor_type = Set(editor.editor_type.string_value());
-        // model.editor_id = Set(editor.editor_id);
+        if let Some(id_str) = extends.get("parent_id") {
+            let parent_id = id_str.parse::<i64>().unwrap_or(0).max(0);
+            query = query.filter(ItemColumn::ParentId.eq(parent_id));
+        }
 
-        let _ = model.save(db).await?;
+        query
+    }
 
-        handle_ok(true)
+    /// 保存时唯一性校验的扩展参数
+    fn store_unique_extends(model: &ItemActiveModel) -> HashMap<String, String> {
+        let mut extends = HashMap::new();
+        if let Some(kind_id) = model.kind_id.try_as_ref() {
+            extends.insert("kind_id".to_string(), kind_id.to_string());
+        }
+        let parent_id = model.parent_id.try_as_ref().copied().unwrap_or(0);
+        extends.insert("parent_id".to_string(), parent_id.to_string());
+        extends
     }
 
-    /// 查看
-    pub async fn view(
-        platform: &PlatformEnum,
-        dto: &ModelViewDTO<ItemLoadEnum>,
-        state: &AppState,
-    ) -> HandleResult<ItemMasterVO> {
-        let id = dto.id;
-        if id < 1 {
-            let err = AppError::BadRequest(String::from("参数ID错误"));
-            return Err(err);
+    /// 导入时按 `parent_path` 引用父级，如 `electronics/phones`
+    fn import_reference(dto: &ItemStoreDTO, record: &HashMap<String, String>) -> Option<String> {
+        let path = Self::import_parent_path(record);
+        if path.is_empty() {
+            return None;
         }
+        Some(format!("{}:{}", dto.kind_id.unwrap_or(0), path))
+    }
 
-        let model = Self::fetch_by_id(id, state).await?;
+    /// 导入记录键为类型ID和名称路径
+    fn import_key(dto: &ItemStoreDTO, record: &HashMap<String, String>) -> Option<String> {
+        let name = dto.name.as_deref()?;
+        let path = Self::import_parent_path(record);
+        let key = if path.is_empty() {
+            format!("{}:{}", dto.kind_id.unwrap_or(0), name)
+        } else {
+            format!("{}:{}/{}", dto.kind_id.unwrap_or(0), path, name)
+        };
+        Some(key)
+    }
 
-        let view_enum = ViewModeEnum::platform_to_detail_mode(platform);
-        if view_enum == ViewModeEnum::OpenDetail {
-            if !model.is_enabled {
-                let err = AppError::NotFound(String::from("访问记录不存在"));
-                return Err(err);
-            }
+    /// 导入时按名称路径逐级查找父级，父级在本次导入中时直接使用其ID
+    async fn import_resolve(
+        dto: &mut ItemStoreDTO,
+        record: &HashMap<String, String>,
+        reference_id: Option<i64>,
+        state: &AppState,
+    ) -> HandleResult<()> {
+        let path = Self::import_parent_path(record);
+        if path.is_empty() {
+            return handle_ok(());
         }
-
-        let mut vo: ItemMasterVO = ItemMasterVO::mode_into(&view_enum, &model);
-        if let Some(load_models) = dto.load_models.clone() {
-            for enums in load_models {
-                match enums {
-                    // ItemLoadEnum::Editor => {
-                    //     vo.editor = EditorService::load_by_id(vo.editor_id.clone(), state).await?;
-                    // }
-                    ItemLoadEnum::App => {
-                        vo.app = AppService::load_by_id(vo.app_id.clone(), state).await?;
-                    }
-                    ItemLoadEnum::Kind => {
-                        vo.kind = KindService::load_by_id(vo.kind_id.clone(), state).await?;
-                    }
-                    ItemLoadEnum::Parent => {
-                        vo.parent = Self::load_by_id(vo.parent_id.clone(), state).await?;
-                    }
-                    _ => {}
-                }
-            }
+        if let Some(id) = reference_id {
+            dto.parent_id = Some(id);
+            return handle_ok(());
         }
 
-        let editor = dto.editor.clone();
-
-        if *platform == PlatformEnum::Manager {
-            vo.can_update = Some(true);
-            vo.can_delete = Some(Self::can_delete(&editor, &model));
+        let kind_id = dto.kind_id.unwrap_or(0);
+        let mut parent_id: i64 = 0;
+        for name in path.split('/') {
+            let model = Self::scope_active_query()
+                .filter(ItemColumn::KindId.eq(kind_id))
+                .filter(ItemColumn::ParentId.eq(parent_id))
+                .filter(ItemColumn::Name.eq(name))
+                .one(&state.db)
+                .await?;
+            parent_id = match model {
+                Some(model) => model.id,
+                None => {
+                    let message = format!("父级 {} 不存在", path);
+                    return Err(AppError::BadRequest(message));
+                }
+            };
         }
+        dto.parent_id = Some(parent_id);
 
-        handle_ok(vo)
+        handle_ok(())
     }
 
-    /// 分页查询
-    pub async fn paginage(
-        platform: &PlatformEnum,
-        dto: &ItemQueryDTO,
-        state: &AppState,
-    ) -> HandleResult<PaginateResultVO<ItemMasterVO>> {
-        let db = &state.db;
-        let page = dto.page;
-        let page_size = dto.page_size;
-
-        let editor = dto.editor.clone();
-        let view_enum = ViewModeEnum::platform_to_list_mode(platform);
-
-        let mut cols = vec![
+    /// 列表允许排序的列
+    fn sort_columns() -> Vec<ItemColumn> {
+        vec![
             ItemColumn::Id,
-            ItemColumn::EditorId,
-            ItemColumn::EditorType,
-            ItemColumn::AppId,
-            ItemColumn::KindId,
             ItemColumn::Name,
             ItemColumn::Title,
-            ItemColumn::Description,
-            ItemColumn::Icon,
-            ItemColumn::ParentId,
             ItemColumn::Level,
-            ItemColumn::IsDirectory,
             ItemColumn::Sort,
-            ItemColumn::IsEnabled,
-        ];
-        if view_enum == ViewModeEnum::ManagerList {
-            cols.push(ItemColumn::CreatedAt);
-            cols.push(ItemColumn::UpdatedAt);
-        }
-
-        let query = Self::query_builder(platform, dto).await?;
-        let paginator = query.paginate(db, page_size);
-        let total = paginator.num_items_and_pages().await?;
-        let models = paginator.fetch_page(page - 1).await?;
-        let len = models.len();
-        let mut list: Vec<ItemMasterVO> = Vec::with_capacity(len);
-        let mut editor_ids: Vec<i64> = Vec::with_capacity(len);
-        let mut app_ids: Vec<i64> = Vec::with_capacity(len);
-        let mut kind_ids: Vec<i64> = Vec::with_capacity(len);
-        let mut parent_ids: Vec<i64> = Vec::with_capacity(len);
-        for model in models.iter() {
-            editor_ids.push(model.editor_id);
-            app_ids.push(model.app_id);
-            kind_ids.push(model.kind_id);
-            parent_ids.push(model.parent_id);
-            let mut vo: ItemMasterVO = ItemMasterVO::mode_into(&view_enum, &model);
-            if *platform == PlatformEnum::Manager {
-                vo.can_update = Some(true);
-                vo.can_delete = Some(Self::can_delete(&editor, &model));
-            }
-            list.push(vo);
-        }
-
-        if let Some(load_models) = dto.load_models.clone() {
-            for enums in load_models {
-                match enums {
-                    // ItemLoadEnum::Editor => {
-                    //     let map = EditorService::batch_load_by_ids(&editor_ids, state).await?;
-                    //     for vo in list.iter_mut() {
-                    //         vo.editor = map.get(&vo.editor_id).cloned();
-                    //     }
-                    // }
-                    ItemLoadEnum::App => {
-                        let map = AppService::batch_load_by_ids(&app_ids, state).await?;
-                        for vo in list.iter_mut() {
-                            vo.app = map.get(&vo.app_id).cloned();
-                        }
-                    }
-                    ItemLoadEnum::Kind => {
-                        let map = KindService::batch_load_by_ids(&kind_ids, state).await?;
-                        for vo in list.iter_mut() {
-                            vo.kind = map.get(&vo.kind_id).cloned();
-                        }
-                    }
-                    ItemLoadEnum::Parent => {
-                        let map = Self::batch_load_by_ids(&parent_ids, state).await?;
-                        for vo in list.iter_mut() {
-                            vo.parent = map.get(&vo.parent_id).cloned();
-                        }
-                    }
-                    _ => {}
-                }
-            }
-        }
+            ItemColumn::CreatedAt,
+            ItemColumn::UpdatedAt,
+        ]
+    }
 
-        let vo = PaginateResultVO {
-            page_size,
-            current_page: page,
-            total: total.number_of_items,
-            list,
-        };
+    /// 列表允许筛选的列及操作符
+    fn filter_columns() -> Vec<(ItemColumn, Vec<FilterOperatorEnum>)> {
+        vec![
+            (ItemColumn::Id, FilterOperatorEnum::list()),
+            (ItemColumn::AppId, FilterOperatorEnum::list()),
+            (ItemColumn::KindId, FilterOperatorEnum::list()),
+            (ItemColumn::ParentId, FilterOperatorEnum::list()),
+            (ItemColumn::Name, FilterOperatorEnum::text()),
+            (ItemColumn::Title, FilterOperatorEnum::text()),
+            (ItemColumn::Level, FilterOperatorEnum::comparison()),
+            (ItemColumn::IsDirectory, vec![FilterOperatorEnum::Eq]),
+            (ItemColumn::IsEnabled, vec![FilterOperatorEnum::Eq]),
+            (ItemColumn::Sort, FilterOperatorEnum::comparison()),
+            (ItemColumn::CreatedAt, FilterOperatorEnum::range()),
+            (ItemColumn::UpdatedAt, FilterOperatorEnum::range()),
+        ]
+    }
 
-        handle_ok(vo)
+    /// 管理端默认加载的关联数据
+    fn manager_load_models(view_enum: &ViewModeEnum) -> Vec<ItemLoadEnum> {
+        match view_enum {
+            ViewModeEnum::ManagerList => vec![ItemLoadEnum::Editor],
+            _ => vec![],
+        }
     }
 
-    /// 构建列表查询器
-    async fn query_builder(
-        platform: &PlatformEnum,
-        dto: &ItemQueryDTO,
-    ) -> HandleResult<Select<ItemEntity>> {
-        let mut query = Self::scope_active_query();
-        query = query.order_by_desc(ItemColumn::Id);
+    /// `include` 参数允许加载的关联数据
+    fn include_options() -> Vec<(&'static str, ItemLoadEnum)> {
+        vec![
+            ("app", ItemLoadEnum::App),
+            ("kind", ItemLoadEnum::Kind),
+            ("parent", ItemLoadEnum::Parent),
+        ]
+    }
 
-        if let Some(keyword) = dto.keyword.clone() {
-            let condition = Condition::any()
-                .add(ItemColumn::Name.contains(&keyword))
-                .add(ItemColumn::Title.contains(&keyword));
-            query = query.filter(condition);
+    /// 批量移动允许修改所属类型和父级
+    fn move_column(field_name: &str) -> Option<ItemColumn> {
+        match field_name.to_lowercase().as_str() {
+            "kind_id" => Some(ItemColumn::KindId),
+            "parent_id" => Some(ItemColumn::ParentId),
+            _ => None,
         }
+    }
 
-        if let Some(app_id) = dto.app_id.clone() {
-            query = query.filter(ItemColumn::AppId.eq(app_id));
+    /// 批量移动前检查目标类型或父级，修改类型时同时修改所属 App
+    async fn move_check(
+        column: ItemColumn,
+        value: i64,
+        state: &AppState,
+    ) -> HandleResult<Vec<(ItemColumn, Value)>> {
+        match column {
+            ItemColumn::KindId => {
+                let kind = KindService::fetch_by_id(value, state)
+                    .await
+                    .map_err(|_| AppError::BadRequest(String::from("类型不存在")))?;
+                if !kind.is_enabled {
+                    let err = AppError::BadRequest(String::from("类型 未启用"));
+                    return Err(err);
+                }
+                handle_ok(vec![
+                    (ItemColumn::KindId, Value::from(value)),
+                    (ItemColumn::AppId, Value::from(kind.app_id)),
+                ])
+            }
+            _ => {
+                let mut level = ROOT_LEVEL;
+                if value > 0 {
+                    let parent = Self::fetch_by_id(value, state).await?;
+                    if !parent.is_enabled {
+                        let err = AppError::BadRequest(String::from("父级 未启用"));
+                        return Err(err);
+                    }
+                    level = parent.level + 1;
+                }
+                handle_ok(vec![
+                    (ItemColumn::ParentId, Value::from(value)),
+                    (ItemColumn::Level, Value::from(level)),
+                ])
+            }
         }
+    }
 
-        if let Some(kind_id) = dto.kind_id.clone() {
-            query = query.filter(ItemColumn::KindId.eq(kind_id));
+    /// 不能移动到自身或自身的下级，逐级向上查找目标父级的祖先
+    async fn can_move(
+        model: &ItemModel,
+        column: ItemColumn,
+        value: i64,
+        state: &AppState,
+    ) -> HandleResult<bool> {
+        if !matches!(column, ItemColumn::ParentId) {
+            return handle_ok(true);
         }
 
-        if let Some(parent_id) = dto.parent_id.clone() {
-            let pid = if parent_id > 0 { parent_id } else { 0 };
-            query = query.filter(ItemColumn::ParentId.eq(pid));
+        let mut visited: Vec<i64> = Vec::new();
+        let mut parent_id = value;
+        while parent_id > 0 {
+            // 已有数据存在环时同样不允许移动
+            if parent_id == model.id || visited.contains(&parent_id) {
+                return handle_ok(false);
+            }
+            visited.push(parent_id);
+            parent_id = ItemEntity::find()
+                .select_only()
+                .column(ItemColumn::ParentId)
+                .filter(ItemColumn::Id.eq(parent_id))
+                .into_tuple::<i64>()
+                .one(&state.db)
+                .await?
+                .unwrap_or(0);
         }
 
-        if let Some(title) = dto.title.clone() {
-            query = query.filter(ItemColumn::Title.contains(&title));
-        }
+        handle_ok(true)
+    }
 
-        if *platform == PlatformEnum::Open {
-            query = query.filter(ItemColumn::IsEnabled.eq(true));
-        } else if let Some(enabled) = dto.is_enabled {
-            query = query.filter(ItemColumn::IsEnabled.eq(enabled));
-        }
+    /// 字段裁剪时加载关联数据和删除权限判断用到的列
+    fn relation_columns() -> Vec<ItemColumn> {
+        vec![
+            ItemColumn::AppId,
+            ItemColumn::KindId,
+            ItemColumn::ParentId,
+            ItemColumn::ChildrenCount,
+            ItemColumn::MorphCount,
+        ]
+    }
 
-        if let Some(time) = dto.created_start_time.clone() {
-            query = query.filter(ItemColumn::CreatedAt.gte(time));
+    /// 是否可以删除记录
+    fn can_delete(editor: &EditorCurrentDTO, model: &ItemModel) -> bool {
+        let has_children = model.children_count.is_none_or(|count| count > 0);
+        if has_children {
+            return false;
         }
 
-        if let Some(time) = dto.created_end_time.clone() {
-            query = query.filter(ItemColumn::CreatedAt.lte(time));
+        let has_morphs = model.morph_count.is_none_or(|count| count > 0);
+        if has_morphs {
+            return false;
         }
 
-        handle_ok(query)
+        editor.editor_type == EditorTypeEnum::Admin
     }
 
-    /// 构建查询器
-    fn scope_active_query() -> Select<ItemEntity> {
-        ItemEntity::find().filter(ItemColumn::IsDeleted.eq(false))
+    /// 恢复前检查所属类型和父级是否存在
+    async fn restore_check(model: &ItemModel, state: &AppState) -> HandleResult<()> {
+        if KindService::fetch_by_id(model.kind_id, state)
+            .await
+            .is_err()
+        {
+            let err = AppError::BadRequest(String::from("类型不存在"));
+            return Err(err);
+        }
+
+        if model.parent_id > 0 && Self::fetch_by_id(model.parent_id, state).await.is_err() {
+            let err = AppError::BadRequest(String::from("父级不存在"));
+            return Err(err);
+        }
+
+        handle_ok(())
     }
 
-    /// 根据ID查询
-    pub async fn fetch_by_id(id: i64, state: &AppState) -> HandleResult<ItemModel> {
-        let db = &state.db;
-        let model = Self::scope_active_query()
-            .filter(ItemColumn::Id.eq(id))
-            .one(db)
-            .await?
-            .ok_or_else(|| AppError::BadRequest(String::from("访问记录不存在")))?;
+    /// 加载关联数据
+    async fn load_relations(
+        list: &mut [ItemMasterVO],
+        load_models: &[ItemLoadEnum],
+        state: &AppState,
+    ) -> HandleResult<()> {
+        for enums in load_models {
+            match enums {
+                ItemLoadEnum::Editor => {
+                    let editor_ids: Vec<Uuid> = list.iter().map(|vo| vo.editor_id).collect();
+                    let map = EditorService::batch_load_by_uuids(&editor_ids, state).await?;
+                    for vo in list.iter_mut() {
+                        vo.editor = map.get(&vo.editor_id.to_string()).cloned();
+                    }
+                }
+                ItemLoadEnum::App => {
+                    let app_ids: Vec<i64> = list.iter().map(|vo| vo.app_id).collect();
+                    let map = AppService::batch_load_by_ids(&app_ids, state).await?;
+                    for vo in list.iter_mut() {
+                        vo.app = map.get(&vo.app_id).cloned();
+                    }
+                }
+                ItemLoadEnum::Kind => {
+                    let kind_ids: Vec<i64> = list.iter().map(|vo| vo.kind_id).collect();
+                    let map = KindService::batch_load_by_ids(&kind_ids, state).await?;
+                    for vo in list.iter_mut() {
+                        vo.kind = map.get(&vo.kind_id).cloned();
+                    }
+                }
+                ItemLoadEnum::Parent => {
+                    let parent_ids: Vec<i64> = list.iter().map(|vo| vo.parent_id).collect();
+                    let map = Self::batch_load_by_ids(&parent_ids, state).await?;
+                    for vo in list.iter_mut() {
+                        vo.parent = map.get(&vo.parent_id).cloned();
+                    }
+                }
+                _ => {}
+            }
+        }
 
-        handle_ok(model)
+        handle_ok(())
     }
 
-    /// 软删除记录
-    pub async fn logic_delete(dto: &ModelLogicDeleteDTO, state: &AppState) -> HandleResult<()> {
-        if dto.id < 1 {
-            return handle_ok(());
+    /// 保存后更新父级和类型的记录数量
+    async fn after_store(
+        _dto: Option<&ItemStoreDTO>,
+        model: &ItemModel,
+        origin: Option<&ItemModel>,
+        txn: &DatabaseTransaction,
+    ) -> HandleResult<()> {
+        let (old_parent_id, old_kind_id) =
+            origin.map_or((0, 0), |origin| (origin.parent_id, origin.kind_id));
+        if let Some(origin) = origin
+            && origin.level != model.level
+        {
+            Self::shift_level_in_children(model.id, model.level - origin.level, &[], txn).await?;
         }
+        Self::batch_upload_count_in_parents(vec![old_parent_id, model.parent_id], txn).await?;
+        Self::batch_upload_count_in_kinds(vec![old_kind_id, model.kind_id], txn).await
+    }
 
-        let db = &state.db;
-        let result = Self::fetch_by_id(dto.id, state).await;
-        if result.is_err() {
-            return handle_ok(());
+    /// 删除后更新父级和类型的记录数量
+    async fn after_delete(model: &ItemModel, txn: &DatabaseTransaction) -> HandleResult<()> {
+        Self::batch_upload_count_in_parents(vec![model.parent_id], txn).await?;
+        Self::batch_upload_count_in_kinds(vec![model.kind_id], txn).await
+    }
+
+    /// 批量操作后一次性更新父级和类型的记录数量
+    async fn after_bulk(
+        models: &[ItemModel],
+        origins: &[ItemModel],
+        txn: &DatabaseTransaction,
+    ) -> HandleResult<()> {
+        let mut parent_ids: Vec<i64> = Vec::new();
+        let mut kind_ids: Vec<i64> = Vec::new();
+        for model in models.iter().chain(origins) {
+            if !parent_ids.contains(&model.parent_id) {
+                parent_ids.push(model.parent_id);
+            }
+            if !kind_ids.contains(&model.kind_id) {
+                kind_ids.push(model.kind_id);
+            }
         }
-        let model = result.unwrap();
-        let editor = dto.editor.clone();
-        if Self::can_delete(&editor, &model) == false {
-            let err = AppError::BadRequest(String::from("无权限删除"));
-            return Err(err);
+        // 移动后下级的层级随之调整，同批次移动的记录已重新计算，跳过
+        let moved_ids: Vec<i64> = models.iter().map(|model| model.id).collect();
+        for (model, origin) in models.iter().zip(origins) {
+            if model.level != origin.level {
+                let delta = model.level - origin.level;
+                Self::shift_level_in_children(model.id, delta, &moved_ids, txn).await?;
+            }
         }
-        let mut model: ItemActiveModel = model.into();
-        model.editor_type = Set(editor.editor_type.string_value());
-        // model.editor_id = Set(editor.editor_id);
-        model.is_deleted = Set(Some(true));
-        let now = time_utils::current_time();
-        model.deleted_at = Set(Some(now));
-        let _ = model.save(db).await?;
+        Self::batch_upload_count_in_parents(parent_ids, txn).await?;
+        Self::batch_upload_count_in_kinds(kind_ids, txn).await
+    }
+}
 
-        handle_ok(())
+impl ItemService {
+    /// 导入行中的父级名称路径，去除多余的分隔符
+    fn import_parent_path(record: &HashMap<String, String>) -> String {
+        record
+            .get("parent_path")
+            .map(|path| {
+                path.split('/')
+                    .map(str::trim)
+                    .filter(|name| !name.is_empty())
+                    .collect::<Vec<&str>>()
+                    .join("/")
+            })
+            .unwrap_or_default()
     }
 
     /// 根据ID查询
@@ -595,7 +628,7 @@ impl ItemService {
         platform: &PlatformEnum,
         state: &AppState,
     ) -> HandleResult<Vec<SelectOptionModel>> {
-        let db = &state.db;
+        let db = state.db_read();
         let mut query = Self::scope_active_query();
         if *platform == PlatformEnum::Open {
             query = query.filter(ItemColumn::IsEnabled.eq(true));
@@ -606,52 +639,19 @@ impl ItemService {
         handle_ok(list)
     }
 
-    /// SelectOptionModel 列表
-    pub async fn fetch_option_list(
-        platform: &PlatformEnum,
-        state: &AppState,
-    ) -> HandleResult<Vec<SelectOptionModel>> {
-        let db = &state.db;
-        let mut query = Self::scope_active_query();
-        if *platform == PlatformEnum::Open {
-            query = query.filter(ItemColumn::IsEnabled.eq(true));
-        }
-        let models = query.all(db).await?;
-        let list: Vec<SelectOptionModel> = models.into_iter().map(|model| model.into()).collect();
-        handle_ok(list)
-    }
-
-    /// 查询关联的单个记录
-    pub async fn load_by_id(id: i64, state: &AppState) -> HandleResult<Option<ItemLoadVO>> {
-        if id < 1 {
-            return handle_ok(None);
-        }
-
-        let db = &state.db;
-        let model = ItemEntity::find()
-            .filter(ItemColumn::Id.eq(id))
-            .one(db)
-            .await?;
-        if let Some(model) = model {
-            let vo: ItemLoadVO = model.into();
-            handle_ok(Some(vo))
-        } else {
-            handle_ok(None)
-        }
-    }
-
     /// 批量查询关联的记录
     pub async fn batch_load_by_ids(
-        ids: &Vec<i64>,
+        ids: &[i64],
         state: &AppState,
     ) -> HandleResult<HashMap<i64, ItemLoadVO>> {
-        let filted_ids: Vec<i64> = ids.into_iter().filter(|&&id| id > 0).cloned().collect();
+        let filted_ids: Vec<i64> = ids.iter().filter(|&&id| id > 0).cloned().collect();
         if filted_ids.is_empty() {
             return handle_ok(HashMap::<i64, ItemLoadVO>::new());
         }
 
-        let db = &state.db;
-        let models = ItemEntity::find()
+        let db = state.db_read();
+        let query = TenantService::scope_query(ItemEntity::find(), Some(ItemColumn::TenantId));
+        let models = query
             .filter(ItemColumn::Id.is_in(filted_ids))
             .all(db)
             .await?;
@@ -664,16 +664,51 @@ impl ItemService {
         handle_ok(map)
     }
 
-    /// 批量更新Kind的记录数量
-    async fn batch_upload_count_in_parents(
+    /// 逐级调整下级的层级，`skip_ids` 中的记录及其下级不调整
+    async fn shift_level_in_children<C: ConnectionTrait>(
+        id: i64,
+        delta: i32,
+        skip_ids: &[i64],
+        db: &C,
+    ) -> HandleResult<()> {
+        let mut parent_ids = vec![id];
+        let mut visited: Vec<i64> = vec![id];
+        while !parent_ids.is_empty() {
+            let ids: Vec<i64> = ItemEntity::find()
+                .select_only()
+                .column(ItemColumn::Id)
+                .filter(ItemColumn::ParentId.is_in(parent_ids))
+                .into_tuple::<i64>()
+                .all(db)
+                .await?
+                .into_iter()
+                .filter(|id| !skip_ids.contains(id) && !visited.contains(id))
+                .collect();
+            if ids.is_empty() {
+                break;
+            }
+
+            ItemEntity::update_many()
+                .col_expr(ItemColumn::Level, Expr::col(ItemColumn::Level).add(delta))
+                .filter(ItemColumn::Id.is_in(ids.clone()))
+                .exec(db)
+                .await?;
+            visited.extend(ids.iter().copied());
+            parent_ids = ids;
+        }
+
+        handle_ok(())
+    }
+
+    /// 批量更新父级的子级数量
+    async fn batch_upload_count_in_parents<C: ConnectionTrait>(
         parent_ids: Vec<i64>,
-        state: &AppState,
+        db: &C,
     ) -> HandleResult<()> {
-        let parent_ids: Vec<i64> = parent_ids.iter().filter(|&&id| id > 0).cloned().collect();
+        let parent_ids: Vec<i64> = parent_ids.into_iter().filter(|&id| id > 0).collect();
         if parent_ids.is_empty() {
             return handle_ok(());
         }
-        let db = &state.db;
         let models: Vec<ModelRelationCountDTO> = Self::scope_active_query()
             .select_only()
             .column_as(ItemColumn::ParentId, "relation_id")
@@ -683,17 +718,17 @@ impl ItemService {
             .into_model::<ModelRelationCountDTO>()
             .all(db)
             .await?;
-        let map: HashMap<i64, i16> = models
+        let map: HashMap<i64, i64> = models
             .into_iter()
-            .map(|model| (model.relation_id, model.item_count))
+            .filter_map(|model| Some((model.relation_id.active_int_id()?, model.item_count)))
             .collect();
 
         for id in parent_ids.iter() {
-            let count = map.get(id).unwrap_or(&0);
+            let count = *map.get(id).unwrap_or(&0) as i16;
 
             let _ = ItemEntity::update_many()
-                .col_expr(ItemColumn::ChildrenCount, Expr::value(*count))
-                .filter(ItemColumn::ParentId.eq(*id))
+                .col_expr(ItemColumn::ChildrenCount, Expr::value(count))
+                .filter(ItemColumn::Id.eq(*id))
                 .exec(db)
                 .await?;
         }
@@ -702,12 +737,14 @@ impl ItemService {
     }
 
     /// 批量更新Kind的记录数量
-    async fn batch_upload_count_in_kinds(kind_ids: Vec<i64>, state: &AppState) -> HandleResult<()> {
-        let kind_ids: Vec<i64> = kind_ids.iter().filter(|&&id| id > 0).cloned().collect();
+    async fn batch_upload_count_in_kinds<C: ConnectionTrait>(
+        kind_ids: Vec<i64>,
+        db: &C,
+    ) -> HandleResult<()> {
+        let kind_ids: Vec<i64> = kind_ids.into_iter().filter(|&id| id > 0).collect();
         if kind_ids.is_empty() {
             return handle_ok(());
         }
-        let db = &state.db;
         let models: Vec<ModelRelationCountDTO> = Self::scope_active_query()
             .select_only()
             .column_as(ItemColumn::KindId, "relation_id")
@@ -717,16 +754,16 @@ impl ItemService {
             .into_model::<ModelRelationCountDTO>()
             .all(db)
             .await?;
-        let map: HashMap<i64, i16> = models
+        let map: HashMap<i64, i64> = models
             .into_iter()
-            .map(|model| (model.relation_id, model.item_count))
+            .filter_map(|model| Some((model.relation_id.active_int_id()?, model.item_count)))
             .collect();
 
         for id in kind_ids.iter() {
-            let count = map.get(id).unwrap_or(&0);
+            let count = *map.get(id).unwrap_or(&0) as i16;
 
             let _ = KindEntity::update_many()
-                .col_expr(KindColumn::ItemCount, Expr::value(*count))
+                .col_expr(KindColumn::ItemCount, Expr::value(count))
                 .filter(KindColumn::Id.eq(*id))
                 .exec(db)
                 .await?;
@@ -734,25 +771,268 @@ impl ItemService {
 
         handle_ok(())
     }
+}
 
-    /// 是否可以删除记录
-    pub fn can_delete(editor: &EditorCurrentDTO, model: &ItemModel) -> bool {
-        let has_children = match model.children_count {
-            Some(count) => count > 0,
-            None => true,
-        };
-        if has_children {
-            return false;
+#[cfg(test)]
+mod tests {
+    use cms_core::domain::dto::{BulkUpdateDTO, ModelLogicDeleteDTO};
+    use cms_core::domain::response::AppResponse;
+    use cms_core::enums::PrimaryIdEnum;
+    use cms_core::fixture::config::FakerAppState;
+    use cms_core::service::ImportService;
+    use cms_core::utils::export_utils;
+
+    use super::*;
+    use crate::domain::form::ItemStoreForm;
+    use crate::fixture::{faker_app, faker_editor, faker_item, faker_kind};
+
+    fn move_dto(items: Vec<(i64, i64)>) -> BulkUpdateDTO<i64> {
+        BulkUpdateDTO {
+            editor: faker_editor(),
+            field_name: "parent_id".to_string(),
+            items,
         }
+    }
 
-        let has_morphs = match model.morph_count {
-            Some(count) => count > 0,
-            None => true,
-        };
-        if has_morphs {
-            return false;
+    /// 导入的 CSV：根分类、引用本次导入中父级的子分类、重复的记录键、同一父级下重复的标题
+    fn import_csv(app_id: i64, kind_id: i64) -> Vec<u8> {
+        let rows = [
+            "phones,Phones,",
+            "android,Android,phones",
+            "phones,Phones2,",
+            "tablets,Android,phones",
+            "iphones,Iphone,/phones/",
+        ];
+        let mut csv = String::from("app_id,kind_id,name,title,parent_path\n");
+        for row in rows {
+            csv.push_str(&format!("{},{},{}\n", app_id, kind_id, row));
+        }
+        csv.into_bytes()
+    }
+
+    fn error_message(err: CoreErrorEnum) -> Option<String> {
+        let err: AppError = err.into();
+        let response: AppResponse<HashMap<String, String>> = err.into();
+        response.message().map(String::from)
+    }
+
+    fn bulk_dto(ids: &[i64]) -> BulkUpdateDTO<()> {
+        BulkUpdateDTO {
+            editor: faker_editor(),
+            field_name: String::new(),
+            items: ids.iter().map(|id| (*id, ())).collect(),
         }
+    }
+
+    #[tokio::test]
+    async fn test_bulk_move_level() {
+        let state = FakerAppState::init().await;
+        let app = faker_app("shop", &state).await;
+        let kind = faker_kind(app.id, "category", &state).await;
+        let a = faker_item(kind.id, 0, "a", &state).await;
+        let b = faker_item(kind.id, a.id, "b", &state).await;
+        let c = faker_item(kind.id, b.id, "c", &state).await;
+        assert_eq!((a.level, b.level, c.level), (1, 2, 3));
+
+        // 不能移动到自身或下级
+        let res = ItemService::bulk_move(&move_dto(vec![(a.id, c.id), (b.id, b.id)]), &state)
+            .await
+            .unwrap();
+        assert_eq!(res.failure_count, 2);
+
+        // 移动后下级的层级随之调整
+        let res = ItemService::bulk_move(&move_dto(vec![(b.id, 0)]), &state)
+            .await
+            .unwrap();
+        assert_eq!(res.success_count, 1);
+        let b = ItemService::fetch_by_id(b.id, &state).await.unwrap();
+        let c = ItemService::fetch_by_id(c.id, &state).await.unwrap();
+        assert_eq!((b.level, c.level), (1, 2));
+        let a = ItemService::fetch_by_id(a.id, &state).await.unwrap();
+        assert_eq!(a.children_count, Some(0));
+
+        // 通过更新修改父级时同样校验
+        let dto = ItemStoreDTO {
+            id: b.id,
+            editor: faker_editor(),
+            kind_id: Some(kind.id),
+            parent_id: Some(c.id),
+            ..Default::default()
+        };
+        let res = ItemService::store(&PlatformEnum::Manager, &dto, &state).await;
+        assert!(matches!(res, Err(AppError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_bulk_move_unique() {
+        let state = FakerAppState::init().await;
+        let app = faker_app("shop", &state).await;
+        let kind = faker_kind(app.id, "category", &state).await;
+        let a = faker_item(kind.id, 0, "a", &state).await;
+        let b = faker_item(kind.id, 0, "b", &state).await;
+        let x = faker_item(kind.id, a.id, "x", &state).await;
+        let y = faker_item(kind.id, b.id, "x", &state).await;
+
+        // 目标父级下已有同名记录
+        let res = ItemService::bulk_move(&move_dto(vec![(y.id, a.id)]), &state)
+            .await
+            .unwrap();
+        assert_eq!(res.failure_count, 1);
+        let y = ItemService::fetch_by_id(y.id, &state).await.unwrap();
+        assert_eq!(y.parent_id, b.id);
+
+        // 同一批次中先移动的记录也参与校验
+        let res = ItemService::bulk_move(&move_dto(vec![(x.id, 0), (y.id, 0)]), &state)
+            .await
+            .unwrap();
+        assert_eq!((res.success_count, res.failure_count), (1, 1));
+        assert!(res.list[0].success);
+        assert!(!res.list[1].success);
+    }
+
+    #[tokio::test]
+    async fn test_bulk_restore_unique() {
+        let state = FakerAppState::init().await;
+        let app = faker_app("shop", &state).await;
+        let kind = faker_kind(app.id, "category", &state).await;
+        let parent = faker_item(kind.id, 0, "parent", &state).await;
+        let x = faker_item(kind.id, parent.id, "x", &state).await;
+        ItemService::bulk_delete(&bulk_dto(&[x.id]), &state)
+            .await
+            .unwrap();
+        let y = faker_item(kind.id, parent.id, "x", &state).await;
+        ItemService::bulk_delete(&bulk_dto(&[y.id]), &state)
+            .await
+            .unwrap();
+
+        // 回收站中的两条同名记录只能恢复一条
+        let res = ItemService::bulk_restore(&bulk_dto(&[x.id, y.id]), &state)
+            .await
+            .unwrap();
+        assert_eq!((res.success_count, res.failure_count), (1, 1));
+        assert!(res.list[0].success);
+        let parent = ItemService::fetch_by_id(parent.id, &state).await.unwrap();
+        assert_eq!(parent.children_count, Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_restore_counts() {
+        let state = FakerAppState::init().await;
+        let app = faker_app("shop", &state).await;
+        let kind = faker_kind(app.id, "category", &state).await;
+        let parent = faker_item(kind.id, 0, "parent", &state).await;
+        let x = faker_item(kind.id, parent.id, "x", &state).await;
+        faker_item(kind.id, parent.id, "y", &state).await;
+
+        let dto = ModelLogicDeleteDTO {
+            id: PrimaryIdEnum::BigInt(x.id),
+            editor: faker_editor(),
+        };
+        ItemService::logic_delete(&dto, &state).await.unwrap();
+        let parent_model = ItemService::fetch_by_id(parent.id, &state).await.unwrap();
+        let kind_model = KindService::fetch_by_id(kind.id, &state).await.unwrap();
+        assert_eq!(parent_model.children_count, Some(1));
+        assert_eq!(kind_model.item_count, Some(2));
+
+        // 恢复后重新统计父级和类型的记录数量
+        ItemService::restore(&dto, &state).await.unwrap();
+        let parent_model = ItemService::fetch_by_id(parent.id, &state).await.unwrap();
+        let kind_model = KindService::fetch_by_id(kind.id, &state).await.unwrap();
+        assert_eq!(parent_model.children_count, Some(2));
+        assert_eq!(kind_model.item_count, Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_import() {
+        let state = FakerAppState::init_file().await;
+        let app = faker_app("shop", &state).await;
+        let kind = faker_kind(app.id, "category", &state).await;
+
+        let csv = import_csv(app.id, kind.id);
+        let vo = ImportService::import::<ItemService, ItemStoreForm>(
+            &PlatformEnum::Manager,
+            &csv,
+            false,
+            &faker_editor(),
+            &state,
+        )
+        .await
+        .unwrap();
+        assert_eq!((vo.success_count, vo.failure_count), (3, 2));
+        let lines: Vec<(u64, bool)> = vo.list.iter().map(|row| (row.line, row.success)).collect();
+        assert_eq!(
+            lines,
+            [(2, true), (3, true), (4, false), (5, false), (6, true)]
+        );
+
+        // 父级在同一批次中未提交时，先提交当前批次再解析父级
+        let parent = ItemService::fetch_by_id(vo.list[0].id.unwrap(), &state)
+            .await
+            .unwrap();
+        let child = ItemService::fetch_by_id(vo.list[1].id.unwrap(), &state)
+            .await
+            .unwrap();
+        assert_eq!((child.parent_id, child.level), (parent.id, 2));
+        assert_eq!(parent.children_count, Some(2));
+
+        // 本次导入中重复的记录键
+        assert_eq!(
+            vo.list[2].message,
+            error_message(CoreErrorEnum::ImportDuplicate)
+        );
+
+        // 失败的行只回滚该行，同一批次中前后的行正常保存
+        assert_eq!(
+            vo.list[3].message,
+            error_message(CoreErrorEnum::TitleExists)
+        );
+        let names: Vec<String> = ItemService::scope_active_query()
+            .filter(ItemColumn::KindId.eq(kind.id))
+            .order_by_asc(ItemColumn::Id)
+            .all(&state.db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|model| model.name)
+            .collect();
+        assert_eq!(names, ["phones", "android", "iphones"]);
+    }
 
-        return editor.editor_type == EditorTypeEnum::Admin;
+    #[tokio::test]
+    async fn test_import_dry_run() {
+        let state = FakerAppState::init().await;
+        let app = faker_app("shop", &state).await;
+        let kind = faker_kind(app.id, "category", &state).await;
+
+        let mut csv = export_utils::CSV_BOM.to_vec();
+        csv.extend(import_csv(app.id, kind.id));
+        let vo = ImportService::import::<ItemService, ItemStoreForm>(
+            &PlatformEnum::Manager,
+            &csv,
+            true,
+            &faker_editor(),
+            &state,
+        )
+        .await
+        .unwrap();
+
+        // 试运行只返回每行的结果，不写入记录
+        assert!(vo.dry_run);
+        assert_eq!((vo.success_count, vo.failure_count), (4, 1));
+        let lines: Vec<(u64, bool)> = vo.list.iter().map(|row| (row.line, row.success)).collect();
+        assert_eq!(
+            lines,
+            [(2, true), (3, true), (4, false), (5, true), (6, true)]
+        );
+        assert!(vo.list.iter().all(|row| row.id.is_none()));
+        assert_eq!(
+            vo.list[2].message,
+            error_message(CoreErrorEnum::ImportDuplicate)
+        );
+        let count = ItemService::scope_active_query()
+            .count(&state.db)
+            .await
+            .unwrap();
+        assert_eq!(count, 0);
     }
 }
@@ -1,18 +1,34 @@
 use dotenvy::dotenv;
 use lapin::message::DeliveryResult;
+use salvo::http::header::CONTENT_TYPE;
+use salvo::prelude::*;
+use tracing::Instrument;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
 
 mod service;
 
-use crate::service::RabbitMQService;
-use cms_core::config::AppState;
+use crate::service::{DeliveryTracker, RabbitMQService};
+use cms_core::config::CmsConfig;
+use cms_core::service::{
+    ConfigReloadService, METRICS_CONTENT_TYPE, MetricsService, ShutdownService, TelemetryService,
+};
+use cms_core::utils::trace_utils;
+
+/// Prometheus 指标
+#[handler]
+async fn metrics(res: &mut Response) {
+    let _ = res.add_header(CONTENT_TYPE, METRICS_CONTENT_TYPE, true);
+    res.render(MetricsService::render(None).await);
+}
 
 #[tokio::main]
 async fn main() {
     dotenv().ok();
 
-    let state = AppState::init().await;
-
-    let pool = &state.rabbitmq;
+    let config = CmsConfig::init();
+    ConfigReloadService::init_tracing(&config.web, env!("CARGO_PKG_NAME"));
+    let shutdown_timeout = config.web.shutdown_timeout();
+    let pool = &config.rabbitmq.build_pool().await.unwrap();
     let queue_name = "queue_test";
     let queue_tag = "";
     let channel = RabbitMQService::init_channel_and_queue(pool, queue_name)
@@ -23,26 +39,73 @@ async fn main() {
         .await
         .unwrap();
 
-    consumer.set_delegate(move |delivery: DeliveryResult| async move {
-        let delivery = match delivery {
-            // Carries the delivery alongside its channel
-            Ok(Some(delivery)) => delivery,
-            // The consumer got canceled
-            Ok(None) => return,
-            // Carries the error and is always followed by Ok(None)
-            Err(error) => {
-                dbg!("Failed to consume queue message {}", error);
-                return;
-            }
-        };
+    let tracker = DeliveryTracker::new();
+    let delivery_tracker = tracker.clone();
+    consumer.set_delegate(move |delivery: DeliveryResult| {
+        let tracker = delivery_tracker.clone();
+        async move {
+            let delivery = match delivery {
+                // Carries the delivery alongside its channel
+                Ok(Some(delivery)) => delivery,
+                // The consumer got canceled
+                Ok(None) => return,
+                // Carries the error and is always followed by Ok(None)
+                Err(error) => {
+                    MetricsService::record_consume("error");
+                    tracing::error!("Failed to consume queue message: {}", error);
+                    return;
+                }
+            };
 
-        // Do something with the delivery data (The message payload)
-        println!("Received message data: {:?}", delivery.data);
+            // 消息头有 traceparent 时作为发布消息的请求的子 span
+            let span = tracing::info_span!(
+                "process",
+                otel.name = %format!("process {}", queue_name),
+                otel.kind = "consumer",
+                messaging.system = "rabbitmq",
+                messaging.operation.type = "process",
+                messaging.destination.name = %delivery.exchange,
+                messaging.rabbitmq.destination.routing_key = %delivery.routing_key,
+            );
+            let _ = span.set_parent(trace_utils::amqp_context(
+                delivery.properties.headers().as_ref(),
+            ));
 
-        RabbitMQService::delivery_basic_ack(&delivery)
-            .await
-            .unwrap();
+            // 关闭时等待处理完成，超时后拒绝消息使其重新入队
+            let _guard = tracker.track().await;
+            let handle = async {
+                // Do something with the delivery data (The message payload)
+                tracing::debug!(bytes = delivery.data.len(), "Received message");
+            };
+            async {
+                tokio::select! {
+                    _ = handle => {
+                        let _ = RabbitMQService::delivery_basic_ack(&delivery).await;
+                    }
+                    _ = tracker.canceled() => {
+                        let _ = RabbitMQService::delivery_basic_nack(&delivery).await;
+                    }
+                }
+            }
+            .instrument(span)
+            .await;
+        }
     });
 
-    std::future::pending::<()>().await;
+    // 指标接口，使用 web 配置的地址
+    let acceptor = TcpListener::new(config.web.address()).bind().await;
+    let server = Server::new(acceptor);
+    let handle = server.handle();
+    tokio::spawn(server.serve(Router::with_path("metrics").get(metrics)));
+
+    ShutdownService::signal().await;
+    if let Err(err) =
+        RabbitMQService::shutdown(&channel, &consumer, &tracker, shutdown_timeout).await
+    {
+        tracing::error!("Failed to stop consumer: {}", err);
+    }
+    handle.stop_graceful(None);
+    pool.close();
+    tracing::info!("Consumer stopped");
+    TelemetryService::shutdown();
 }
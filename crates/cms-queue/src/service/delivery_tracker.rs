@@ -0,0 +1,95 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{OwnedRwLockReadGuard, RwLock, watch};
+
+/// 处理中的消息，关闭时等待全部处理完成
+#[derive(Debug, Clone)]
+pub struct DeliveryTracker {
+    lock: Arc<RwLock<()>>,
+    /// 等待超时后取消处理中的消息
+    canceled: watch::Sender<bool>,
+}
+
+impl Default for DeliveryTracker {
+    fn default() -> Self {
+        Self {
+            lock: Arc::default(),
+            canceled: watch::Sender::new(false),
+        }
+    }
+}
+
+impl DeliveryTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 开始处理消息，返回的守卫释放时处理完成
+    pub async fn track(&self) -> OwnedRwLockReadGuard<()> {
+        self.lock.clone().read_owned().await
+    }
+
+    /// 等待处理中的消息完成，超时返回 false
+    pub async fn wait(&self, timeout: Duration) -> bool {
+        tokio::time::timeout(timeout, self.lock.write())
+            .await
+            .is_ok()
+    }
+
+    /// 取消处理中的消息
+    pub fn cancel(&self) {
+        self.canceled.send_replace(true);
+    }
+
+    /// 等待取消
+    pub async fn canceled(&self) {
+        let mut canceled = self.canceled.subscribe();
+        let _ = canceled.wait_for(|canceled| *canceled).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_wait() {
+        let tracker = DeliveryTracker::new();
+        assert!(tracker.wait(Duration::from_millis(10)).await);
+
+        // 处理中的消息未完成时超时
+        let guard = tracker.track().await;
+        assert!(!tracker.wait(Duration::from_millis(20)).await);
+
+        let waiting = {
+            let tracker = tracker.clone();
+            tokio::spawn(async move { tracker.wait(Duration::from_secs(5)).await })
+        };
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!waiting.is_finished());
+        drop(guard);
+        assert!(waiting.await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_cancel() {
+        let tracker = DeliveryTracker::new();
+        let canceled = {
+            let tracker = tracker.clone();
+            tokio::spawn(async move { tracker.canceled().await })
+        };
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!canceled.is_finished());
+
+        tracker.cancel();
+        tokio::time::timeout(Duration::from_secs(1), canceled)
+            .await
+            .expect("canceled() should return after cancel()")
+            .unwrap();
+
+        // 取消后开始等待的也立即返回
+        tokio::time::timeout(Duration::from_secs(1), tracker.canceled())
+            .await
+            .unwrap();
+    }
+}
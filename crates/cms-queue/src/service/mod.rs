@@ -1,3 +1,5 @@
+mod delivery_tracker;
 mod rabbitmq_service;
 
+pub use delivery_tracker::DeliveryTracker;
 pub use rabbitmq_service::RabbitMQService;
@@ -1,16 +1,25 @@
 use deadpool_lapin::Pool;
-use deadpool_lapin::lapin::{BasicProperties, options::BasicPublishOptions};
 use lapin::Consumer;
 use lapin::message::Delivery;
 use lapin::{
     Channel,
-    options::{BasicAckOptions, BasicConsumeOptions, QueueDeclareOptions},
+    options::{
+        BasicAckOptions, BasicCancelOptions, BasicConsumeOptions, BasicNackOptions,
+        QueueDeclareOptions,
+    },
     types::FieldTable,
 };
+use std::time::Duration;
 
 use cms_core::domain::{HandleResult, handle_ok};
 use cms_core::error::AppError;
-use tracing::{error, info}; // 确保引入 AppError
+use cms_core::service::{MessagePublisher, MetricsService, RabbitMQPublisher};
+use tracing::{error, info, warn}; // 确保引入 AppError
+
+use super::DeliveryTracker;
+
+/// 取消处理后等待拒绝消息的时间
+const NACK_TIMEOUT: Duration = Duration::from_secs(5);
 
 pub struct RabbitMQService {}
 
@@ -42,42 +51,9 @@ impl RabbitMQService {
         routing_key: &str,
         payload: &[u8],
     ) -> HandleResult<()> {
-        if (exchange.is_empty() && routing_key.is_empty()) || payload.is_empty() {
-            let error = AppError::BadRequest(
-                "Exchange, routing key, and payload must not be empty".to_string(),
-            );
-            return Err(error.into());
-        }
-
-        let connection = pool.get().await?;
-        let channel = connection.create_channel().await?;
-
-        match channel
-            .basic_publish(
-                exchange,
-                routing_key,
-                BasicPublishOptions::default(),
-                payload,
-                BasicProperties::default(),
-            )
+        RabbitMQPublisher::new(pool.clone())
+            .publish(exchange, routing_key, payload)
             .await
-        {
-            Ok(publish_result) => {
-                if let Err(err) = publish_result.await {
-                    error!("Failed to confirm message publication: {}", err);
-                    return Err(err.into());
-                }
-                info!(
-                    "Message published successfully to exchange '{}' with routing key '{}'",
-                    exchange, routing_key
-                );
-                handle_ok(())
-            }
-            Err(err) => {
-                error!("Failed to publish message: {}", err);
-                Err(err.into())
-            }
-        }
     }
 
     pub async fn init_consumer(
@@ -107,11 +83,59 @@ impl RabbitMQService {
 
     pub async fn delivery_basic_ack(delivery: &Delivery) -> HandleResult<()> {
         if let Err(err) = delivery.ack(BasicAckOptions::default()).await {
+            MetricsService::record_consume("error");
             error!("Failed to acknowledge message: {}", err);
             return Err(err.into());
         }
+        MetricsService::record_consume("ack");
 
         info!("Message acknowledged successfully");
         handle_ok(())
     }
+
+    /// 拒绝消息并重新入队
+    pub async fn delivery_basic_nack(delivery: &Delivery) -> HandleResult<()> {
+        let options = BasicNackOptions {
+            requeue: true,
+            ..Default::default()
+        };
+        if let Err(err) = delivery.nack(options).await {
+            MetricsService::record_consume("error");
+            error!("Failed to reject message: {}", err);
+            return Err(err.into());
+        }
+        MetricsService::record_consume("nack");
+
+        warn!("Message rejected and requeued");
+        handle_ok(())
+    }
+
+    /// 停止消费并关闭通道
+    ///
+    /// 先取消消费者，不再接收新消息；再等待处理中的消息完成，最多等待 `timeout`。
+    /// 超时后取消处理并拒绝消息使其重新入队，仍未确认的消息在通道关闭后由 RabbitMQ 重新入队。
+    pub async fn shutdown(
+        channel: &Channel,
+        consumer: &Consumer,
+        tracker: &DeliveryTracker,
+        timeout: Duration,
+    ) -> HandleResult<()> {
+        channel
+            .basic_cancel(consumer.tag().as_str(), BasicCancelOptions::default())
+            .await?;
+        info!("Consumer '{}' canceled", consumer.tag());
+
+        if !tracker.wait(timeout).await {
+            warn!(
+                "In-flight messages not finished after {}s, requeueing them",
+                timeout.as_secs()
+            );
+            tracker.cancel();
+            tracker.wait(NACK_TIMEOUT).await;
+        }
+
+        channel.close(200, "shutdown").await?;
+        info!("Channel closed");
+        handle_ok(())
+    }
 }
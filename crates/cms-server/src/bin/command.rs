@@ -1,5 +1,15 @@
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use dotenvy::dotenv;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use cms_core::config::{AppState, CmsConfig};
+use cms_core::domain::dto::{DEFAULT_TENANT_ID, TenantCurrentDTO};
+use cms_core::domain::{HandleResult, handle_ok};
+use cms_core::service::{ExportService, ResourceService, TenantService};
+use cms_core::utils::time_utils;
+use cms_mate::service::{AppService, ItemService, KindService};
+use cms_user::service::UserService;
 
 #[derive(Parser, Debug)]
 pub enum CommandEnum {
@@ -9,6 +19,76 @@ pub enum CommandEnum {
         #[clap(long, default_value_t = 3)]
         days: u32,
     },
+    #[clap(about = "Permanently delete expired trash")]
+    PurgeTrash {
+        // 回收站中超过指定天数的记录将被彻底删除，默认值为 30
+        #[clap(long, default_value_t = 30)]
+        older_than: u32,
+    },
+    #[clap(about = "Check or show the effective config")]
+    Config {
+        #[clap(subcommand)]
+        action: ConfigActionEnum,
+        // 配置文件，默认使用 CMS_CONFIG_FILE 指定的文件或当前目录下的 cms.toml
+        #[clap(long, global = true)]
+        file: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigActionEnum {
+    #[clap(about = "Validate the config and report all errors")]
+    Check,
+    #[clap(about = "Print the effective config with secrets redacted")]
+    Show,
+}
+
+/// 检查或显示生效的配置，密码和密钥隐藏显示
+fn config(action: ConfigActionEnum, file: Option<PathBuf>) -> Result<(), Vec<String>> {
+    let path = CmsConfig::config_file(file.as_deref());
+    let source = match &path {
+        Some(path) => path.display().to_string(),
+        None => String::from("none, defaults and environment only"),
+    };
+    let config = CmsConfig::load(path.as_deref())?;
+
+    match action {
+        ConfigActionEnum::Check => {
+            println!("Config file: {}", source);
+            println!("Config OK");
+        }
+        ConfigActionEnum::Show => {
+            let text = config.to_redacted_toml().map_err(|err| vec![err])?;
+            println!("# Config file: {}\n{}", source, text);
+        }
+    }
+    Ok(())
+}
+
+/// 彻底删除回收站中过期的记录，包括所有租户，同时删除过期的导出文件
+async fn purge_trash(older_than: u32) -> HandleResult<()> {
+    let all_tenants = TenantCurrentDTO::cross_tenant(DEFAULT_TENANT_ID);
+    TenantService::scope(all_tenants, purge_expired(older_than)).await
+}
+
+async fn purge_expired(older_than: u32) -> HandleResult<()> {
+    let config = CmsConfig::init();
+    let state = AppState::init(config).await;
+    let time = time_utils::current_time() - Duration::from_secs(u64::from(older_than) * 86400);
+
+    // 先删除子级记录
+    let count = ItemService::purge_expired(time, &state).await?;
+    eprintln!("Purged {} items", count);
+    let count = KindService::purge_expired(time, &state).await?;
+    eprintln!("Purged {} kinds", count);
+    let count = AppService::purge_expired(time, &state).await?;
+    eprintln!("Purged {} apps", count);
+    let count = UserService::purge_expired(time, &state).await?;
+    eprintln!("Purged {} users", count);
+    let count = ExportService::purge_expired_files(&state).await?;
+    eprintln!("Purged {} export files", count);
+
+    handle_ok(())
 }
 
 #[derive(Parser, Debug)]
@@ -23,12 +103,27 @@ async fn main() {
     dotenv().ok();
 
     let args = CommandArgs::parse();
-    println!("{:?}", args);
+    tracing::debug!("{:?}", args);
     if let Some(command) = args.name {
         match command {
             CommandEnum::AppRefresh { days } => {
                 println!("App refresh with {} days", days);
             }
+            CommandEnum::PurgeTrash { older_than } => {
+                if let Err(err) = purge_trash(older_than).await {
+                    eprintln!("Purge trash failed: {:?}", err);
+                    std::process::exit(1);
+                }
+            }
+            CommandEnum::Config { action, file } => {
+                if let Err(errors) = config(action, file) {
+                    eprintln!("Invalid configuration:");
+                    for error in errors {
+                        eprintln!("  - {}", error);
+                    }
+                    std::process::exit(1);
+                }
+            }
         }
     }
 }
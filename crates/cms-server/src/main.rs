@@ -3,7 +3,9 @@ use salvo::oapi::security::Http;
 use salvo::oapi::{OpenApi, SecurityScheme};
 use salvo::prelude::*;
 
-use cms_core::config::{AppState, WebConfig};
+use cms_core::config::{AppState, CmsConfig};
+use cms_core::middleware::{metrics, request_id};
+use cms_core::service::{ConfigReloadService, ShutdownService};
 
 mod route;
 
@@ -11,24 +13,22 @@ mod route;
 async fn main() {
     dotenv().ok();
 
-    let web_config = WebConfig::from_env().expect("Failed to load web config");
+    let config = CmsConfig::init();
+    let web_config = config.web.clone();
 
-    tracing_subscriber::fmt()
-        .with_max_level(web_config.tracing_level())
-        .with_test_writer()
-        .init();
+    ConfigReloadService::init_tracing(&web_config, env!("CARGO_PKG_NAME"));
 
-    let state = AppState::init().await;
+    let state = AppState::init(config).await;
+    ConfigReloadService::watch(state.config.clone());
 
     let addr = web_config.address();
-    println!(
-        "🚀 {} service successfully started on http://{}",
-        &web_config.app_name(),
-        &addr
+    tracing::info!(
+        addr,
+        "{} service successfully started",
+        web_config.app_name()
     );
-    tracing::info!(addr, "Server is running");
 
-    let router = route::init_router(state);
+    let router = route::init_router(state.clone());
     let doc = OpenApi::new(
         web_config.app_name().as_str(),
         web_config.app_version().as_str(),
@@ -67,5 +67,9 @@ async fn main() {
         );
 
     let acceptor = TcpListener::new(&addr).bind().await;
-    Server::new(acceptor).serve(router).await;
+    let service = Service::new(router)
+        .hoop(request_id)
+        .hoop(metrics)
+        .hoop(route::init_cors(&state));
+    ShutdownService::serve(acceptor, service, &state).await;
 }
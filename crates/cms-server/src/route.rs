@@ -1,28 +1,160 @@
-use salvo::cors::Cors;
+use salvo::cors::{AllowOrigin, Cors, CorsHandler};
 use salvo::http::Method;
 use salvo::prelude::*;
 
 use cms_auth::handler::init_router as auth_router;
 use cms_core::config::AppState;
-use cms_core::handler::init_router as core_router;
-use cms_core::middleware::jwt_authorizor_init;
+use cms_core::handler::{init_router as core_router, metrics_router, probe_router};
+use cms_core::middleware::{jwt_authorizor_init, read_your_writes, tenant};
 use cms_mate::handler::init_router as mate_router;
 use cms_user::handler::init_router as user_router;
 
+/// 跨域处理，挂在 Service 上，预检请求不匹配路由也能返回
+pub fn init_cors(state: &AppState) -> CorsHandler {
+    let config = state.config.clone();
+    Cors::new()
+        .allow_origin(AllowOrigin::judge(move |origin, _, _| {
+            let origin = origin.to_str().unwrap_or_default();
+            config.current().web.is_origin_allowed(origin)
+        }))
+        .allow_methods(vec![
+            Method::GET,
+            Method::POST,
+            Method::PATCH,
+            Method::DELETE,
+        ])
+        .allow_headers(vec![
+            "authorization",
+            "content-type",
+            "idempotency-key",
+            "if-match",
+            "if-none-match",
+            "read-your-writes",
+            "tenant-id",
+            "x-request-id",
+        ])
+        .expose_headers(vec!["etag", "x-request-id"])
+        .into_handler()
+}
+
 pub fn init_router(state: AppState) -> Router {
-    let jwt_auth = jwt_authorizor_init();
-    let cors = Cors::new()
-        .allow_origin("*")
-        .allow_methods(vec![Method::GET, Method::POST, Method::DELETE])
-        .allow_headers("authorization")
-        .into_handler();
+    let jwt_auth = jwt_authorizor_init(&state.config.current().jwt);
 
+    // 探针和指标不经过认证和租户解析，依赖不可用时也能返回状态
     Router::new()
-        .hoop(cors)
-        .hoop(jwt_auth)
-        .hoop(affix_state::inject(state))
-        .push(Router::with_path("/auth").push(auth_router()))
-        .push(Router::with_path("/core").push(core_router()))
-    // .push(Router::with_path("/mate").push(mate_router()))
-    // .push(Router::with_path("/user").push(user_router()))
+        .push(probe_router(state.clone()))
+        .push(metrics_router(state.clone()))
+        .push(
+            Router::new()
+                .hoop(jwt_auth)
+                .hoop(affix_state::inject(state))
+                .hoop(read_your_writes)
+                .hoop(tenant)
+                .push(Router::with_path("/auth").push(auth_router()))
+                .push(Router::with_path("/core").push(core_router())),
+            // .push(Router::with_path("/mate").push(mate_router()))
+            // .push(Router::with_path("/user").push(user_router()))
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use salvo::prelude::*;
+    use salvo::test::{ResponseExt, TestClient};
+    use sea_orm::prelude::Uuid;
+    use serde_json::{Value as JsonValue, json};
+
+    use cms_core::fixture::config::FakerAppState;
+
+    use super::{init_cors, init_router};
+
+    async fn faker_service() -> Service {
+        let state = FakerAppState::init().await;
+        Service::new(init_router(state.clone())).hoop(init_cors(&state))
+    }
+
+    #[tokio::test]
+    async fn test_database_health() {
+        let service = faker_service().await;
+
+        let mut response = TestClient::get("http://127.0.0.1:5800/core/checker/database")
+            .send(&service)
+            .await;
+        assert_eq!(response.status_code.unwrap(), StatusCode::OK);
+        let json: JsonValue = response.take_json().await.unwrap();
+        assert_eq!(json.get("data"), Some(&json!(true)));
+    }
+
+    #[tokio::test]
+    async fn test_probes() {
+        let service = faker_service().await;
+
+        let response = TestClient::get("http://127.0.0.1:5800/healthz")
+            .send(&service)
+            .await;
+        assert_eq!(response.status_code.unwrap(), StatusCode::OK);
+
+        let mut response = TestClient::get("http://127.0.0.1:5800/readyz")
+            .send(&service)
+            .await;
+        assert_eq!(response.status_code.unwrap(), StatusCode::OK);
+        let json: JsonValue = response.take_json().await.unwrap();
+        assert_eq!(json.pointer("/data/ready"), Some(&json!(true)));
+    }
+
+    #[tokio::test]
+    async fn test_cors_preflight() {
+        let service = faker_service().await;
+
+        let response = TestClient::options("http://127.0.0.1:5800/core/exports/1")
+            .add_header("origin", "http://localhost:3000", true)
+            .add_header("access-control-request-method", "PATCH", true)
+            .add_header(
+                "access-control-request-headers",
+                "content-type,if-match",
+                true,
+            )
+            .send(&service)
+            .await;
+        let headers = response.headers();
+        let methods = headers["access-control-allow-methods"].to_str().unwrap();
+        assert!(methods.contains("PATCH"));
+        let allowed = headers["access-control-allow-headers"].to_str().unwrap();
+        assert!(allowed.contains("if-match"));
+        assert!(allowed.contains("content-type"));
+
+        let response = TestClient::get("http://127.0.0.1:5800/healthz")
+            .add_header("origin", "http://localhost:3000", true)
+            .send(&service)
+            .await;
+        let exposed = response.headers()["access-control-expose-headers"]
+            .to_str()
+            .unwrap();
+        assert!(exposed.contains("etag"));
+        assert!(exposed.contains("x-request-id"));
+    }
+
+    #[tokio::test]
+    async fn test_password_login_user_not_found() {
+        let service = faker_service().await;
+
+        let mut response = TestClient::post("http://127.0.0.1:5800/auth/manage/login/password")
+            .json(&json!({"username": "nobody", "password": "123456"}))
+            .send(&service)
+            .await;
+        assert_eq!(response.status_code.unwrap(), StatusCode::OK);
+        let json: JsonValue = response.take_json().await.unwrap();
+        assert_eq!(json.get("code"), Some(&json!(400)));
+        assert_eq!(json.get("message"), Some(&json!("用户不存在")));
+    }
+
+    #[tokio::test]
+    async fn test_access_without_token() {
+        let service = faker_service().await;
+
+        let url = format!("http://127.0.0.1:5800/core/exports/{}", Uuid::new_v4());
+        let mut response = TestClient::get(url).send(&service).await;
+        let json: JsonValue = response.take_json().await.unwrap();
+        assert_eq!(json.get("code"), Some(&json!(401)));
+    }
 }
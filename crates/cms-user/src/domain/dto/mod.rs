@@ -2,10 +2,8 @@ mod detail_store_dto;
 mod user_query_dto;
 mod user_store_dto;
 mod user_update_password_dto;
-mod user_view_dto;
 
 pub use detail_store_dto::DetailStoreDTO;
 pub use user_query_dto::UserQueryDTO;
 pub use user_store_dto::UserStoreDTO;
 pub use user_update_password_dto::UserUpdatePasswordDTO;
-pub use user_view_dto::UserViewDTO;
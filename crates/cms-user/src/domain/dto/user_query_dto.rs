@@ -1,7 +1,11 @@
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 
+use cms_core::domain::dto::{
+    EditorCurrentDTO, FieldSetDTO, ListFilterDTO, ListSortDTO, ResourceQueryDTO,
+};
 use cms_core::enums::EditorTypeEnum;
+use cms_core::utils::parameter_utils::{page_no_set, page_size_set};
 
 use crate::{
     domain::query::UserPaginateQuery,
@@ -19,6 +23,24 @@ pub struct UserQueryDTO {
     /// 每页数量
     pub page_size: Option<u64>,
 
+    /// 分页游标
+    pub cursor: Option<String>,
+
+    /// 是否统计总条数
+    pub with_total: Option<bool>,
+
+    /// 排序项
+    pub sorts: Vec<ListSortDTO>,
+
+    /// 筛选项
+    pub filters: Vec<ListFilterDTO>,
+
+    /// 返回字段
+    pub fields: FieldSetDTO,
+
+    /// 编辑用户
+    pub editor: EditorCurrentDTO,
+
     /// 用户类型
     pub user_types: Option<EditorTypeEnum>,
 
@@ -64,6 +86,8 @@ impl From<UserPaginateQuery> for UserQueryDTO {
         Self {
             page: model.page,
             page_size: model.page_size,
+            cursor: model.cursor,
+            with_total: model.with_total,
             keyword: model.keyword,
             phone: model.phone,
             email: model.email,
@@ -79,3 +103,41 @@ impl From<UserPaginateQuery> for UserQueryDTO {
         }
     }
 }
+
+impl ResourceQueryDTO<UserLoadEnum> for UserQueryDTO {
+    fn page(&self) -> u64 {
+        page_no_set(self.page)
+    }
+
+    fn page_size(&self) -> u64 {
+        page_size_set(self.page_size)
+    }
+
+    fn cursor(&self) -> Option<&str> {
+        self.cursor.as_deref()
+    }
+
+    fn with_total(&self) -> bool {
+        self.with_total.unwrap_or(true)
+    }
+
+    fn sorts(&self) -> &[ListSortDTO] {
+        &self.sorts
+    }
+
+    fn filters(&self) -> &[ListFilterDTO] {
+        &self.filters
+    }
+
+    fn fields(&self) -> Option<&FieldSetDTO> {
+        Some(&self.fields)
+    }
+
+    fn editor(&self) -> &EditorCurrentDTO {
+        &self.editor
+    }
+
+    fn load_models(&self) -> Option<&Vec<UserLoadEnum>> {
+        self.load_models.as_ref()
+    }
+}
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 
+use cms_core::domain::dto::{EditorCurrentDTO, ResourceStoreDTO};
 use cms_core::enums::EditorTypeEnum;
 
 use crate::{
@@ -15,7 +16,10 @@ use super::DetailStoreDTO;
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
 pub struct UserStoreDTO {
     /// 主键
-    pub id: Option<i64>,
+    pub id: i64,
+
+    /// 编辑用户
+    pub editor: EditorCurrentDTO,
 
     /// NO
     pub no: Option<String>,
@@ -62,6 +66,9 @@ pub struct UserStoreDTO {
     /// 是否测试账号
     pub is_test: Option<bool>,
 
+    /// 版本号
+    pub version_no: Option<i32>,
+
     /// 详情信息
     pub detail: Option<DetailStoreDTO>,
 }
@@ -113,7 +120,7 @@ impl From<UserUpdateForm> for UserStoreDTO {
             detail_dto = Some(model.detail.clone().unwrap().into());
         }
         Self {
-            id: model.id,
+            id: model.id.unwrap_or(0),
             avatar_path: model.avatar_path,
             email: model.email,
             gender: model.gender,
@@ -125,9 +132,28 @@ impl From<UserUpdateForm> for UserStoreDTO {
             no: model.no,
             phone: model.phone,
             real_name: model.real_name,
+            version_no: model.version_no,
             detail: detail_dto,
             types_list: Self::str_to_type_vec(&model.user_types),
             ..Default::default()
         }
     }
 }
+
+impl ResourceStoreDTO for UserStoreDTO {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn version_no(&self) -> Option<i32> {
+        self.version_no
+    }
+
+    fn editor(&self) -> &EditorCurrentDTO {
+        &self.editor
+    }
+
+    fn set_editor(&mut self, editor: EditorCurrentDTO) {
+        self.editor = editor;
+    }
+}
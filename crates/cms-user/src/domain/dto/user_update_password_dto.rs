@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use cms_core::domain::dto::EditorCurrentDTO;
+
 use crate::domain::form::UserUpdatePasswordForm;
 
 // ------------------------------------
@@ -10,6 +12,9 @@ pub struct UserUpdatePasswordDTO {
     /// 主键
     pub id: i64,
 
+    /// 编辑用户
+    pub editor: EditorCurrentDTO,
+
     /// 当前密码
     pub current_password: Option<String>,
 
@@ -24,6 +29,7 @@ impl From<UserUpdatePasswordForm> for UserUpdatePasswordDTO {
     fn from(model: UserUpdatePasswordForm) -> Self {
         Self {
             id: 0,
+            editor: EditorCurrentDTO::default(),
             current_password: model.current_password,
             confirm_password: model.confirm_password.unwrap(),
             new_password: model.new_password.unwrap(),
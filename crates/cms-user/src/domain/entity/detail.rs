@@ -10,7 +10,7 @@ pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i64,
     pub editor_type: String,
-    pub editor_id: i64,
+    pub editor_id: Uuid,
     #[sea_orm(unique)]
     pub user_id: i64,
     pub identity_no: String,
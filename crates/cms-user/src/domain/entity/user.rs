@@ -11,8 +11,9 @@ use cms_core::utils::time_utils;
 pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i64,
+    pub tenant_id: i64,
     pub editor_type: String,
-    pub editor_id: i64,
+    pub editor_id: Uuid,
     pub no: String,
     pub name: String,
     pub real_name: String,
@@ -34,6 +35,7 @@ pub struct Model {
     pub is_authed: bool,
     pub is_enabled: bool,
     pub is_test: bool,
+    pub version_no: Option<i32>,
     pub is_deleted: bool,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
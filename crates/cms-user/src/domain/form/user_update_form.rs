@@ -2,7 +2,7 @@ use salvo::oapi::ToSchema;
 use serde::{Deserialize, Serialize};
 use validator::{Validate, ValidationError};
 
-use cms_core::utils::validate_utils;
+use cms_core::utils::{deserializer_utils, validate_utils};
 
 use super::DetailStoreForm;
 use crate::enums::GenderEnum;
@@ -121,6 +121,11 @@ pub struct UserUpdateForm {
     /// 角色类型
     pub user_types: Option<String>,
 
+    /// 版本号
+    #[serde(default, deserialize_with = "deserializer_utils::string_to_option_i32")]
+    #[salvo(schema(required = false, nullable = false, value_type = i32, minimum = 1, example = 3))]
+    pub version_no: Option<i32>,
+
     // 详情信息
     pub detail: Option<DetailStoreForm>,
 }
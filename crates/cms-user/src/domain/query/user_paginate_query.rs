@@ -17,6 +17,14 @@ pub struct UserPaginateQuery {
     #[salvo(parameter(required = false, nullable = true, value_type =u32, default = 10, minimum = 1, maximum = 100))]
     pub page_size: Option<u64>,
 
+    /// 分页游标，传入时使用游标分页，空字符串表示第一页
+    #[salvo(parameter(required = false, nullable = true))]
+    pub cursor: Option<String>,
+
+    /// 是否统计总条数，默认统计
+    #[salvo(parameter(required = false, nullable = true))]
+    pub with_total: Option<bool>,
+
     /// 关键字
     #[salvo(parameter(required = false, nullable = true))]
     pub keyword: Option<String>,
@@ -1,5 +1,6 @@
 use salvo::oapi::ToSchema;
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 use crate::domain::entity::detail::Model;
 
@@ -14,7 +15,8 @@ pub struct DetailVO {
 
     pub editor_type: String,
 
-    pub editor_id: i64,
+    #[salvo(schema(value_type = KnownFormat::Uuid, example = "00000000-0000-0000-0000-000000000000"))]
+    pub editor_id: Uuid,
 
     /// 身份证号
     pub identity_no: String,
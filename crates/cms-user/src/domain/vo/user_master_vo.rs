@@ -1,6 +1,7 @@
 use cms_core::domain::vo::EditorLoadVO;
 use salvo::oapi::ToSchema;
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 use cms_core::enums::EditorTypeEnum;
 
@@ -23,7 +24,8 @@ pub struct UserMasterVO {
 
     /// 编辑用户ID
     #[serde(skip_serializing)]
-    pub editor_id: i64,
+    #[salvo(schema(value_type = KnownFormat::Uuid, example = "00000000-0000-0000-0000-000000000000"))]
+    pub editor_id: Uuid,
 
     /// NO
     pub no: String,
@@ -79,6 +81,10 @@ pub struct UserMasterVO {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_test: Option<bool>,
 
+    /// 版本号
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version_no: Option<i32>,
+
     /// 创建时间
     pub created_time: String,
 
@@ -116,6 +122,7 @@ impl UserMasterVO {
             is_authed: Some(model.is_authed),
             is_enabled: Some(model.is_enabled),
             is_test: Some(model.is_test),
+            version_no: model.version_no,
             created_time,
             updated_time,
             ..Default::default()
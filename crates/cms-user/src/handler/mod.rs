@@ -1,35 +1,23 @@
 use salvo::prelude::*;
 
-mod checker_handler;
+use cms_core::handler::{checker_router, export_router};
+use cms_core::middleware::jwt_verify_access;
+
 mod user_handler;
 
 pub fn init_router() -> Router {
     Router::new()
-        .push(
-            Router::with_path("/checker")
-                .push(Router::with_path("/health").get(checker_handler::health))
-                .push(Router::with_path("/database").get(checker_handler::database)),
-        )
+        .push(checker_router())
+        .push(export_router())
         .push(
             Router::with_path("/manage")
-                .push(
-                    Router::with_path("/users")
-                        .get(user_handler::manager_paginate)
-                        .post(user_handler::manager_create),
-                )
-                .push(Router::with_path("/users/form").get(user_handler::manager_form))
-                .push(Router::with_path("/users/unique").post(user_handler::check_field_unique))
-                .push(Router::with_path("/users/{id}/bool").post(user_handler::update_bool_field))
-                .push(
-                    Router::with_path("/users/{id}")
-                        .get(user_handler::manager_view)
-                        .patch(user_handler::manager_update)
-                        .delete(user_handler::manager_delete),
-                )
+                .hoop(jwt_verify_access)
+                .push(Router::with_path("/users/import").post(user_handler::manager_import))
                 .push(
                     Router::with_path("/users/{id}/password")
                         .post(user_handler::manager_update_password),
                 )
+                .push(user_handler::manage_router())
                 .push(Router::with_path("/logs").get(user_handler::manager_logs)),
         )
         .push(
@@ -1,168 +1,33 @@
 use salvo::oapi::extract::*;
 use salvo::prelude::*;
+use tracing::debug;
 use validator::Validate;
 
 use cms_core::{
     config::AppState,
-    domain::{
-        AppResult,
-        dto::FieldBoolUpdateDTO,
-        form::{FieldBoolUpdateForm, FieldValueUniqueForm},
-        result_ok,
-        vo::PaginateResultVO,
-    },
+    domain::{AppResult, query::ImportQuery, result_ok, vo::ImportResultVO},
     enums::PlatformEnum,
+    service::ImportService,
+    utils::get_current_editor,
 };
 
 use crate::{
     domain::{
-        dto::{UserQueryDTO, UserStoreDTO, UserUpdatePasswordDTO, UserViewDTO},
+        dto::UserUpdatePasswordDTO,
         form::{UserCreateForm, UserUpdateForm, UserUpdatePasswordForm},
         query::UserPaginateQuery,
-        vo::{UserFormOptionVO, UserMasterVO},
     },
-    enums::UserLoadEnum,
     service::UserService,
 };
 
-/// 分页列表
-///
-/// 管理端分页查询
-#[endpoint(
-    parameters(UserPaginateQuery),
-    tags("用户模块/管理端/用户管理"),
-    responses(
-        (status_code = 200, description = "success response")
-    )
-)]
-pub async fn manager_paginate(
-    depot: &mut Depot,
+cms_core::resource_manage_router! {
+    path: "users",
+    service: UserService,
+    create_form: UserCreateForm,
+    update_form: UserUpdateForm,
     query: UserPaginateQuery,
-) -> AppResult<PaginateResultVO<UserMasterVO>> {
-    let state = depot.obtain::<AppState>().unwrap();
-    let mut dto: UserQueryDTO = query.into();
-    dto.load_models = Some(vec![UserLoadEnum::Editor]);
-    let vo = UserService::paginage(&PlatformEnum::Manager, &dto, state).await?;
-    result_ok(vo)
-}
-
-/// 创建用户
-///
-/// 管理端创建用户
-#[endpoint(
-    tags("用户模块/管理端/用户管理"),
-    responses(
-        (status_code = 200, description = "success response")
-    )
-)]
-pub async fn manager_create(
-    depot: &mut Depot,
-    json: JsonBody<UserCreateForm>,
-) -> AppResult<String> {
-    let form = json.into_inner();
-    form.validate()?;
-    let state = depot.obtain::<AppState>().unwrap();
-    let dto = form.into();
-    UserService::store(&PlatformEnum::Manager, &dto, state).await?;
-    result_ok("oK".to_string())
-}
-
-/// 更新用户
-///
-/// 管理端更新用户
-#[endpoint(
-    tags("用户模块/管理端/用户管理"),
-    responses(
-        (status_code = 200, description = "success response")
-    )
-)]
-pub async fn manager_update(
-    depot: &mut Depot,
-    id: PathParam<i64>,
-    json: JsonBody<UserUpdateForm>,
-) -> AppResult<String> {
-    let form = json.into_inner();
-    form.validate()?;
-    let state = depot.obtain::<AppState>().unwrap();
-    let mut dto: UserStoreDTO = form.into();
-    dto.id = Some(id.into_inner());
-    UserService::store(&PlatformEnum::Manager, &dto, state).await?;
-    result_ok("oK".to_string())
-}
-
-/// 删除用户
-///
-/// 管理端删除用户
-#[endpoint(
-    tags("用户模块/管理端/用户管理"),
-    responses(
-        (status_code = 200, description = "success response")
-    )
-)]
-pub async fn manager_delete(depot: &mut Depot, id: PathParam<i64>) -> AppResult<bool> {
-    let state = depot.obtain::<AppState>().unwrap();
-    let id = id.into_inner();
-    UserService::destroy(id, state).await?;
-    result_ok(true)
-}
-
-/// 表单选项
-///
-/// 管理端表单选项
-#[endpoint(
-    tags("用户模块/管理端/用户管理"),
-    responses(
-        (status_code = 200, description = "success response")
-    )
-)]
-pub fn manager_form() -> AppResult<UserFormOptionVO> {
-    let vo = UserService::form_options()?;
-    result_ok(vo)
-}
-
-/// 唯一性校验
-///
-/// 管理端字段值唯一性校验
-#[endpoint(
-    tags("用户模块/管理端/用户管理"),
-    responses(
-        (status_code = 200, description = "success response")
-    )
-)]
-pub async fn check_field_unique(
-    depot: &mut Depot,
-    json: JsonBody<FieldValueUniqueForm>,
-) -> AppResult<bool> {
-    let form = json.into_inner();
-    form.validate()?;
-    let dto = form.into();
-    let state = depot.obtain::<AppState>().unwrap();
-    let value = UserService::field_unique(&dto, state).await?;
-    result_ok(value)
-}
-
-/// 更新Bool字段值
-///
-/// 管理端更新Bool字段值
-#[endpoint(
-    tags("用户模块/管理端/用户管理"),
-    responses(
-        (status_code = 200, description = "success response")
-    )
-)]
-pub async fn update_bool_field(
-    depot: &mut Depot,
-    id: PathParam<i64>,
-    json: JsonBody<FieldBoolUpdateForm>,
-) -> AppResult<bool> {
-    let form = json.into_inner();
-    form.validate()?;
-    // 因为设置 id 所以必须指定 dto 类型
-    let mut dto: FieldBoolUpdateDTO = form.into();
-    dto.id = id.into_inner();
-    let state = depot.obtain::<AppState>().unwrap();
-    let value = UserService::update_bool_field(&dto, state).await?;
-    result_ok(value)
+    operation_id: "user",
+    tag: "用户模块/管理端/用户管理",
 }
 
 /// 修改密码
@@ -183,30 +48,38 @@ pub async fn manager_update_password(
     form.validate()?;
     let mut dto: UserUpdatePasswordDTO = form.into();
     dto.id = id.into_inner();
+    dto.editor = get_current_editor(depot);
     let state = depot.obtain::<AppState>().unwrap();
     let value = UserService::update_password(&dto, state).await?;
     result_ok(value)
 }
 
-/// 查看详情
+/// 导入用户
 ///
-/// 管理端查看用户详情
+/// 管理端从 CSV 批量创建用户，表头与创建用户的字段一致
 #[endpoint(
-    tags("用户模块/管理端/用户管理"),
-    responses(
-        (status_code = 200, description = "success response")
-    )
+    operation_id = "user_manager_import",
+    security(["bearer" = ["bearer"]]),
+    tags("用户模块/管理端/用户管理")
 )]
-pub async fn manager_view(depot: &mut Depot, id: PathParam<i64>) -> AppResult<UserMasterVO> {
-    let load_models: Vec<UserLoadEnum> = vec![UserLoadEnum::Editor, UserLoadEnum::Detail];
-    let dto = UserViewDTO {
-        id: id.into_inner(),
-        load_models: Some(load_models),
-        ..Default::default()
-    };
+pub async fn manager_import(
+    depot: &mut Depot,
+    query: ImportQuery,
+    file: FormFile,
+) -> AppResult<ImportResultVO> {
     let state = depot.obtain::<AppState>().unwrap();
-    let model = UserService::view(&PlatformEnum::Manager, &dto, state).await?;
-    result_ok(model)
+    let editor = get_current_editor(depot);
+    let bytes = ImportService::read_file(&file).await?;
+
+    let vo = ImportService::import::<UserService, UserCreateForm>(
+        &PlatformEnum::Manager,
+        &bytes,
+        query.dry_run.unwrap_or(false),
+        &editor,
+        state,
+    )
+    .await?;
+    result_ok(vo)
 }
 
 /// 日志列表
@@ -219,7 +92,7 @@ pub async fn manager_view(depot: &mut Depot, id: PathParam<i64>) -> AppResult<Us
     )
 )]
 pub async fn manager_logs(depot: &mut Depot, form: JsonBody<UserCreateForm>) -> AppResult<String> {
-    println!("form: {:?}", form);
+    debug!("Login logs for user {:?}", form.name);
     let state = depot.obtain::<AppState>().unwrap();
     let _ = &state.db.ping().await?;
     result_ok("oK".to_string())
@@ -236,7 +109,7 @@ pub async fn manager_logs(depot: &mut Depot, form: JsonBody<UserCreateForm>) ->
     )
 )]
 pub async fn open_paginate(query: UserPaginateQuery) -> AppResult<String> {
-    println!("query: {:?}", query);
+    debug!("Paginate users: {:?}", query);
     result_ok("oK".to_string())
 }
 
@@ -250,7 +123,8 @@ pub async fn open_paginate(query: UserPaginateQuery) -> AppResult<String> {
     )
 )]
 pub async fn open_create(depot: &mut Depot, form: FormBody<UserCreateForm>) -> AppResult<String> {
-    println!("form: {:?}", form);
+    // 表单包含密码，只记录用户名
+    debug!("Create user {:?}", form.name);
     let state = depot.obtain::<AppState>().unwrap();
     let _ = &state.db.ping().await?;
     result_ok("oK".to_string())
@@ -2,7 +2,10 @@ use dotenvy::dotenv;
 use salvo::oapi::OpenApi;
 use salvo::prelude::*;
 
-use cms_core::config::{AppState, WebConfig};
+use cms_core::config::{AppState, CmsConfig};
+use cms_core::handler::{metrics_router, probe_router};
+use cms_core::middleware::{metrics, read_your_writes, request_id, tenant};
+use cms_core::service::{ConfigReloadService, ShutdownService};
 
 mod domain;
 mod enums;
@@ -13,28 +16,33 @@ mod service;
 async fn main() {
     dotenv().ok();
 
-    let web_config = WebConfig::from_env().expect("Failed to load web config");
+    let config = CmsConfig::init();
+    let web_config = config.web.clone();
 
-    tracing_subscriber::fmt()
-        .with_max_level(web_config.tracing_level())
-        .with_test_writer()
-        .init();
+    ConfigReloadService::init_tracing(&web_config, env!("CARGO_PKG_NAME"));
 
-    let state = AppState::init().await;
+    let state = AppState::init(config).await;
+    ConfigReloadService::watch(state.config.clone());
 
     let addr = web_config.address();
     let acceptor = TcpListener::new(&addr).bind().await;
 
-    println!(
-        "🚀 {} service successfully started on http://{}",
-        &web_config.app_name(),
-        &addr
+    tracing::info!(
+        addr,
+        "{} service successfully started",
+        web_config.app_name()
     );
-    tracing::info!(addr, "Server is running");
 
     let router = Router::new()
-        .hoop(affix_state::inject(state))
-        .push(Router::with_path("/user").push(handler::init_router()));
+        .push(probe_router(state.clone()))
+        .push(metrics_router(state.clone()))
+        .push(
+            Router::new()
+                .hoop(affix_state::inject(state.clone()))
+                .hoop(read_your_writes)
+                .hoop(tenant)
+                .push(Router::with_path("/user").push(handler::init_router())),
+        );
 
     let doc = OpenApi::new(
         web_config.app_name().as_str(),
@@ -67,5 +75,6 @@ async fn main() {
                 .into_router("rapi-doc"),
         );
 
-    Server::new(acceptor).serve(router).await;
+    let service = Service::new(router).hoop(request_id).hoop(metrics);
+    ShutdownService::serve(acceptor, service, &state).await;
 }
@@ -1,21 +1,16 @@
-use sea_orm::prelude::Expr;
 use sea_orm::*;
+use uuid::Uuid;
 
 use cms_core::config::AppState;
-use cms_core::domain::{
-    HandleResult,
-    dto::{FieldBoolUpdateDTO, FieldValueUniqueDTO},
-    handle_ok,
-    vo::PaginateResultVO,
+use cms_core::domain::{HandleResult, dto::EditorCurrentDTO, handle_ok};
+use cms_core::enums::{
+    AuditActionEnum, EditorTypeEnum, FilterOperatorEnum, PlatformEnum, ViewModeEnum,
 };
-use cms_core::enums::{EditorTypeEnum, PlatformEnum};
 use cms_core::error::AppError;
-use cms_core::service::EditorService;
+use cms_core::service::{EditorService, ResourceService};
 use cms_core::utils::{encrypt_utils::encrypt_password, random_utils, time_utils};
 
-use crate::domain::dto::{
-    DetailStoreDTO, UserQueryDTO, UserStoreDTO, UserUpdatePasswordDTO, UserViewDTO,
-};
+use crate::domain::dto::{DetailStoreDTO, UserQueryDTO, UserStoreDTO, UserUpdatePasswordDTO};
 use crate::domain::entity::detail::{
     ActiveModel as DetailActiveModel, Column as DetailColumn, Entity as DetailEntity,
 };
@@ -30,219 +25,408 @@ pub struct UserService {}
 const RAND_SALT_LENGTH: usize = 5;
 const RAND_NO_LENGTH: usize = 10;
 
-impl UserService {
-    pub async fn store(
+impl ResourceService for UserService {
+    type Entity = UserEntity;
+    type Model = UserModel;
+    type ActiveModel = UserActiveModel;
+    type Column = UserColumn;
+    type StoreDTO = UserStoreDTO;
+    type QueryDTO = UserQueryDTO;
+    type LoadEnum = UserLoadEnum;
+    type MasterVO = UserMasterVO;
+    type QueryOptionVO = UserFormOptionVO;
+    type FormOptionVO = UserFormOptionVO;
+
+    fn id_column() -> UserColumn {
+        UserColumn::Id
+    }
+
+    /// 填充创建/更新字段
+    async fn fill_store_model(
         platform: &PlatformEnum,
         dto: &UserStoreDTO,
-        state: &AppState,
-    ) -> HandleResult<UserModel> {
-        let mut id: i64 = 0;
-        let mut is_create = true;
-        if dto.id.is_some() {
-            id = dto.id.unwrap();
-            is_create = false;
-        }
-        let mut model = if is_create {
-            UserActiveModel {
-                ..Default::default()
-            }
-        } else {
-            let model = Self::fetch_by_id(id, state).await?;
-            model.into()
-        };
-        let db = &state.db;
+        model: &mut UserActiveModel,
+        origin: Option<&UserModel>,
+        _state: &AppState,
+    ) -> HandleResult<()> {
+        let is_create = origin.is_none();
 
-        let name = dto.name.clone();
-        if name.is_some() {
-            let name = name.unwrap();
-            let is_exists = Self::is_column_exist(
-                id,
-                UserColumn::Name,
-                sea_orm::Value::from(name.to_owned()),
-                db,
-            )
-            .await?;
-            if is_exists {
-                let err = AppError::BadRequest(String::from("用户名已存在"));
-                return Err(err);
-            }
-            model.name = Set(name);
+        if let Some(name) = &dto.name {
+            model.name = Set(name.clone());
         }
 
-        let real_name = dto.real_name.clone();
-        if real_name.is_some() {
-            model.real_name = Set(real_name.unwrap());
+        if let Some(real_name) = &dto.real_name {
+            model.real_name = Set(real_name.clone());
         }
 
-        let nickname = dto.nickname.clone();
-        if nickname.is_some() {
-            model.nickname = Set(nickname.unwrap());
+        if let Some(nickname) = &dto.nickname {
+            model.nickname = Set(nickname.clone());
         }
 
-        let user_types = dto.types_list.clone();
-        if user_types.is_some() {
-            let list: Vec<EditorTypeEnum> = user_types.unwrap();
-            match platform {
-                PlatformEnum::Open => {
-                    let type_name = EditorTypeEnum::Member.string_value();
-                    model.user_types = Set(type_name);
-                }
-                _ => {
-                    let type_names = EditorTypeEnum::to_comma_str(&list);
-                    model.user_types = Set(type_names);
-                }
+        if let Some(list) = &dto.types_list {
+            let type_names = match platform {
+                PlatformEnum::Open => EditorTypeEnum::Member.string_value(),
+                _ => EditorTypeEnum::to_comma_str(list),
             };
+            model.user_types = Set(type_names);
         }
 
-        if dto.gender.is_some() {
-            let gender = dto.gender.clone().unwrap();
-            match gender {
-                GenderEnum::None => {}
-                _ => {
-                    model.gender = Set(gender.as_value());
-                }
-            }
+        if let Some(gender) = &dto.gender
+            && *gender != GenderEnum::None
+        {
+            model.gender = Set(gender.as_value());
         }
 
-        let phone = dto.phone.clone();
-        if phone.is_some() {
-            let phone = phone.unwrap();
-            let is_exists = Self::is_column_exist(
-                id,
-                UserColumn::Phone,
-                sea_orm::Value::from(phone.to_owned()),
-                db,
-            )
-            .await?;
-            if is_exists {
-                let err = AppError::BadRequest(String::from("手机号已存在"));
-                return Err(err);
-            }
-            model.phone = Set(phone);
+        if let Some(phone) = &dto.phone {
+            model.phone = Set(phone.clone());
         }
 
-        let avatar_path: Option<String> = dto.avatar_path.clone();
-        if avatar_path.is_some() {
-            model.avatar_path = Set(avatar_path.unwrap());
+        if let Some(avatar_path) = &dto.avatar_path {
+            model.avatar_path = Set(avatar_path.clone());
         }
 
-        let email: Option<String> = dto.email.clone();
-        if email.is_some() {
-            let email = email.unwrap();
-            let is_exists = Self::is_column_exist(
-                id,
-                UserColumn::Email,
-                sea_orm::Value::from(email.to_owned()),
-                db,
-            )
-            .await?;
-            if is_exists {
-                let err = AppError::BadRequest(String::from("邮箱已存在"));
-                return Err(err);
-            }
-            model.email = Set(email);
+        if let Some(email) = &dto.email {
+            model.email = Set(email.clone());
         }
 
-        let is_authed = match dto.is_authed {
-            Some(flag) => flag,
-            None => false,
-        };
-        model.is_authed = Set(is_authed);
+        if let Some(is_authed) = dto.is_authed.or(is_create.then_some(false)) {
+            model.is_authed = Set(is_authed);
+        }
 
-        let is_enabled = match dto.is_enabled {
-            Some(flag) => flag,
-            None => true,
-        };
-        model.is_enabled = Set(is_enabled);
+        if let Some(is_enabled) = dto.is_enabled.or(is_create.then_some(true)) {
+            model.is_enabled = Set(is_enabled);
+        }
 
-        let is_test = match dto.is_test {
-            Some(flag) => flag,
-            None => false,
-        };
-        model.is_test = Set(is_test);
+        if let Some(is_test) = dto.is_test.or(is_create.then_some(false)) {
+            model.is_test = Set(is_test);
+        }
 
-        let time = time_utils::current_time();
-        model.updated_at = Set(time);
+        if !is_create {
+            return handle_ok(());
+        }
 
-        if is_create {
-            model.created_at = Set(time);
+        let no = match &dto.no {
+            Some(str) => str.clone(),
+            None => {
+                let rand_str = random_utils::alpha_string(RAND_NO_LENGTH);
+                format!("U{}", rand_str)
+            }
+        };
+        model.no = Set(no);
 
-            let no = dto.no.clone();
-            let no = match no {
-                Some(str) => str,
+        if let Some(password) = &dto.password {
+            match &dto.confirm_password {
                 None => {
-                    let rand_str = random_utils::alpha_string(RAND_NO_LENGTH);
-                    format!("U{}", rand_str)
-                }
-            };
-            model.no = Set(no);
-
-            let password = dto.password.clone();
-            if password.is_some() {
-                let password = password.unwrap();
-                let password_str = password.as_str();
-                let confirm_password = dto.confirm_password.clone();
-                if confirm_password.is_none() {
                     let err = AppError::BadRequest(String::from("确认密码不能为空"));
                     return Err(err);
-                } else if !confirm_password.unwrap().eq(password_str) {
+                }
+                Some(confirm_password) if confirm_password != password => {
                     let err = AppError::BadRequest(String::from("两次输入的密码不一致"));
                     return Err(err);
                 }
+                _ => {}
+            }
+
+            let salt = random_utils::alpha_string(RAND_SALT_LENGTH);
+            let password = encrypt_password(salt.as_str(), password.as_str());
+            model.salt = Set(salt);
+            model.password = Set(password);
+        }
 
-                let salt = random_utils::alpha_string(RAND_SALT_LENGTH);
-                let password = encrypt_password(salt.as_str(), password_str);
-                model.salt = Set(salt);
-                model.password = Set(password);
+        model.data_source_id = Set(dto.data_source_id.unwrap_or(0));
+
+        handle_ok(())
+    }
+
+    /// 构建列表查询器
+    fn query_builder(
+        platform: &PlatformEnum,
+        dto: &UserQueryDTO,
+    ) -> HandleResult<Select<UserEntity>> {
+        let mut query = Self::scope_active_query();
+        query = query.order_by_desc(UserColumn::Id);
+
+        match platform {
+            PlatformEnum::Open => {
+                query = query.filter(UserColumn::IsTest.eq(false));
             }
+            _ => {
+                query = query.filter(UserColumn::IsTest.eq(false));
+            }
+        }
 
-            let data_source_id = match dto.data_source_id {
-                Some(id) => id,
-                None => 0,
-            };
-            model.data_source_id = Set(data_source_id);
+        if let Some(keyword) = dto.keyword.clone() {
+            let condition = Condition::any()
+                .add(UserColumn::Name.contains(&keyword))
+                .add(UserColumn::Nickname.contains(&keyword))
+                .add(UserColumn::Phone.contains(&keyword))
+                .add(UserColumn::Email.contains(&keyword));
+            query = query.filter(condition);
+        } else {
+            if let Some(phone) = dto.phone.clone() {
+                query = query.filter(UserColumn::Phone.eq(phone));
+            }
+
+            if let Some(email) = dto.email.clone() {
+                query = query.filter(UserColumn::Email.eq(email));
+            }
         }
 
-        let txn = db.begin().await?;
+        if let Some(enabled) = dto.is_enabled {
+            query = query.filter(UserColumn::IsEnabled.eq(enabled));
+        }
 
-        let model = model.save(&txn).await?;
-        let model = model.try_into_model()?;
+        if let Some(authed) = dto.is_authed {
+            query = query.filter(UserColumn::IsAuthed.eq(authed));
+        }
 
-        let opt_detail_origin = dto.detail.clone();
-        let mut opt_detail: Option<DetailStoreDTO> = None;
-        if opt_detail_origin.is_some() {
-            let mut dto = opt_detail_origin.unwrap().clone();
-            dto.user_id = Some(model.id);
-            opt_detail = Some(dto);
-        } else if is_create {
-            opt_detail = Some(DetailStoreDTO {
-                user_id: Some(model.id),
-                ..Default::default()
-            });
+        if let Some(test) = dto.is_test {
+            query = query.filter(UserColumn::IsTest.eq(test));
+        }
+
+        if let Some(gender) = dto.gender.clone() {
+            match gender {
+                GenderEnum::Male | GenderEnum::Female | GenderEnum::Unknown => {
+                    let value = gender.as_value();
+                    query = query.filter(UserColumn::Gender.eq(value));
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(time) = dto.login_start_time {
+            query = query.filter(UserColumn::LastLoginAt.gte(time));
+        }
+
+        if let Some(time) = dto.login_end_time {
+            query = query.filter(UserColumn::LastLoginAt.lte(time));
+        }
+
+        if let Some(time) = dto.created_start_time {
+            query = query.filter(UserColumn::CreatedAt.gte(time));
         }
-        if let Some(dto) = opt_detail {
-            Self::store_detail(&dto, state, &txn).await?;
+
+        if let Some(time) = dto.created_end_time {
+            query = query.filter(UserColumn::CreatedAt.lte(time));
+        }
+
+        handle_ok(query)
+    }
+
+    /// Model 转换为 VO
+    fn master_vo(
+        _view_enum: &ViewModeEnum,
+        _editor: &EditorCurrentDTO,
+        model: &UserModel,
+    ) -> UserMasterVO {
+        model.into()
+    }
+
+    /// 查询选项
+    async fn query_options(
+        platform: &PlatformEnum,
+        state: &AppState,
+    ) -> HandleResult<UserFormOptionVO> {
+        Self::form_options(platform, state).await
+    }
+
+    /// 表单选项
+    async fn form_options(
+        _platform: &PlatformEnum,
+        _state: &AppState,
+    ) -> HandleResult<UserFormOptionVO> {
+        let genders = GenderEnum::to_option_list();
+        let types = EditorTypeEnum::to_option_list();
+
+        let vo = UserFormOptionVO {
+            genders: Some(genders),
+            types: Some(types),
+        };
+
+        handle_ok(vo)
+    }
+
+    /// 变更历史中不记录密码和盐值
+    fn audit_skip_columns() -> &'static [&'static str] {
+        &["password", "old_password", "salt"]
+    }
+
+    /// 唯一性校验字段
+    fn unique_columns() -> Vec<(UserColumn, AppError)> {
+        vec![
+            (
+                UserColumn::Name,
+                AppError::BadRequest(String::from("用户名已存在")),
+            ),
+            (
+                UserColumn::Phone,
+                AppError::BadRequest(String::from("手机号已存在")),
+            ),
+            (
+                UserColumn::Email,
+                AppError::BadRequest(String::from("邮箱已存在")),
+            ),
+        ]
+    }
+
+    /// 唯一性校验接口允许的字段名
+    fn unique_column(field_name: &str) -> Option<UserColumn> {
+        match field_name.to_lowercase().as_str() {
+            "name" | "username" => Some(UserColumn::Name),
+            "email" => Some(UserColumn::Email),
+            "mobile" | "phone" => Some(UserColumn::Phone),
+            _ => None,
+        }
+    }
+
+    /// 允许修改的布尔字段
+    fn bool_column(field_name: &str) -> Option<UserColumn> {
+        match field_name.to_lowercase().as_str() {
+            "is_enabled" => Some(UserColumn::IsEnabled),
+            "is_authed" => Some(UserColumn::IsAuthed),
+            "is_test" => Some(UserColumn::IsTest),
+            _ => None,
+        }
+    }
+
+    /// 列表允许排序的列
+    fn sort_columns() -> Vec<UserColumn> {
+        vec![
+            UserColumn::Id,
+            UserColumn::No,
+            UserColumn::Name,
+            UserColumn::CreatedAt,
+            UserColumn::UpdatedAt,
+            UserColumn::LastLoginAt,
+        ]
+    }
+
+    /// 列表允许筛选的列及操作符
+    fn filter_columns() -> Vec<(UserColumn, Vec<FilterOperatorEnum>)> {
+        vec![
+            (UserColumn::Id, FilterOperatorEnum::list()),
+            (UserColumn::No, FilterOperatorEnum::text()),
+            (UserColumn::Name, FilterOperatorEnum::text()),
+            (UserColumn::RealName, FilterOperatorEnum::text()),
+            (UserColumn::Nickname, FilterOperatorEnum::text()),
+            (UserColumn::Phone, FilterOperatorEnum::text()),
+            (UserColumn::Email, FilterOperatorEnum::text()),
+            (UserColumn::Gender, FilterOperatorEnum::list()),
+            (UserColumn::DataSourceId, FilterOperatorEnum::list()),
+            (UserColumn::IsAuthed, vec![FilterOperatorEnum::Eq]),
+            (UserColumn::IsEnabled, vec![FilterOperatorEnum::Eq]),
+            (UserColumn::IsTest, vec![FilterOperatorEnum::Eq]),
+            (UserColumn::CreatedAt, FilterOperatorEnum::range()),
+            (
+                UserColumn::LastLoginAt,
+                [FilterOperatorEnum::range(), vec![FilterOperatorEnum::Null]].concat(),
+            ),
+        ]
+    }
+
+    /// 管理端默认加载的关联数据
+    fn manager_load_models(view_enum: &ViewModeEnum) -> Vec<UserLoadEnum> {
+        match view_enum {
+            ViewModeEnum::ManagerList => vec![UserLoadEnum::Editor],
+            ViewModeEnum::ManagerDetail => vec![UserLoadEnum::Editor, UserLoadEnum::Detail],
+            _ => vec![],
+        }
+    }
+
+    /// `include` 参数允许加载的关联数据
+    fn include_options() -> Vec<(&'static str, UserLoadEnum)> {
+        vec![("detail", UserLoadEnum::Detail)]
+    }
+
+    /// 字段裁剪时用户端可见判断用到的列
+    fn relation_columns() -> Vec<UserColumn> {
+        vec![UserColumn::IsTest]
+    }
+
+    /// 用户端只能查看已启用的非测试账号
+    fn is_open_visible(model: &UserModel) -> bool {
+        model.is_enabled && !model.is_test
+    }
+
+    /// 加载关联数据
+    async fn load_relations(
+        list: &mut [UserMasterVO],
+        load_models: &[UserLoadEnum],
+        state: &AppState,
+    ) -> HandleResult<()> {
+        for enums in load_models {
+            match enums {
+                UserLoadEnum::Editor => {
+                    let editor_ids: Vec<Uuid> = list.iter().map(|vo| vo.editor_id).collect();
+                    let map = EditorService::batch_load_by_uuids(&editor_ids, state).await?;
+                    for vo in list.iter_mut() {
+                        vo.editor = map.get(&vo.editor_id.to_string()).cloned();
+                    }
+                }
+                UserLoadEnum::Detail => {
+                    let user_ids: Vec<i64> = list.iter().map(|vo| vo.id).collect();
+                    let details = DetailEntity::find()
+                        .filter(DetailColumn::UserId.is_in(user_ids))
+                        .all(state.db_read())
+                        .await?;
+                    for detail in details {
+                        if let Some(vo) = list.iter_mut().find(|vo| vo.id == detail.user_id) {
+                            vo.detail = Some(detail.into());
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        handle_ok(())
+    }
+
+    /// 保存用户详情，新建用户时同时创建空详情
+    async fn after_store(
+        dto: Option<&UserStoreDTO>,
+        model: &UserModel,
+        origin: Option<&UserModel>,
+        txn: &DatabaseTransaction,
+    ) -> HandleResult<()> {
+        let detail = match dto.and_then(|dto| dto.detail.as_ref()) {
+            Some(detail) => Some(detail.clone()),
+            None if origin.is_none() => Some(DetailStoreDTO::default()),
+            None => None,
+        };
+        if let Some(mut detail) = detail {
+            detail.user_id = Some(model.id);
+            Self::store_detail(&detail, model, txn).await?;
         }
 
-        // 提交事务
-        txn.commit().await?;
+        handle_ok(())
+    }
+
+    /// 彻底删除后同时删除用户详情
+    async fn after_purge(model: &UserModel, txn: &DatabaseTransaction) -> HandleResult<()> {
+        DetailEntity::delete_many()
+            .filter(DetailColumn::UserId.eq(model.id))
+            .exec(txn)
+            .await?;
 
-        handle_ok(model)
+        handle_ok(())
     }
+}
 
+impl UserService {
+    /// 创建/更新用户详情，编辑用户与用户记录一致
     async fn store_detail(
         dto: &DetailStoreDTO,
-        state: &AppState,
+        user: &UserModel,
         txn: &DatabaseTransaction,
     ) -> HandleResult<bool> {
-        let user_id = dto.user_id.clone().unwrap_or(0);
+        let user_id = dto.user_id.unwrap_or(0);
         if user_id < 1 {
             return handle_ok(true);
         }
-        let db = &state.db;
         let model = DetailEntity::find()
             .filter(DetailColumn::UserId.eq(user_id))
-            .one(db)
+            .one(txn)
             .await?;
         let mut is_create = false;
         let mut model: DetailActiveModel = match model {
@@ -267,7 +451,7 @@ impl UserService {
         }
 
         if dto.born_on.is_some() {
-            model.born_on = Set(dto.born_on.clone());
+            model.born_on = Set(dto.born_on);
         } else {
             model.born_on = Set(None);
         }
@@ -307,6 +491,9 @@ impl UserService {
             model.expertises = Set(expertises);
         }
 
+        model.editor_type = Set(user.editor_type.to_owned());
+        model.editor_id = Set(user.editor_id);
+
         let now = time_utils::current_time();
         if is_create {
             model.created_at = Set(now);
@@ -318,92 +505,6 @@ impl UserService {
         handle_ok(true)
     }
 
-    /// 检查字段值是否唯一
-    async fn is_column_exist(
-        id: i64,
-        column: UserColumn,
-        value: sea_orm::Value,
-        db: &DatabaseConnection,
-    ) -> HandleResult<bool> {
-        let count = Self::scope_active_query()
-            .select_only()
-            .column(UserColumn::Id)
-            .filter(column.eq(value))
-            .filter(UserColumn::Id.ne(id))
-            .count(db)
-            .await?;
-
-        handle_ok(count > 0)
-    }
-
-    /// 表单选项
-    pub fn form_options() -> HandleResult<UserFormOptionVO> {
-        let genders = GenderEnum::to_option_list();
-        let types = EditorTypeEnum::to_option_list();
-
-        let vo = UserFormOptionVO {
-            genders: Some(genders),
-            types: Some(types),
-        };
-
-        handle_ok(vo)
-    }
-
-    /// 检查字段值是否唯一
-    pub async fn field_unique(dto: &FieldValueUniqueDTO, state: &AppState) -> HandleResult<bool> {
-        let _id = dto.skip_id;
-        let db = &state.db;
-
-        let field_name = dto.field_name.to_owned();
-        let column = match field_name.to_lowercase().as_str() {
-            "name" | "username" => UserColumn::Name,
-            "email" => UserColumn::Email,
-            "mobile" | "phone" => UserColumn::Phone,
-            _ => {
-                let err = AppError::BadRequest(String::from("无效的字段"));
-                return Err(err);
-            }
-        };
-
-        let field_value = dto.field_value.to_owned();
-        let value = sea_orm::Value::from(field_value);
-
-        let exist = Self::is_column_exist(0, column, value, db).await?;
-        handle_ok(exist != true)
-    }
-
-    /// 修改布尔值字段
-    pub async fn update_bool_field(
-        dto: &FieldBoolUpdateDTO,
-        state: &AppState,
-    ) -> HandleResult<bool> {
-        let id = dto.id;
-        if id < 1 {
-            let err = AppError::BadRequest(String::from("无效的用户ID"));
-            return Err(err);
-        }
-        let db = &state.db;
-
-        let field_name = dto.field_name.to_owned();
-        let column = match field_name.to_lowercase().as_str() {
-            "is_enabled" => UserColumn::IsEnabled,
-            "is_authed" => UserColumn::IsAuthed,
-            "is_test" => UserColumn::IsTest,
-            _ => {
-                let err = AppError::BadRequest(String::from("无效的字段"));
-                return Err(err);
-            }
-        };
-
-        let _update_rows_count = UserEntity::update_many()
-            .col_expr(column, Expr::value(dto.field_value))
-            .filter(UserColumn::Id.eq(id))
-            .exec(db)
-            .await?;
-
-        handle_ok(true)
-    }
-
     /// 修改登录密码
     pub async fn update_password(
         dto: &UserUpdatePasswordDTO,
@@ -415,12 +516,11 @@ impl UserService {
             return Err(err);
         }
 
-        let db = &state.db;
         let model = Self::fetch_by_id(id, state).await?;
         if dto.current_password.is_some() {
             let current_password = dto.current_password.clone().unwrap();
             let salt = model.salt.clone();
-            let md5_password = encrypt_password(salt.as_str(), &current_password.as_str());
+            let md5_password = encrypt_password(salt.as_str(), current_password.as_str());
             if md5_password.ne(model.password.as_str()) {
                 let err = AppError::BadRequest(String::from("当前密码不正确"));
                 return Err(err);
@@ -441,228 +541,117 @@ impl UserService {
         //     return Err(err);
         // }
 
-        let mut active: UserActiveModel = model.into();
+        let mut active: UserActiveModel = model.clone().into();
         active.password = Set(password.to_owned());
         active.salt = Set(salt.to_owned());
-        active.updated_at = Set(time_utils::current_time());
-        let _ = active.update(db).await?;
+        Self::persist(
+            AuditActionEnum::Update,
+            None,
+            active,
+            Some(&model),
+            &dto.editor,
+            state,
+        )
+        .await?;
 
         handle_ok(true)
     }
+}
 
-    pub async fn view(
-        platform: &PlatformEnum,
-        dto: &UserViewDTO,
-        state: &AppState,
-    ) -> HandleResult<UserMasterVO> {
-        let id = dto.id;
-        if id < 1 {
-            let err = AppError::BadRequest(String::from("无效的用户ID"));
-            return Err(err);
-        }
+#[cfg(test)]
+mod tests {
+    use cms_core::domain::dto::{FieldSetDTO, ListSortDTO};
+    use cms_core::fixture::config::FakerAppState;
 
-        let db = &state.db;
-        let model = Self::fetch_by_id(id, state).await?;
-        if *platform == PlatformEnum::Open {
-            if model.is_test {
-                let err = AppError::BadRequest(String::from("无效的用户ID"));
-                return Err(err);
-            } else if !model.is_enabled {
-                let err = AppError::BadRequest(String::from("无效的用户ID"));
-                return Err(err);
-            }
-        }
+    use super::*;
 
-        let mut vo: UserMasterVO = model.into();
-        if let Some(load_models) = dto.load_models.clone() {
-            for enums in load_models {
-                match enums {
-                    // UserLoadEnum::Editor => {
-                    //     vo.editor = EditorService::load_by_id(vo.editor_id, state).await?;
-                    // }
-                    UserLoadEnum::Detail => {
-                        let detail = DetailEntity::find()
-                            .filter(DetailColumn::UserId.eq(id))
-                            .one(db)
-                            .await?;
-                        if let Some(detail) = detail {
-                            vo.detail = Some(detail.into());
-                        }
-                    }
-                    _ => {}
-                }
-            }
+    fn user_model() -> UserModel {
+        let time = time_utils::current_time();
+        UserModel {
+            id: 1,
+            tenant_id: 1,
+            editor_type: EditorTypeEnum::Admin.string_value(),
+            editor_id: Uuid::nil(),
+            no: "U0000000001".to_string(),
+            name: "admin".to_string(),
+            real_name: String::new(),
+            nickname: String::new(),
+            user_types: EditorTypeEnum::Admin.string_value(),
+            gender: 0,
+            phone: String::new(),
+            avatar_path: String::new(),
+            email: String::new(),
+            data_source_id: 0,
+            password: encrypt_password("abcde", "secret"),
+            old_password: String::new(),
+            salt: "abcde".to_string(),
+            password_modified_at: None,
+            attempted_times: 0,
+            last_attempted_at: None,
+            last_login_id: 0,
+            last_login_at: None,
+            is_authed: false,
+            is_enabled: true,
+            is_test: false,
+            version_no: Some(1),
+            is_deleted: false,
+            created_at: time,
+            updated_at: time,
+            deleted_at: None,
         }
-
-        handle_ok(vo)
     }
 
-    /// 分页查询
-    pub async fn paginage(
-        platform: &PlatformEnum,
-        dto: &UserQueryDTO,
-        state: &AppState,
-    ) -> HandleResult<PaginateResultVO<UserMasterVO>> {
-        let db = &state.db;
-        let mut page = dto.page.unwrap_or(1);
-        if page < 1 {
-            page = 1;
-        }
-
-        let mut page_size = dto.page_size.unwrap_or(20);
-        if page_size < 1 {
-            page_size = 20;
-        } else if page_size > 50 {
-            page_size = 50;
-        }
-        let query = Self::query_builder(platform, dto).await?;
-        let paginator = query.paginate(db, page_size);
-        let total = paginator.num_items_and_pages().await?;
-        let models = paginator.fetch_page(page - 1).await?;
-        let len = models.len();
-        let mut list: Vec<UserMasterVO> = Vec::with_capacity(len);
-        let mut editor_ids: Vec<i64> = Vec::with_capacity(len);
-        for model in models.iter() {
-            editor_ids.push(model.editor_id);
-            let vo: UserMasterVO = model.into();
-            list.push(vo);
-        }
-
-        if let Some(load_models) = dto.load_models.clone() {
-            for enums in load_models {
-                match enums {
-                    // UserLoadEnum::Editor => {
-                    //     let map = EditorService::batch_load_by_ids(&editor_ids, state).await?;
-                    //     for vo in list.iter_mut() {
-                    //         let editor = map.get(&vo.editor_id).cloned();
-                    //         vo.editor = editor;
-                    //     }
-                    // }
-                    _ => {}
-                }
-            }
-        }
-
-        println!("lenth: {}", editor_ids.len());
-        let vo = PaginateResultVO {
-            page_size,
-            current_page: page,
-            total: total.number_of_items,
-            list,
-        };
-
-        // let editor_ids = models
-        //     .into_iter()
-        //     .map(|model| model.editor_id)
-        //     .collect::<Vec<i64>>();
-        handle_ok(vo)
+    #[test]
+    fn test_audit_changes_skip_password() {
+        let origin = user_model();
+        let changes = UserService::audit_changes(None, &origin);
+        let fields = changes.as_object().unwrap();
+        assert!(fields.contains_key("name"));
+        for key in ["password", "old_password", "salt"] {
+            assert!(!fields.contains_key(key), "{} should not be audited", key);
+        }
+
+        let mut model = origin.clone();
+        model.old_password = origin.password.clone();
+        model.password = encrypt_password("fghij", "changed");
+        model.salt = "fghij".to_string();
+        let changes = UserService::audit_changes(Some(&origin), &model);
+        assert_eq!(changes, serde_json::json!({}));
+
+        // 只修改密码时仍写入变更历史
+        assert!(UserService::audit_skip_changed(Some(&origin), &model));
+        assert!(!UserService::audit_skip_changed(Some(&origin), &origin));
     }
 
-    async fn query_builder(
-        platform: &PlatformEnum,
-        dto: &UserQueryDTO,
-    ) -> HandleResult<Select<UserEntity>> {
-        let mut query = Self::scope_active_query();
-        query = query.order_by_desc(UserColumn::Id);
-
-        match platform {
-            PlatformEnum::Open => {
-                query = query.filter(UserColumn::IsTest.eq(false));
-            }
-            _ => {
-                query = query.filter(UserColumn::IsTest.eq(false));
-            }
-        }
-
-        if let Some(keyword) = dto.keyword.clone() {
-            let condition = Condition::any()
-                .add(UserColumn::Name.contains(&keyword))
-                .add(UserColumn::Nickname.contains(&keyword))
-                .add(UserColumn::Phone.contains(&keyword))
-                .add(UserColumn::Email.contains(&keyword));
-            query = query.filter(condition);
-        } else {
-            if let Some(phone) = dto.phone.clone() {
-                query = query.filter(UserColumn::Phone.eq(phone));
-            }
-
-            if let Some(email) = dto.email.clone() {
-                query = query.filter(UserColumn::Email.eq(email));
-            }
-        }
-
-        if let Some(enabled) = dto.is_enabled {
-            query = query.filter(UserColumn::IsEnabled.eq(enabled));
-        }
+    #[test]
+    fn test_resolve_load_models() {
+        let view = ViewModeEnum::ManagerList;
+        let fields = FieldSetDTO::parse("id,name,detail.real_name");
+        let list = UserService::resolve_load_models(&view, None, &fields).unwrap();
+        assert_eq!(list, vec![UserLoadEnum::Detail]);
 
-        if let Some(authed) = dto.is_authed {
-            query = query.filter(UserColumn::IsAuthed.eq(authed));
+        for fields in ["id,unknown", "id,unknown.name"] {
+            let fields = FieldSetDTO::parse(fields);
+            let res = UserService::resolve_load_models(&view, None, &fields);
+            assert!(matches!(res, Err(AppError::BadRequest(_))));
         }
-
-        if let Some(test) = dto.is_test {
-            query = query.filter(UserColumn::IsTest.eq(test));
-        }
-
-        if let Some(gender) = dto.gender.clone() {
-            match gender {
-                GenderEnum::Male | GenderEnum::Female | GenderEnum::Unknown => {
-                    let value = gender.as_value();
-                    query = query.filter(UserColumn::Gender.eq(value));
-                }
-                _ => {}
-            }
-        }
-
-        if let Some(time) = dto.login_start_time.clone() {
-            query = query.filter(UserColumn::LastLoginAt.gte(time));
-        }
-
-        if let Some(time) = dto.login_end_time.clone() {
-            query = query.filter(UserColumn::LastLoginAt.lte(time));
-        }
-
-        if let Some(time) = dto.created_start_time.clone() {
-            query = query.filter(UserColumn::CreatedAt.gte(time));
-        }
-
-        if let Some(time) = dto.created_end_time.clone() {
-            query = query.filter(UserColumn::CreatedAt.lte(time));
-        }
-
-        handle_ok(query)
-    }
-
-    fn scope_active_query() -> Select<UserEntity> {
-        UserEntity::find().filter(UserColumn::IsDeleted.eq(false))
-    }
-
-    async fn fetch_by_id(id: i64, state: &AppState) -> HandleResult<UserModel> {
-        let db = &state.db;
-        let model = Self::scope_active_query()
-            .filter(UserColumn::Id.eq(id))
-            .one(db)
-            .await?
-            .ok_or_else(|| AppError::BadRequest(String::from("无效的用户ID")))?;
-
-        handle_ok(model)
     }
 
-    /// 软删除记录
-    pub async fn destroy(id: i64, state: &AppState) -> HandleResult<()> {
-        if id < 1 {
-            return handle_ok(());
-        }
-
-        let db = &state.db;
-        let result = Self::fetch_by_id(id, state).await;
-        if let Ok(model) = result {
-            let mut model: UserActiveModel = model.into();
-            model.is_deleted = Set(true);
-            let now = time_utils::current_time();
-            model.deleted_at = Set(Some(now));
-            let _ = model.save(db).await?;
-        }
-
-        handle_ok(())
+    #[tokio::test]
+    async fn test_paginage_cursor_with_sort() {
+        let state = FakerAppState::init().await;
+        let mut dto = UserQueryDTO {
+            cursor: Some(String::new()),
+            ..Default::default()
+        };
+        let res = UserService::paginage(&PlatformEnum::Manager, &dto, &state).await;
+        assert!(res.is_ok());
+
+        dto.sorts = vec![ListSortDTO {
+            field: "name".to_string(),
+            desc: false,
+        }];
+        let res = UserService::paginage(&PlatformEnum::Manager, &dto, &state).await;
+        assert!(matches!(res, Err(AppError::BadRequest(_))));
     }
 }
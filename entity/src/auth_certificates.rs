@@ -16,6 +16,7 @@ pub struct Model {
     pub refresh_expired_at: DateTime,
     pub created_at: DateTime,
     pub updated_at: DateTime,
+    pub tenant_id: i64,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -3,6 +3,8 @@
 pub mod prelude;
 
 pub mod auth_certificates;
+pub mod core_audits;
+pub mod core_tenants;
 pub mod mate_apps;
 pub mod mate_items;
 pub mod mate_kinds;
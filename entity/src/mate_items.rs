@@ -9,7 +9,6 @@ pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i64,
     pub editor_type: String,
-    pub editor_id: i64,
     pub app_id: i64,
     pub kind_id: i64,
     pub name: String,
@@ -34,6 +33,8 @@ pub struct Model {
     pub version_no: i32,
     pub children_count: i16,
     pub morph_count: i16,
+    pub editor_id: Uuid,
+    pub tenant_id: i64,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -9,7 +9,6 @@ pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i64,
     pub editor_type: String,
-    pub editor_id: i64,
     pub app_id: i64,
     pub name: String,
     pub title: String,
@@ -25,6 +24,8 @@ pub struct Model {
     pub deleted_at: Option<DateTime>,
     pub version_no: i32,
     pub item_count: i16,
+    pub editor_id: Uuid,
+    pub tenant_id: i64,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -9,7 +9,6 @@ pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i64,
     pub editor_type: String,
-    pub editor_id: i64,
     pub app_id: i64,
     pub kind_id: i64,
     pub item_id: i64,
@@ -17,6 +16,8 @@ pub struct Model {
     pub instance_id: i64,
     pub created_at: DateTime,
     pub updated_at: DateTime,
+    pub editor_id: Uuid,
+    pub tenant_id: i64,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
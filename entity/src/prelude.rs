@@ -1,6 +1,8 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.6
 
 pub use super::auth_certificates::Entity as AuthCertificates;
+pub use super::core_audits::Entity as CoreAudits;
+pub use super::core_tenants::Entity as CoreTenants;
 pub use super::mate_apps::Entity as MateApps;
 pub use super::mate_items::Entity as MateItems;
 pub use super::mate_kinds::Entity as MateKinds;
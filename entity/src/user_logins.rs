@@ -13,6 +13,7 @@ pub struct Model {
     pub client_ip: String,
     pub user_agent: String,
     pub created_at: DateTime,
+    pub tenant_id: i64,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -0,0 +1,113 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DatabaseBackend;
+
+/// 空 UUID 的十六进制字面量，SQLite 和 MySQL 的 UUID 字段均为 16 字节的二进制
+const UUID_NIL_HEX: &str = "x'00000000000000000000000000000000'";
+
+/// UUID 主键的默认值：随机生成
+pub fn uuid_random(manager: &SchemaManager) -> SimpleExpr {
+    match manager.get_database_backend() {
+        DatabaseBackend::Sqlite => Expr::cust("(randomblob(16))"),
+        // MySQL 8.0.13 起支持表达式默认值
+        DatabaseBackend::MySql => Expr::cust("(uuid_to_bin(uuid()))"),
+        DatabaseBackend::Postgres => Expr::cust("gen_random_uuid()"),
+    }
+}
+
+/// UUID 字段的默认值：空 UUID
+pub fn uuid_nil(manager: &SchemaManager) -> SimpleExpr {
+    match manager.get_database_backend() {
+        DatabaseBackend::Sqlite | DatabaseBackend::MySql => Expr::cust(UUID_NIL_HEX),
+        // 不依赖 uuid-ossp 扩展的 uuid_nil()
+        DatabaseBackend::Postgres => Expr::cust("'00000000-0000-0000-0000-000000000000'::uuid"),
+    }
+}
+
+/// 创建数据表
+///
+/// PostgreSQL 的字段定义中不能包含注释，建表后使用 COMMENT ON COLUMN 添加。
+pub async fn create_table(
+    manager: &SchemaManager<'_>,
+    table: TableCreateStatement,
+) -> Result<(), DbErr> {
+    manager.create_table(table.clone()).await?;
+    if let Some(TableRef::Table(name)) = table.get_table_name() {
+        comment_columns(manager, name, table.get_columns()).await?;
+    }
+    Ok(())
+}
+
+/// 添加多个字段，SQLite 的 ALTER TABLE 每次只能修改一个字段
+pub async fn add_columns<T>(
+    manager: &SchemaManager<'_>,
+    table: T,
+    columns: Vec<ColumnDef>,
+) -> Result<(), DbErr>
+where
+    T: IntoIden,
+{
+    let table = table.into_iden();
+    for mut column in columns.clone() {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(table.clone())
+                    .add_column(&mut column)
+                    .to_owned(),
+            )
+            .await?;
+    }
+    comment_columns(manager, &table, &columns).await
+}
+
+/// 删除多个字段
+pub async fn drop_columns<T, C>(
+    manager: &SchemaManager<'_>,
+    table: T,
+    columns: Vec<C>,
+) -> Result<(), DbErr>
+where
+    T: IntoIden,
+    C: IntoIden + 'static,
+{
+    let table = table.into_iden();
+    for column in columns {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(table.clone())
+                    .drop_column(column)
+                    .to_owned(),
+            )
+            .await?;
+    }
+    Ok(())
+}
+
+/// PostgreSQL 添加字段注释，MySQL 的注释已包含在字段定义中，SQLite 不支持注释
+async fn comment_columns(
+    manager: &SchemaManager<'_>,
+    table: &DynIden,
+    columns: &[ColumnDef],
+) -> Result<(), DbErr> {
+    if manager.get_database_backend() != DatabaseBackend::Postgres {
+        return Ok(());
+    }
+
+    for column in columns {
+        let comment = column.get_column_spec().iter().find_map(|spec| match spec {
+            ColumnSpec::Comment(comment) => Some(comment),
+            _ => None,
+        });
+        if let Some(comment) = comment {
+            let sql = format!(
+                r#"COMMENT ON COLUMN "{}"."{}" IS '{}'"#,
+                table.to_string(),
+                column.get_column_name(),
+                comment.replace('\'', "''")
+            );
+            manager.get_connection().execute_unprepared(&sql).await?;
+        }
+    }
+    Ok(())
+}
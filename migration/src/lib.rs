@@ -1,5 +1,6 @@
 pub use sea_orm_migration::prelude::*;
 
+mod helper;
 mod m20240806_013138_create_table_user;
 mod m20240806_025749_create_table_auth_certificate;
 mod m20240806_025755_create_table_user_login;
@@ -9,6 +10,12 @@ mod m20250225_040906_create_table_mate_kind;
 mod m20250225_040909_create_table_mate_item;
 mod m20250225_040913_create_table_mate_morph;
 mod m20250228_005123_add_count_columns_to_mate_table;
+mod m20250601_000000_create_table_core_audit;
+mod m20250602_000000_add_version_no_to_user;
+mod m20250603_000000_change_editor_id_to_uuid;
+mod m20250604_000000_create_table_core_tenant;
+mod m20250604_000001_add_tenant_id_to_tables;
+mod m20250605_000000_add_tenant_unique_indexes;
 
 pub struct Migrator;
 
@@ -25,6 +32,12 @@ impl MigratorTrait for Migrator {
             Box::new(m20250225_040909_create_table_mate_item::Migration),
             Box::new(m20250225_040913_create_table_mate_morph::Migration),
             Box::new(m20250228_005123_add_count_columns_to_mate_table::Migration),
+            Box::new(m20250601_000000_create_table_core_audit::Migration),
+            Box::new(m20250602_000000_add_version_no_to_user::Migration),
+            Box::new(m20250603_000000_change_editor_id_to_uuid::Migration),
+            Box::new(m20250604_000000_create_table_core_tenant::Migration),
+            Box::new(m20250604_000001_add_tenant_id_to_tables::Migration),
+            Box::new(m20250605_000000_add_tenant_unique_indexes::Migration),
         ]
     }
 }
@@ -1,60 +1,62 @@
 use sea_orm_migration::prelude::*;
 
+use crate::helper;
+
 #[derive(DeriveMigrationName)]
 pub struct Migration;
 
 #[async_trait::async_trait]
 impl MigrationTrait for Migration {
     async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
-        manager
-            .create_table(
-                Table::create()
-                    .table(UserLogin::Table)
-                    .if_not_exists()
-                    .col(
-                        ColumnDef::new(UserLogin::Id)
-                            .uuid()
-                            .default(Expr::cust("gen_random_uuid()"))
-                            .primary_key()
-                            .comment("ID"),
-                    )
-                    .col(
-                        ColumnDef::new(UserLogin::UserId)
-                            .uuid()
-                            .not_null()
-                            .default(Expr::cust("uuid_nil()"))
-                            .comment("用户ID"),
-                    )
-                    .col(
-                        ColumnDef::new(UserLogin::LoginType)
-                            .string_len(15)
-                            .not_null()
-                            .default("")
-                            .comment("登录身份"),
-                    )
-                    .col(
-                        ColumnDef::new(UserLogin::ClientIp)
-                            .string_len(15)
-                            .not_null()
-                            .default("")
-                            .comment("Client IP"),
-                    )
-                    .col(
-                        ColumnDef::new(UserLogin::UserAgent)
-                            .string_len(200)
-                            .not_null()
-                            .default("")
-                            .comment("User Agent"),
-                    )
-                    .col(
-                        ColumnDef::new(UserLogin::CreatedAt)
-                            .date_time()
-                            .not_null()
-                            .comment("创建时间"),
-                    )
-                    .to_owned(),
-            )
-            .await?;
+        helper::create_table(
+            manager,
+            Table::create()
+                .table(UserLogin::Table)
+                .if_not_exists()
+                .col(
+                    ColumnDef::new(UserLogin::Id)
+                        .uuid()
+                        .default(helper::uuid_random(manager))
+                        .primary_key()
+                        .comment("ID"),
+                )
+                .col(
+                    ColumnDef::new(UserLogin::UserId)
+                        .uuid()
+                        .not_null()
+                        .default(helper::uuid_nil(manager))
+                        .comment("用户ID"),
+                )
+                .col(
+                    ColumnDef::new(UserLogin::LoginType)
+                        .string_len(15)
+                        .not_null()
+                        .default("")
+                        .comment("登录身份"),
+                )
+                .col(
+                    ColumnDef::new(UserLogin::ClientIp)
+                        .string_len(15)
+                        .not_null()
+                        .default("")
+                        .comment("Client IP"),
+                )
+                .col(
+                    ColumnDef::new(UserLogin::UserAgent)
+                        .string_len(200)
+                        .not_null()
+                        .default("")
+                        .comment("User Agent"),
+                )
+                .col(
+                    ColumnDef::new(UserLogin::CreatedAt)
+                        .date_time()
+                        .not_null()
+                        .comment("创建时间"),
+                )
+                .to_owned(),
+        )
+        .await?;
 
         manager
             .create_index(
@@ -0,0 +1,112 @@
+use sea_orm_migration::prelude::*;
+
+use crate::helper;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        helper::create_table(
+            manager,
+            Table::create()
+                .table(CoreAudit::Table)
+                .if_not_exists()
+                .col(
+                    ColumnDef::new(CoreAudit::Id)
+                        .big_integer()
+                        .primary_key()
+                        .auto_increment()
+                        .comment("ID"),
+                )
+                .col(
+                    ColumnDef::new(CoreAudit::EntityType)
+                        .string_len(30)
+                        .not_null()
+                        .default("")
+                        .comment("实体类型"),
+                )
+                .col(
+                    ColumnDef::new(CoreAudit::EntityId)
+                        .big_integer()
+                        .not_null()
+                        .default(0)
+                        .comment("实体ID"),
+                )
+                .col(
+                    ColumnDef::new(CoreAudit::Action)
+                        .string_len(10)
+                        .not_null()
+                        .default("")
+                        .comment("操作类型"),
+                )
+                .col(
+                    ColumnDef::new(CoreAudit::EditorType)
+                        .string_len(10)
+                        .not_null()
+                        .default("system")
+                        .comment("编辑类型"),
+                )
+                .col(
+                    ColumnDef::new(CoreAudit::EditorId)
+                        .uuid()
+                        .not_null()
+                        .default(helper::uuid_nil(manager))
+                        .comment("编辑ID"),
+                )
+                .col(
+                    ColumnDef::new(CoreAudit::VersionNo)
+                        .integer()
+                        .not_null()
+                        .default(0)
+                        .comment("版本号"),
+                )
+                .col(
+                    ColumnDef::new(CoreAudit::Changes)
+                        .json()
+                        .not_null()
+                        .comment("变更字段"),
+                )
+                .col(
+                    ColumnDef::new(CoreAudit::CreatedAt)
+                        .date_time()
+                        .not_null()
+                        .comment("创建时间"),
+                )
+                .to_owned(),
+        )
+        .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("core_audits_idx_by_entity")
+                    .table(CoreAudit::Table)
+                    .col(CoreAudit::EntityType)
+                    .col(CoreAudit::EntityId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CoreAudit::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum CoreAudit {
+    #[sea_orm(iden = "core_audits")]
+    Table,
+    Id,
+    EntityType,
+    EntityId,
+    Action,
+    EditorType,
+    EditorId,
+    VersionNo,
+    Changes,
+    CreatedAt,
+}
@@ -0,0 +1,36 @@
+use sea_orm_migration::prelude::*;
+
+use crate::helper;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        helper::add_columns(
+            manager,
+            User::Table,
+            vec![
+                ColumnDef::new(User::VersionNo)
+                    .integer()
+                    .not_null()
+                    .default(1)
+                    .comment("版本号")
+                    .to_owned(),
+            ],
+        )
+        .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        helper::drop_columns(manager, User::Table, vec![User::VersionNo]).await
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    #[sea_orm(iden = "users")]
+    Table,
+    VersionNo,
+}
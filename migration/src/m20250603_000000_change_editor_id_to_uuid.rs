@@ -0,0 +1,151 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::{ConnectionTrait, TryGetable};
+use uuid::Uuid;
+
+use crate::helper;
+
+/// 编辑ID统一为用户的 UUID
+///
+/// 原有的 i64 编辑ID按数值转换为 UUID（高位补零）写入新字段，再替换原字段；回滚时只有可以
+/// 转换回 i64 的值被保留，其余为 0。
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for table in tables() {
+            helper::add_columns(
+                manager,
+                table.clone(),
+                vec![
+                    ColumnDef::new(Mate::EditorIdNew)
+                        .uuid()
+                        .not_null()
+                        .default(helper::uuid_nil(manager))
+                        .comment("编辑ID")
+                        .to_owned(),
+                ],
+            )
+            .await?;
+
+            let ids: Vec<i64> = distinct_editor_ids(manager, table.clone(), 0i64).await?;
+            for id in ids {
+                let uuid = Uuid::from_u128(id as u64 as u128);
+                copy_editor_id(manager, table.clone(), id, uuid).await?;
+            }
+
+            replace_editor_id(manager, table).await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for table in tables() {
+            helper::add_columns(
+                manager,
+                table.clone(),
+                vec![
+                    ColumnDef::new(Mate::EditorIdNew)
+                        .big_integer()
+                        .not_null()
+                        .default(0)
+                        .comment("编辑ID")
+                        .to_owned(),
+                ],
+            )
+            .await?;
+
+            let uuids: Vec<Uuid> = distinct_editor_ids(manager, table.clone(), Uuid::nil()).await?;
+            for uuid in uuids {
+                let value = uuid.as_u128();
+                if value <= i64::MAX as u128 {
+                    copy_editor_id(manager, table.clone(), uuid, value as i64).await?;
+                }
+            }
+
+            replace_editor_id(manager, table).await?;
+        }
+        Ok(())
+    }
+}
+
+fn tables() -> Vec<Mate> {
+    vec![
+        Mate::AppTable,
+        Mate::KindTable,
+        Mate::ItemTable,
+        Mate::MorphTable,
+    ]
+}
+
+/// 查询不同的编辑ID，跳过默认值
+async fn distinct_editor_ids<V>(
+    manager: &SchemaManager<'_>,
+    table: Mate,
+    empty: V,
+) -> Result<Vec<V>, DbErr>
+where
+    V: Into<Value> + TryGetable,
+{
+    let db = manager.get_connection();
+    let stmt = Query::select()
+        .distinct()
+        .column(Mate::EditorId)
+        .from(table)
+        .and_where(Expr::col(Mate::EditorId).ne(empty))
+        .to_owned();
+    let rows = db.query_all(db.get_database_backend().build(&stmt)).await?;
+    rows.iter()
+        .map(|row| row.try_get("", &Mate::EditorId.to_string()))
+        .collect()
+}
+
+/// 将原字段的值转换后写入新字段
+async fn copy_editor_id<F, T>(
+    manager: &SchemaManager<'_>,
+    table: Mate,
+    from: F,
+    to: T,
+) -> Result<(), DbErr>
+where
+    F: Into<Value>,
+    T: Into<Value>,
+{
+    let db = manager.get_connection();
+    let stmt = Query::update()
+        .table(table)
+        .value(Mate::EditorIdNew, to)
+        .and_where(Expr::col(Mate::EditorId).eq(from))
+        .to_owned();
+    db.execute(db.get_database_backend().build(&stmt)).await?;
+    Ok(())
+}
+
+/// 删除原字段，新字段改为原字段名
+async fn replace_editor_id(manager: &SchemaManager<'_>, table: Mate) -> Result<(), DbErr> {
+    helper::drop_columns(manager, table.clone(), vec![Mate::EditorId]).await?;
+    manager
+        .alter_table(
+            Table::alter()
+                .table(table)
+                .rename_column(Mate::EditorIdNew, Mate::EditorId)
+                .to_owned(),
+        )
+        .await
+}
+
+#[derive(DeriveIden, Clone)]
+enum Mate {
+    #[sea_orm(iden = "mate_apps")]
+    AppTable,
+    #[sea_orm(iden = "mate_kinds")]
+    KindTable,
+    #[sea_orm(iden = "mate_items")]
+    ItemTable,
+    #[sea_orm(iden = "mate_morphes")]
+    MorphTable,
+    EditorId,
+    #[sea_orm(iden = "editor_id_new")]
+    EditorIdNew,
+}
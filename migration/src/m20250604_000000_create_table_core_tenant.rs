@@ -0,0 +1,95 @@
+use sea_orm_migration::prelude::*;
+
+use crate::helper;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        helper::create_table(
+            manager,
+            Table::create()
+                .table(CoreTenant::Table)
+                .if_not_exists()
+                .col(
+                    ColumnDef::new(CoreTenant::Id)
+                        .big_integer()
+                        .primary_key()
+                        .auto_increment()
+                        .comment("ID"),
+                )
+                .col(
+                    ColumnDef::new(CoreTenant::Name)
+                        .string_len(30)
+                        .not_null()
+                        .default("")
+                        .comment("标识"),
+                )
+                .col(
+                    ColumnDef::new(CoreTenant::Title)
+                        .string_len(100)
+                        .not_null()
+                        .default("")
+                        .comment("名称"),
+                )
+                .col(
+                    ColumnDef::new(CoreTenant::Host)
+                        .string_len(100)
+                        .not_null()
+                        .default("")
+                        .comment("访问域名"),
+                )
+                .col(
+                    ColumnDef::new(CoreTenant::IsEnabled)
+                        .boolean()
+                        .not_null()
+                        .default(true)
+                        .comment("是否启用"),
+                )
+                .col(
+                    ColumnDef::new(CoreTenant::CreatedAt)
+                        .date_time()
+                        .not_null()
+                        .comment("创建时间"),
+                )
+                .col(
+                    ColumnDef::new(CoreTenant::UpdatedAt)
+                        .date_time()
+                        .not_null()
+                        .comment("更新时间"),
+                )
+                .to_owned(),
+        )
+        .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("core_tenants_idx_by_host")
+                    .table(CoreTenant::Table)
+                    .col(CoreTenant::Host)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CoreTenant::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum CoreTenant {
+    #[sea_orm(iden = "core_tenants")]
+    Table,
+    Id,
+    Name,
+    Title,
+    Host,
+    IsEnabled,
+    CreatedAt,
+    UpdatedAt,
+}
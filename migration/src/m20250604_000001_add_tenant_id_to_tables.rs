@@ -0,0 +1,88 @@
+use sea_orm_migration::prelude::*;
+
+use crate::helper;
+
+/// 按租户隔离的数据表添加租户ID，原有数据属于默认租户 0
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for table in tables() {
+            helper::add_columns(
+                manager,
+                table.clone(),
+                vec![
+                    ColumnDef::new(Scoped::TenantId)
+                        .big_integer()
+                        .not_null()
+                        .default(0)
+                        .comment("租户ID")
+                        .to_owned(),
+                ],
+            )
+            .await?;
+            manager
+                .create_index(
+                    Index::create()
+                        .name(index_name(&table))
+                        .table(table)
+                        .col(Scoped::TenantId)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for table in tables() {
+            manager
+                .drop_index(
+                    Index::drop()
+                        .name(index_name(&table))
+                        .table(table.clone())
+                        .to_owned(),
+                )
+                .await?;
+            helper::drop_columns(manager, table, vec![Scoped::TenantId]).await?;
+        }
+        Ok(())
+    }
+}
+
+fn tables() -> Vec<Scoped> {
+    vec![
+        Scoped::UserTable,
+        Scoped::CertificateTable,
+        Scoped::LoginTable,
+        Scoped::AppTable,
+        Scoped::KindTable,
+        Scoped::ItemTable,
+        Scoped::MorphTable,
+    ]
+}
+
+fn index_name(table: &Scoped) -> String {
+    format!("{}_idx_by_tenant", table.to_string())
+}
+
+#[derive(DeriveIden, Clone)]
+enum Scoped {
+    #[sea_orm(iden = "users")]
+    UserTable,
+    #[sea_orm(iden = "auth_certificates")]
+    CertificateTable,
+    #[sea_orm(iden = "user_logins")]
+    LoginTable,
+    #[sea_orm(iden = "mate_apps")]
+    AppTable,
+    #[sea_orm(iden = "mate_kinds")]
+    KindTable,
+    #[sea_orm(iden = "mate_items")]
+    ItemTable,
+    #[sea_orm(iden = "mate_morphes")]
+    MorphTable,
+    TenantId,
+}
@@ -0,0 +1,134 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DatabaseBackend;
+
+/// 唯一字段按租户建立唯一索引，与服务中的唯一性校验一致
+///
+/// 回收站中的记录不参与唯一性校验，索引只包含未删除的记录：PostgreSQL 和 SQLite 使用部分
+/// 索引，MySQL 使用函数索引，已删除的记录索引值为 NULL。
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// 唯一索引：数据表、索引名、唯一的字段（最后一个为校验的字段）、是否忽略空字符串
+struct UniqueIndex {
+    table: &'static str,
+    name: &'static str,
+    columns: &'static [&'static str],
+    skip_empty: bool,
+}
+
+const INDEXES: &[UniqueIndex] = &[
+    UniqueIndex {
+        table: "users",
+        name: "users_uniq_by_tenant_name",
+        columns: &["tenant_id", "name"],
+        skip_empty: false,
+    },
+    UniqueIndex {
+        table: "users",
+        name: "users_uniq_by_tenant_phone",
+        columns: &["tenant_id", "phone"],
+        skip_empty: true,
+    },
+    UniqueIndex {
+        table: "users",
+        name: "users_uniq_by_tenant_email",
+        columns: &["tenant_id", "email"],
+        skip_empty: true,
+    },
+    UniqueIndex {
+        table: "mate_apps",
+        name: "mate_apps_uniq_by_tenant_name",
+        columns: &["tenant_id", "name"],
+        skip_empty: false,
+    },
+    UniqueIndex {
+        table: "mate_apps",
+        name: "mate_apps_uniq_by_tenant_title",
+        columns: &["tenant_id", "title"],
+        skip_empty: false,
+    },
+    UniqueIndex {
+        table: "mate_kinds",
+        name: "mate_kinds_uniq_by_tenant_name",
+        columns: &["tenant_id", "name"],
+        skip_empty: false,
+    },
+    UniqueIndex {
+        table: "mate_kinds",
+        name: "mate_kinds_uniq_by_tenant_app_title",
+        columns: &["tenant_id", "app_id", "title"],
+        skip_empty: false,
+    },
+    UniqueIndex {
+        table: "mate_items",
+        name: "mate_items_uniq_by_tenant_parent_name",
+        columns: &["tenant_id", "kind_id", "parent_id", "name"],
+        skip_empty: false,
+    },
+    UniqueIndex {
+        table: "mate_items",
+        name: "mate_items_uniq_by_tenant_parent_title",
+        columns: &["tenant_id", "kind_id", "parent_id", "title"],
+        skip_empty: false,
+    },
+];
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let backend = manager.get_database_backend();
+        for index in INDEXES {
+            let sql = create_index_sql(backend, index);
+            manager.get_connection().execute_unprepared(&sql).await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for index in INDEXES {
+            manager
+                .drop_index(
+                    Index::drop()
+                        .name(index.name)
+                        .table(Alias::new(index.table))
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+fn create_index_sql(backend: DatabaseBackend, index: &UniqueIndex) -> String {
+    let (scopes, column) = index.columns.split_at(index.columns.len() - 1);
+    let column = column[0];
+    match backend {
+        DatabaseBackend::MySql => {
+            let mut skipped = String::from("is_deleted");
+            if index.skip_empty {
+                skipped.push_str(&format!(" OR {} = ''", column));
+            }
+            format!(
+                "CREATE UNIQUE INDEX {} ON {} ({}, (IF({}, NULL, {})))",
+                index.name,
+                index.table,
+                scopes.join(", "),
+                skipped,
+                column
+            )
+        }
+        DatabaseBackend::Postgres | DatabaseBackend::Sqlite => {
+            let mut condition = String::from("is_deleted = false");
+            if index.skip_empty {
+                condition.push_str(&format!(" AND {} <> ''", column));
+            }
+            format!(
+                "CREATE UNIQUE INDEX {} ON {} ({}) WHERE {}",
+                index.name,
+                index.table,
+                index.columns.join(", "),
+                condition
+            )
+        }
+    }
+}